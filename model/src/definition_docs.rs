@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 /// A definition from the Definition sections of the docs:
 /// eg. https://developer.oanda.com/rest-live-v20/account-df Could
 /// contain a struct definition or a string with a format, etc.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Definition {
     pub name: String,
     pub doc_string: String,
@@ -13,7 +13,7 @@ pub struct Definition {
 }
 
 /// The actual data from a json type Definition
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Value {
     Table(Vec<Row>),
     Struct(Struct),
@@ -21,7 +21,7 @@ pub enum Value {
 }
 
 /// The docs presented us with a table, and it could be any of these formats
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Row {
     ValueDescription {
         value: String,
@@ -46,14 +46,14 @@ pub enum Row {
     },
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Schema {
     Struct(Struct),
     Stream(Stream),
 }
 
 /// A struct definition. Used to create serde types to match oanda json types
-#[derive(Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Struct {
     pub fields: Vec<Field>,
 }
@@ -63,15 +63,16 @@ pub struct Struct {
 /// Each object will be *one of* the `objects`
 /// We just give the name of the objects, from that the code generator can
 /// Look them up.
-#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Stream {
     pub objects: HashSet<String>,
 }
 
 /// Represents a field in a struct definiton
-#[derive(Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Field {
-    /// The field name (key in the json)
+    /// The field name, exactly as the key in the json. eg. `relatedTransactionIDs`.
+    /// The writer makes the rust name from it, and renames the field back to it for serde
     pub name: String,
     /// May be a simple type like integer, or a defined type (another struct definition)
     pub type_name: String,
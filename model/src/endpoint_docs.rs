@@ -20,13 +20,15 @@ pub enum Endpoints {
 }
 
 /// The documentation for an API call from one of the endpoints
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RestCall {
     pub endpoint: Endpoints,
     pub http_method: HttpMethod,
     pub path: String,
     pub doc_string: String,
     pub parameters: Vec<RestCallParameter>,
+    /// The json body sent with the request. Only POST, PUT and PATCH calls have one
+    pub request_body: Option<Schema>,
     pub responses: Vec<Response>,
     /// Other reponses taht of type HTTPErrorResponse
     pub other_responses: Vec<u16>,
@@ -42,7 +44,7 @@ pub enum HTTPErrorResponse {
 }
 
 /// Encodes the documentation for a REST response given by an HTTP call to the Oanda API
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Response {
     pub code: u16,
     pub description: String,
@@ -50,13 +52,13 @@ pub struct Response {
     pub schema: Schema,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResponseHeader {
     pub name: String,
     pub description: String,
 }
 
-#[derive(Display, Default, FromStr, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Display, Default, FromStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[display(style = "UPPERCASE")]
 pub enum HttpMethod {
     #[default]
@@ -67,7 +69,7 @@ pub enum HttpMethod {
 }
 
 /// How a parameter should be sent to an http request
-#[derive(Display, FromStr, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Display, FromStr, Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 #[display(style = "snake_case")]
 pub enum LocatedIn {
     Header,
@@ -91,10 +93,35 @@ impl LocatedIn {
 }
 
 /// An http request documentation body
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RestCallParameter {
     pub name: String,
     pub located_in: LocatedIn,
+    /// For lists, this is the type of each item. eg. `InstrumentName` for "List of (InstrumentName, csv)"
     pub type_name: String,
     pub description: String,
+    /// The value the server uses when the parameter isn't sent. eg. `M` from "[default=M]"
+    pub default: Option<String>,
+    /// True if the description says "[required]"
+    #[serde(default)]
+    pub required: bool,
+    /// Set when the parameter holds a list of `type_name`s
+    pub collection: Option<CollectionFormat>,
+}
+
+/// How a list of values is encoded into a single parameter
+#[derive(Display, FromStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[display(style = "lowercase")]
+pub enum CollectionFormat {
+    /// Comma separated values
+    Csv,
+}
+
+impl CollectionFormat {
+    /// The text between each value in the list
+    pub fn separator(&self) -> &'static str {
+        match self {
+            CollectionFormat::Csv => ",",
+        }
+    }
 }
@@ -24,7 +24,7 @@ pub struct ErrorDefinition {
 }
 
 /// The content of one page of the oanda docs
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Content {
     pub urls: Vec<Url>,
     pub documentation: Documentation,
@@ -65,7 +65,7 @@ impl Content {
 }
 
 /// Represents a single api endpoint definition. eg. https://developer.oanda.com/rest-live-v20/instrument-df/
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Endpoint {
     pub name: String,
     pub calls: Vec<RestCall>,
@@ -74,7 +74,7 @@ pub struct Endpoint {
 /// Each page of the oanda website is either
 /// A bunch of definitions. eg. https://developer.oanda.com/rest-live-v20/instrument-df/
 /// Or a single endpoint with multiple Rest calls. eg. https://developer.oanda.com/rest-live-v20/account-ep/
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Documentation {
     Endpoint(Endpoint),
     Definitions {
@@ -1,16 +1,52 @@
-use crate::{host::Host, Error};
+use crate::{
+    error::HttpErrorKind, host::Host, rate_limit::{RateLimit, RateLimiter},
+    response::{Response, ResponseBody, ResponseHeaders},
+    retry::RetryPolicy, stream::{json_lines, StreamItem},
+    transport::{HttpResponse, ReqwestTransport, Transport},
+    Error,
+};
 use error_stack::{Report, ResultExt};
+use futures::Stream;
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
-use std::borrow::ToOwned;
-
+use std::sync::Arc;
+use crate::definitions::accept_datetime_format::AcceptDatetimeFormat;
+use crate::endpoints::instrument::Instrument;
+use crate::endpoints::account::Account;
+use crate::endpoints::order::Order;
+use crate::endpoints::trade::Trade;
+use crate::endpoints::position::Position;
+use crate::endpoints::transaction::Transaction;
+use crate::endpoints::pricing::Pricing;
+/// The error type of a single call. Each call has its own, generated in its `responses` module
+pub trait CallError: error_stack::Context + Sized {
+    /// Parses the body of a reply that didn't have a 2xx status code
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError>;
+    /// For when the request couldn't be sent, or its reply couldn't be read
+    fn request() -> Self;
+    /// For when the reply was read, but isn't what the docs say the call sends
+    fn decode() -> Self;
+}
 #[derive(Debug, Clone)]
 pub struct Client {
     token: String,
     pub host: Host,
+    /// The format the server sends and expects dates and times in. Defaults to RFC3339
+    pub datetime_format: AcceptDatetimeFormat,
+    /// Makes the requests, see the builders below
     rest_client: reqwest::Client,
+    /// Sends the requests
+    transport: Arc<dyn Transport>,
+    /// Spaces out the REST calls. Shared by clones of the client
+    rest_limiter: Arc<RateLimiter>,
+    /// Spaces out connecting to the streaming calls. Shared by clones of the client
+    stream_limiter: Arc<RateLimiter>,
+    /// When to send a failed request again
+    pub retry_policy: RetryPolicy,
 }
-
 impl Client {
     /// Creates a new [`Client`].
     ///
@@ -24,14 +60,62 @@ impl Client {
             .build()
             .map_err(Report::from)
             .unwrap();
-        Client { token, host, rest_client }
+        Client {
+            token,
+            host,
+            datetime_format: AcceptDatetimeFormat::Rfc3339,
+            transport: Arc::new(ReqwestTransport::new(rest_client.clone())),
+            rest_client,
+            rest_limiter: Arc::new(RateLimiter::new(RateLimit::REST)),
+            stream_limiter: Arc::new(RateLimiter::new(RateLimit::STREAM)),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+    /// Sets when to send a request again, after a dropped connection or a 429 or 5xx
+    /// reply. GETs are sent again, as are other calls with a `ClientRequestID` header,
+    /// so the server can tell it's a resend rather than, eg. a second order
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Client {
+        self.retry_policy = retry_policy;
+        self
+    }
+    /// Sets how fast the REST calls may be sent. Calls over the limit wait their turn.
+    /// Defaults to [`RateLimit::REST`]
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Client {
+        self.rest_limiter = Arc::new(RateLimiter::new(limit));
+        self
+    }
+    /// Sets how fast the streaming calls may connect. Defaults to [`RateLimit::STREAM`]
+    pub fn with_stream_rate_limit(mut self, limit: RateLimit) -> Client {
+        self.stream_limiter = Arc::new(RateLimiter::new(limit));
+        self
+    }
+    /// The number of calls, by this client and its clones, waiting for their turn to be
+    /// sent
+    pub fn queue_depth(&self) -> usize {
+        self.rest_limiter.queued() + self.stream_limiter.queued()
+    }
+    /// Sends the requests with `transport`, rather than over the network. eg. a
+    /// `crate::transport::MemoryTransport`, to test against canned responses
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Client {
+        self.transport = Arc::new(transport);
+        self
+    }
+    /// Sets the format the server sends and expects dates and times in
+    pub fn with_datetime_format(
+        mut self,
+        datetime_format: AcceptDatetimeFormat,
+    ) -> Client {
+        self.datetime_format = datetime_format;
+        self
     }
-
     /// Given a URL path, inserts the part before it
-    pub fn url(&self, path: &str) -> String {
+    pub fn url(&self, path: impl std::fmt::Display) -> String {
         self.host.rest_url(path)
     }
-
+    /// Given a URL path for one of the streaming calls, inserts the part before it
+    pub fn stream_url(&self, path: impl std::fmt::Display) -> String {
+        self.host.stream_url(path)
+    }
     /// Given a URL path, creates a get request builder with the
     /// correct host and authentication token
     pub fn get(&self, url: &str) -> RequestBuilder {
@@ -40,8 +124,8 @@ impl Client {
             .get(url)
             .header(AUTHORIZATION, format!("Bearer {}", & self.token))
             .header(ACCEPT, "application/json")
+            .header("Accept-Datetime-Format", self.datetime_format.to_string())
     }
-
     /// Given a URL path, creates a put request builder with the
     /// correct host and authentication token
     pub fn put(&self, url: &str) -> RequestBuilder {
@@ -50,8 +134,8 @@ impl Client {
             .put(url)
             .header(AUTHORIZATION, format!("Bearer {}", & self.token))
             .header(ACCEPT, "application/json")
+            .header("Accept-Datetime-Format", self.datetime_format.to_string())
     }
-
     /// Given a URL path, creates a patch request builder with the
     /// correct host and authentication token
     pub fn patch(&self, url: &str) -> RequestBuilder {
@@ -60,8 +144,8 @@ impl Client {
             .patch(url)
             .header(AUTHORIZATION, format!("Bearer {}", & self.token))
             .header(ACCEPT, "application/json")
+            .header("Accept-Datetime-Format", self.datetime_format.to_string())
     }
-    
     /// Given a URL path, creates a post request builder with the
     /// correct host and authentication token
     pub fn post(&self, url: &str) -> RequestBuilder {
@@ -70,8 +154,8 @@ impl Client {
             .post(url)
             .header(AUTHORIZATION, format!("Bearer {}", & self.token))
             .header(ACCEPT, "application/json")
+            .header("Accept-Datetime-Format", self.datetime_format.to_string())
     }
-
     /// Given a URL path, creates a delete request builder with the
     /// correct host and authentication token
     pub fn delete(&self, url: &str) -> RequestBuilder {
@@ -80,60 +164,168 @@ impl Client {
             .delete(url)
             .header(AUTHORIZATION, format!("Bearer {}", & self.token))
             .header(ACCEPT, "application/json")
+            .header("Accept-Datetime-Format", self.datetime_format.to_string())
+    }
+    /// Sends a request made by one of the builders above.
+    ///
+    /// A 2xx reply is parsed into `T`, with the call's documented headers `H`. Any other
+    /// reply is parsed into the call's own error type `E`, so callers can match on the
+    /// documented failures of each call
+    pub async fn send<
+        T: DeserializeOwned + ResponseBody,
+        H: ResponseHeaders,
+        E: CallError,
+    >(&self, request: RequestBuilder) -> error_stack::Result<Response<T, H>, E> {
+        let (url, response) = self
+            .execute(request, &self.rest_limiter)
+            .await
+            .change_context_lazy(E::request)?;
+        let status = response.status;
+        let headers = H::from_headers(&response.headers);
+        let body = response
+            .text()
+            .await
+            .attach_printable("Retrieving HTTP body")
+            .change_context_lazy(E::request)
+            .attach_printable_lazy(|| format!("HTTP status code: {status}"))
+            .attach_printable_lazy(|| format!("URL: {url}"))?;
+        if (200..300).contains(&status) {
+            crate::error::from_json(body.as_bytes())
+                .map(|parsed: T| Response {
+                    last_transaction_id: parsed.last_transaction_id(),
+                    body: parsed,
+                    headers,
+                })
+                .map_err(|err| {
+                    let error = Error::json(&url, &err);
+                    Report::from(err).change_context(error)
+                })
+                .change_context_lazy(E::decode)
+        } else {
+            Err(Self::error_reply(&url, status, &body))
+        }
+            .attach_printable_lazy(|| format!("Body: {body}"))
+            .attach_printable_lazy(|| format!("HTTP status code: {status}"))
+            .attach_printable_lazy(|| format!("URL: {url}"))
+    }
+    /// Sends a request to one of the streaming calls.
+    ///
+    /// Once connected, the server sends one json object per line, for as long as the
+    /// connection lasts. Each is parsed into a `T`. If the connection fails, the reply is
+    /// parsed into the call's own error type `E`, like in [`Client::send`]
+    pub async fn stream<T: StreamItem, E: CallError>(
+        &self,
+        request: RequestBuilder,
+    ) -> error_stack::Result<impl Stream<Item = error_stack::Result<T, E>>, E> {
+        let (url, response) = self
+            .execute(request, &self.stream_limiter)
+            .await
+            .change_context_lazy(E::request)?;
+        if response.is_success() {
+            return Ok(json_lines(url, response.body));
+        }
+        let status = response.status;
+        let body = response
+            .text()
+            .await
+            .attach_printable("Retrieving HTTP body")
+            .change_context_lazy(E::request)
+            .attach_printable_lazy(|| format!("HTTP status code: {status}"))
+            .attach_printable_lazy(|| format!("URL: {url}"))?;
+        Err(Self::error_reply(&url, status, &body))
+            .attach_printable_lazy(|| format!("Body: {body}"))
+            .attach_printable_lazy(|| format!("HTTP status code: {status}"))
+            .attach_printable_lazy(|| format!("URL: {url}"))
     }
-
-    /// Makes an authenticated get request to a path in the rest api
-    pub async fn send<T: DeserializeOwned>(
+    /// Builds the request and hands it to the transport, once `limiter` says it's its
+    /// turn. Sends it again if the `retry_policy` says to, and it's safe to. Gives back
+    /// the URL it was sent to, for the error reports, with the response
+    async fn execute(
         &self,
         request: RequestBuilder,
-    ) -> error_stack::Result<T, Error> {
+        limiter: &RateLimiter,
+    ) -> error_stack::Result<(String, HttpResponse), Error> {
         let request = request
             .build()
             .map_err(Report::from)
-            .change_context_lazy(|| Error::new("Building request"))?;
-        let url = request.url().to_owned();
-        let response = self
-            .rest_client
-            .execute(request)
-            .await
-            .map_err(Report::from)
-            .change_context_lazy(|| Error::new(format!("URL: {url}")))?;
-        let status = response.status();
-        let body = response.text().await;
-        if status.is_client_error() {
-            let result = Err(Error::new("HTTP Status code indicates client error"));
-            match body {
-                Ok(body) => result.attach_printable_lazy(|| format!("Body: {body}")),
-                Err(body) => {
-                    result
-                        .attach_printable_lazy(|| format!("Error getting body: {body}"))
+            .change_context(Error::Transport)
+            .attach_printable("Building request")?;
+        let url = request.url().to_string();
+        let can_resend = request.method() == reqwest::Method::GET
+            || request.headers().contains_key("ClientRequestID");
+        let mut request = request;
+        let mut attempt = 1;
+        loop {
+            let resend = if can_resend { request.try_clone() } else { None };
+            limiter.acquire().await;
+            let result = self.transport.execute(request).await;
+            match (resend, self.retry_policy.wait_before_retry(attempt, &result)) {
+                (Some(resend), Some(wait)) => {
+                    tokio::time::sleep(wait).await;
+                    request = resend;
+                    attempt += 1;
                 }
-            }
-        } else if status.is_server_error() {
-            let result = Err(Error::new("HTTP Status code indicates server error"));
-            match body {
-                Ok(body) => result.attach_printable_lazy(|| format!("Body: {body}")),
-                Err(body) => {
-                    result
-                        .attach_printable_lazy(|| format!("Error getting body: {body}"))
+                _ => {
+                    return result
+                        .attach_printable_lazy(|| format!("URL: {url}"))
+                        .attach_printable(format!("Attempts: {attempt}"))
+                        .map(|response| (url, response));
                 }
             }
-        } else {
-            match body {
-                Ok(body) => {
-                    serde_json::from_str(&body)
-                        .map_err(Report::from)
-                        .change_context_lazy(|| Error::new("Parsing json"))
-                        .attach_printable_lazy(|| format!("Body: {body}"))
-                }
-                Err(err) => {
-                    Err(Report::from(err))
-                        .change_context_lazy(|| Error::new("Retrieving HTTP body"))
-                }
+        }
+    }
+    /// The report for a reply from `url` with a status code other than 2xx. The call's own
+    /// error type `E` is caused by our [`Error`], which says what went wrong the same way
+    /// for every call. Where OANDA documents the status, its [`HttpErrorKind`] is
+    /// attached, so the report says what usually causes it.
+    ///
+    /// If the body isn't what the docs say the call replies with, it's `E::decode`,
+    /// caused by an [`Error::Json`]
+    fn error_reply<E: CallError>(url: &str, status: u16, body: &str) -> Report<E> {
+        let mut report = match E::from_response(status, body) {
+            Ok(error) => {
+                Report::new(Error::from_response(status, body)).change_context(error)
+            }
+            Err(err) => {
+                let error = Error::json(url, &err);
+                Report::from(err).change_context(error).change_context(E::decode())
             }
+        };
+        if let Some(kind) = HttpErrorKind::from_status(status) {
+            report = report
+                .attach(kind)
+                .attach_printable(format!("{kind}: {}", kind.remedy()));
         }
-            .attach_printable_lazy(|| format!("HTTP status code: {status:#?}"))
-            .attach_printable_lazy(|| format!("URL: {url}"))
+        report
+    }
+    /// Rest API calls for the instrument endpoint
+    pub fn instrument(&self) -> Instrument<'_> {
+        Instrument { client: self }
+    }
+    /// Rest API calls for the account endpoint
+    pub fn account(&self) -> Account<'_> {
+        Account { client: self }
+    }
+    /// Rest API calls for the order endpoint
+    pub fn order(&self) -> Order<'_> {
+        Order { client: self }
+    }
+    /// Rest API calls for the trade endpoint
+    pub fn trade(&self) -> Trade<'_> {
+        Trade { client: self }
+    }
+    /// Rest API calls for the position endpoint
+    pub fn position(&self) -> Position<'_> {
+        Position { client: self }
+    }
+    /// Rest API calls for the transaction endpoint
+    pub fn transaction(&self) -> Transaction<'_> {
+        Transaction { client: self }
+    }
+    /// Rest API calls for the pricing endpoint
+    pub fn pricing(&self) -> Pricing<'_> {
+        Pricing { client: self }
     }
-
 }
+#[cfg(test)]
+mod test;
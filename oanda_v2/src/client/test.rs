@@ -0,0 +1,115 @@
+//! Tests of what the client does with the replies it gets, against canned responses
+use crate::{
+    client::Client,
+    endpoints::{
+        account::AccountsRequest,
+        order::{responses::cancel, CancelRequest, OrdersRequest},
+    },
+    host::Host,
+    retry::RetryPolicy,
+    transport::{CannedResponse, MemoryTransport},
+    Error,
+};
+use reqwest::Method;
+
+/// A client that doesn't retry, so each canned response is used once
+fn client(transport: &MemoryTransport) -> Client {
+    Client::new("token".to_string(), Host::Dev)
+        .with_transport(transport.clone())
+        .with_retry_policy(RetryPolicy::NEVER)
+}
+
+#[tokio::test]
+async fn test_undecodable_reply_has_json_path() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        Method::GET,
+        "/v3/accounts",
+        CannedResponse::new(
+            200,
+            r#"{"accounts": [{"id": "001-001-1234567-001"}, {"id": 5}]}"#,
+        ),
+    );
+    let client = client(&transport);
+    let report = client
+        .account()
+        .accounts(AccountsRequest::new())
+        .await
+        .unwrap_err();
+    let Some(Error::Json { path, .. }) = report.downcast_ref::<Error>() else {
+        panic!("Expected an Error::Json: {report:?}")
+    };
+    assert_eq!("accounts[1].id", path);
+}
+
+#[tokio::test]
+async fn test_reply_headers_and_last_transaction_id() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        Method::GET,
+        "/v3/accounts/1/orders",
+        CannedResponse::new(200, r#"{"orders": [], "lastTransactionID": "6356"}"#)
+            .with_header("requestid", "24689179521545658"),
+    );
+    let response = OrdersRequest::new("1".into())
+        .send(&client(&transport))
+        .await
+        .unwrap();
+    assert_eq!(
+        Some("24689179521545658".into()),
+        response.headers.request_id
+    );
+    assert_eq!(Some("6356".into()), response.last_transaction_id);
+    assert_eq!(
+        response.last_transaction_id,
+        response.body.last_transaction_id
+    );
+}
+
+#[tokio::test]
+async fn test_documented_error_with_only_a_message() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        Method::PUT,
+        "/v3/accounts/1/orders/2/cancel",
+        CannedResponse::new(
+            404,
+            r#"{"errorMessage": "The Order specified does not exist"}"#,
+        ),
+    );
+    let report = CancelRequest::new("1".into(), "2".into())
+        .send(&client(&transport))
+        .await
+        .unwrap_err();
+    let Some(cancel::Error::E404(body)) = report.downcast_ref::<cancel::Error>() else {
+        panic!("Expected an E404: {report:?}")
+    };
+    assert_eq!("The Order specified does not exist", body.error_message);
+    assert_eq!(None, body.last_transaction_id);
+}
+
+#[tokio::test]
+async fn test_malformed_order_is_a_decode_error() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        Method::GET,
+        "/v3/accounts/1/orders",
+        CannedResponse::new(
+            200,
+            r#"{"orders": [
+                {"type": "SOMETHING_NEW", "id": "1"},
+                {"type": "MARKET", "id": "2", "units": {"oops": 1}}
+            ]}"#,
+        ),
+    );
+    // An unknown type is kept, but a MARKET order that doesn't parse isn't hidden as one
+    let report = OrdersRequest::new("1".into())
+        .send(&client(&transport))
+        .await
+        .unwrap_err();
+    let Some(Error::Json { path, message, .. }) = report.downcast_ref::<Error>() else {
+        panic!("Expected an Error::Json: {report:?}")
+    };
+    assert_eq!("orders[1]", path);
+    assert!(message.contains("units"), "{message}");
+}
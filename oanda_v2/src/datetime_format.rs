@@ -0,0 +1,521 @@
+//! Reads and writes the two ways OANDA encodes a `DateTime`, picked by the
+//! `Accept-Datetime-Format` header:
+//!
+//! * RFC3339: "2023-11-14T22:13:20.123456789Z"
+//! * UNIX: "1700000000.123456789", seconds and nanoseconds since the epoch
+use crate::definitions::accept_datetime_format::AcceptDatetimeFormat;
+use chrono::{SecondsFormat, Utc};
+use serde::{
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Serialize, Serializer,
+};
+
+/// The reason a string couldn't be read as a `DateTime`
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid RFC3339 or UNIX date time: {0:?}")]
+pub struct ParseDateTimeError(String);
+
+/// Writes `time` in `format`, to the nanosecond
+pub fn format(time: &chrono::DateTime<Utc>, format: AcceptDatetimeFormat) -> String {
+    match format {
+        AcceptDatetimeFormat::Rfc3339 => time.to_rfc3339_opts(SecondsFormat::Nanos, true),
+        AcceptDatetimeFormat::Unix => {
+            format!("{}.{:09}", time.timestamp(), time.timestamp_subsec_nanos())
+        }
+    }
+}
+
+/// The name `DateTime`s give the newtype they serialize as. Serializers that don't know it write
+/// the RFC3339 string inside, as serde_json does
+const DATE_TIME: &str = "oanda_v2::DateTime";
+
+/// Serializes a `DateTime` as RFC3339, marked so [`json_body`] can write it in another format
+pub fn serialize<S: Serializer>(
+    time: &chrono::DateTime<Utc>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_newtype_struct(DATE_TIME, &format(time, AcceptDatetimeFormat::Rfc3339))
+}
+
+/// A request body that writes its `DateTime`s in `format`, rather than RFC3339, see [`json_body`]
+pub struct JsonBody<'a, T: ?Sized> {
+    body: &'a T,
+    format: AcceptDatetimeFormat,
+}
+
+/// Wraps a request body, so its `DateTime`s are sent in the format the client asked the server
+/// for. Only the `DateTime`s change; a string that looks like one, eg. in a comment, is left be
+pub fn json_body<T: Serialize>(body: &T, format: AcceptDatetimeFormat) -> JsonBody<'_, T> {
+    JsonBody { body, format }
+}
+
+impl<T: Serialize + ?Sized> Serialize for JsonBody<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.body.serialize(FormatSerializer {
+            inner: serializer,
+            format: self.format,
+        })
+    }
+}
+
+/// Passes everything through to `inner`, but writes the `DateTime`s it finds in `format`. Each
+/// value inside a list, map or struct is wrapped in a [`JsonBody`], so they're found at any depth
+struct FormatSerializer<S> {
+    inner: S,
+    format: AcceptDatetimeFormat,
+}
+
+impl<S> FormatSerializer<S> {
+    fn wrap<'a, T: ?Sized>(&self, value: &'a T) -> JsonBody<'a, T> {
+        JsonBody {
+            body: value,
+            format: self.format,
+        }
+    }
+}
+
+impl<S: Serializer> Serializer for FormatSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        if name != DATE_TIME || self.format == AcceptDatetimeFormat::Rfc3339 {
+            let value = self.wrap(value);
+            return self.inner.serialize_newtype_struct(name, &value);
+        }
+        let rfc3339 = serde_json::to_value(value).map_err(serde::ser::Error::custom)?;
+        let time = rfc3339
+            .as_str()
+            .map(parse)
+            .ok_or_else(|| serde::ser::Error::custom("A DateTime serializes as a string"))?
+            .map_err(serde::ser::Error::custom)?;
+        self.inner.serialize_str(&format(&time, self.format))
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i128(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u64(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = self.wrap(value);
+        self.inner
+            .serialize_newtype_variant(name, variant_index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_seq(len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_tuple(len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_tuple_struct(name, len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_map(len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_struct(name, len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        let format = self.format;
+        self.inner
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(|inner| Compound { inner, format })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// A list, map or struct being serialized by a [`FormatSerializer`]
+struct Compound<C> {
+    inner: C,
+    format: AcceptDatetimeFormat,
+}
+
+impl<C> Compound<C> {
+    fn wrap<'a, T: ?Sized>(&self, value: &'a T) -> JsonBody<'a, T> {
+        JsonBody {
+            body: value,
+            format: self.format,
+        }
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), C::Error> {
+        let key = self.wrap(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        let value = self.wrap(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// Reads a date time in either format, whatever the client asked for
+pub fn parse(s: &str) -> Result<chrono::DateTime<Utc>, ParseDateTimeError> {
+    let error = || ParseDateTimeError(s.to_string());
+    if s.contains('T') {
+        return chrono::DateTime::parse_from_rfc3339(s)
+            .map(|time| time.with_timezone(&Utc))
+            .map_err(|_| error());
+    }
+    let (seconds, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if fraction.len() > 9 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(error());
+    }
+    let seconds: i64 = seconds.parse().map_err(|_| error())?;
+    // "5" after the point is 500_000_000 nanoseconds
+    let nanoseconds = format!("{fraction:0<9}").parse().map_err(|_| error())?;
+    chrono::DateTime::from_timestamp(seconds, nanoseconds).ok_or_else(error)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format, json_body, parse};
+    use crate::definitions::{accept_datetime_format::AcceptDatetimeFormat, date_time::DateTime};
+    use serde::Serialize;
+
+    #[test]
+    fn test_parse_both_formats() {
+        let rfc3339 = parse("2023-11-14T22:13:20.123456789Z").unwrap();
+        let unix = parse("1700000000.123456789").unwrap();
+        assert_eq!(rfc3339, unix);
+        assert_eq!(
+            parse("1700000000").unwrap(),
+            parse("2023-11-14T22:13:20Z").unwrap()
+        );
+        assert_eq!(
+            parse("1700000000.5").unwrap(),
+            parse("2023-11-14T22:13:20.5Z").unwrap()
+        );
+        assert!(parse("yesterday").is_err());
+        assert!(parse("1700000000.1234567890").is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        let time = parse("1700000000.000000123").unwrap();
+        assert_eq!(
+            "2023-11-14T22:13:20.000000123Z",
+            format(&time, AcceptDatetimeFormat::Rfc3339)
+        );
+        assert_eq!(
+            "1700000000.000000123",
+            format(&time, AcceptDatetimeFormat::Unix)
+        );
+    }
+
+    #[test]
+    fn test_json_body() {
+        #[derive(Serialize)]
+        struct Body {
+            time: DateTime,
+            times: Vec<DateTime>,
+            maybe: Option<DateTime>,
+            comment: String,
+        }
+        let time = DateTime(parse("1700000000.000000123").unwrap());
+        let body = Body {
+            time,
+            times: vec![time],
+            maybe: Some(time),
+            // Only DateTimes are rewritten, not strings that look like one
+            comment: time.to_string(),
+        };
+        let unix = serde_json::to_value(json_body(&body, AcceptDatetimeFormat::Unix)).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "time": "1700000000.000000123",
+                "times": ["1700000000.000000123"],
+                "maybe": "1700000000.000000123",
+                "comment": "2023-11-14T22:13:20.000000123Z",
+            }),
+            unix
+        );
+        let rfc3339 =
+            serde_json::to_value(json_body(&body, AcceptDatetimeFormat::Rfc3339)).unwrap();
+        assert_eq!(serde_json::to_value(&body).unwrap(), rfc3339);
+        assert_eq!("2023-11-14T22:13:20.000000123Z", rfc3339["time"]);
+    }
+
+    #[test]
+    fn test_unset_fields_are_left_out() {
+        use crate::{
+            definitions::take_profit_details::TakeProfitDetails, endpoints::trade::OrdersBody,
+        };
+        // A `null` stopLoss would cancel the Trade's Stop Loss, so only the take profit is sent
+        let body = OrdersBody {
+            take_profit: Some(TakeProfitDetails::default()),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(json_body(&body, AcceptDatetimeFormat::Rfc3339)).unwrap();
+        let keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        assert_eq!(vec!["takeProfit"], keys);
+    }
+}
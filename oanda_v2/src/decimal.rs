@@ -0,0 +1,66 @@
+//! An exact decimal number, for the prices and amounts OANDA sends as strings.
+//!
+//! It's [`rust_decimal::Decimal`], which reads and writes OANDA's string form and keeps the
+//! precision it was given, so "1.10500" prints back as "1.10500". It has `checked_add`,
+//! `checked_mul`, etc. for when a result might not fit; the operators panic then, like the
+//! integers do
+pub use rust_decimal::{Decimal, Error as ParseDecimalError};
+
+#[cfg(test)]
+mod test {
+    use super::Decimal;
+
+    fn d(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for s in ["1.10500", "-0.0001", "42", "0.5", "-12.000"] {
+            assert_eq!(s, d(s).to_string());
+        }
+        assert_eq!("0.5", d(".5").to_string());
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("-".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(d("0.3"), d("0.1") + d("0.2"));
+        assert_eq!("1.10550", (d("1.10500") + d("0.0005")).to_string());
+        assert_eq!(d("-0.0015"), d("1.1050") - d("1.1065"));
+        assert_eq!(d("110.5"), d("1.105") * d("100"));
+        assert_eq!(d("2.5"), d("10") / d("4"));
+        assert_eq!(d("-1.5"), -d("1.5"));
+        assert_eq!(d("1.105"), d("1.10500"));
+        assert!(d("-2") < d("-1.5"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Keeps as many digits after the point as fit
+        assert_eq!(d("3000"), (d("1000") / (d("1") / d("3"))).round_dp(6));
+        assert_eq!(None, Decimal::MAX.checked_add(d("1")));
+        assert_eq!(None, Decimal::MAX.checked_mul(d("2")));
+        assert_eq!(None, d("1").checked_div(d("0")));
+        assert_eq!(Decimal::MAX, Decimal::MIN.abs());
+        assert!("99999999999999999999999999999999".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn test_precision() {
+        // Rounds half to even
+        assert_eq!("1.10", format!("{:.2}", d("1.105")));
+        assert_eq!("1.10000", format!("{:.5}", d("1.1")));
+        assert_eq!("1", format!("{:.0}", d("1.499")));
+        assert_eq!(d("1.1"), d("1.14").round_dp(1));
+    }
+
+    #[test]
+    fn test_serde() {
+        let value: Decimal = serde_json::from_str(r#""1.10500""#).unwrap();
+        assert_eq!(r#""1.10500""#, serde_json::to_string(&value).unwrap());
+        // OANDA always sends them as strings
+        assert!(serde_json::from_str::<Decimal>("100").is_err());
+    }
+}
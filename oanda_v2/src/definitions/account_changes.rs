@@ -1,39 +1,43 @@
+use crate::definitions::order::Order;
+use crate::definitions::position::Position;
+use crate::definitions::trade_summary::TradeSummary;
+use crate::definitions::transaction::Transaction;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AccountChanges {
     /// The Orders created. These Orders may have been filled,
     /// cancelled or triggered in the same period.
-    orders_created: Vec<Order>,
+    #[serde(rename = "ordersCreated")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orders_created: Vec<Order>,
     /// The Orders cancelled.
-    orders_cancelled: Vec<Order>,
+    #[serde(rename = "ordersCancelled")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orders_cancelled: Vec<Order>,
     /// The Orders filled.
-    orders_filled: Vec<Order>,
+    #[serde(rename = "ordersFilled")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orders_filled: Vec<Order>,
     /// The Orders triggered.
-    orders_triggered: Vec<Order>,
+    #[serde(rename = "ordersTriggered")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orders_triggered: Vec<Order>,
     /// The Trades opened.
-    trades_opened: Vec<TradeSummary>,
+    #[serde(rename = "tradesOpened")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trades_opened: Vec<TradeSummary>,
     /// The Trades reduced.
-    trades_reduced: Vec<TradeSummary>,
+    #[serde(rename = "tradesReduced")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trades_reduced: Vec<TradeSummary>,
     /// The Trades closed.
-    trades_closed: Vec<TradeSummary>,
+    #[serde(rename = "tradesClosed")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trades_closed: Vec<TradeSummary>,
     /// The Positions changed.
-    positions: Vec<Position>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub positions: Vec<Position>,
     /// The Transactions that have been generated.
-    transactions: Vec<Transaction>,
-}
-impl Default for AccountChanges {
-    fn default() -> Self {
-        Self {
-            orders_created: Default::default(),
-            orders_cancelled: Default::default(),
-            orders_filled: Default::default(),
-            orders_triggered: Default::default(),
-            trades_opened: Default::default(),
-            trades_reduced: Default::default(),
-            trades_closed: Default::default(),
-            positions: Default::default(),
-            transactions: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<Transaction>,
 }
@@ -1,6 +1,16 @@
 use serde::{Serialize, Deserialize};
 /// The financing mode of an Account
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AccountFinancingMode {
     /// No financing is paid/charged for open Trades in the Account
@@ -5,6 +5,18 @@ use serde::{Serialize, Deserialize};
 /// “{siteID}-{divisionID}-{userID}-{accountNumber}”
 ///
 /// Example: 001-011-5838423-001
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct AccountID(String);
 impl std::ops::Deref for AccountID {
     type Target = str;
@@ -12,3 +24,18 @@ impl std::ops::Deref for AccountID {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for AccountID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for AccountID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for AccountID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -1,21 +1,16 @@
+use crate::definitions::account_id::AccountID;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AccountProperties {
     /// The Account’s identifier
-    id: Option<AccountID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<AccountID>,
     /// The Account’s associated MT4 Account ID. This field will not
     /// be present if the Account is not an MT4 account.
-    mt4_account_id: Option<integer>,
+    #[serde(rename = "mt4AccountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mt4_account_id: Option<i64>,
     /// The Account’s tags
-    tags: Vec<String>,
-}
-impl Default for AccountProperties {
-    fn default() -> Self {
-        Self {
-            id: Default::default(),
-            mt4_account_id: Default::default(),
-            tags: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
@@ -1,147 +1,178 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::currency::Currency;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_order_mode::GuaranteedStopLossOrderMode;
+use crate::definitions::guaranteed_stop_loss_order_parameters::GuaranteedStopLossOrderParameters;
+use crate::definitions::transaction_id::TransactionID;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AccountSummary {
     /// The Account’s identifier
-    id: Option<AccountID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<AccountID>,
     /// Client-assigned alias for the Account. Only provided if the
     /// Account has an alias set
-    alias: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
     /// The home currency of the Account
-    currency: Option<Currency>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
     /// ID of the user that created the Account.
-    created_by_user_id: Option<integer>,
+    #[serde(rename = "createdByUserID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by_user_id: Option<i64>,
     /// The date/time when the Account was created.
-    created_time: Option<DateTime<Utc>>,
+    #[serde(rename = "createdTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<DateTime>,
     /// The current guaranteed Stop Loss Order settings of
     /// the Account. This field will only be present if the
     /// guaranteedStopLossOrderMode is not ‘DISABLED’.
-    guaranteed_stop_loss_order_parameters: Option<GuaranteedStopLossOrderParameters>,
+    #[serde(rename = "guaranteedStopLossOrderParameters")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_parameters: Option<GuaranteedStopLossOrderParameters>,
     /// The current guaranteed Stop Loss Order mode of the Account.
-    guaranteed_stop_loss_order_mode: Option<GuaranteedStopLossOrderMode>,
+    #[serde(rename = "guaranteedStopLossOrderMode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_mode: Option<GuaranteedStopLossOrderMode>,
     /// The date/time that the Account’s resettablePL was last
     /// reset.
-    resettable_pl_time: Option<DateTime<Utc>>,
+    #[serde(rename = "resettablePLTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resettable_pl_time: Option<DateTime>,
     /// Client-provided margin rate override for the Account. The
     /// effective margin rate of the Account is the lesser of this
     /// value and the OANDA margin rate for the Account’s division.
     /// This value is only provided if a margin rate override exists
     /// for the Account.
-    margin_rate: Option<DecimalNumber>,
+    #[serde(rename = "marginRate")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_rate: Option<DecimalNumber>,
     /// The number of Trades currently open in the Account.
-    open_trade_count: Option<integer>,
+    #[serde(rename = "openTradeCount")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_trade_count: Option<i64>,
     /// The number of Positions currently open in the Account.
-    open_position_count: Option<integer>,
+    #[serde(rename = "openPositionCount")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_position_count: Option<i64>,
     /// The number of Orders currently pending in the Account.
-    pending_order_count: Option<integer>,
+    #[serde(rename = "pendingOrderCount")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_order_count: Option<i64>,
     /// Flag indicating that the Account has hedging enabled.
-    hedging_enabled: Option<boolean>,
+    #[serde(rename = "hedgingEnabled")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hedging_enabled: Option<bool>,
     /// The total unrealized profit/loss for all Trades currently
     /// open in the Account.
-    unrealized_pl: Option<AccountUnits>,
+    #[serde(rename = "unrealizedPL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unrealized_pl: Option<AccountUnits>,
     /// The net asset value of the Account. Equal to Account balance
     /// + unrealizedPL.
-    nav: Option<AccountUnits>,
+    #[serde(rename = "NAV")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nav: Option<AccountUnits>,
     /// Margin currently used for the Account.
-    margin_used: Option<AccountUnits>,
+    #[serde(rename = "marginUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_used: Option<AccountUnits>,
     /// Margin available for Account currency.
-    margin_available: Option<AccountUnits>,
+    #[serde(rename = "marginAvailable")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_available: Option<AccountUnits>,
     /// The value of the Account’s open positions represented in the
     /// Account’s home currency.
-    position_value: Option<AccountUnits>,
+    #[serde(rename = "positionValue")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position_value: Option<AccountUnits>,
     /// The Account’s margin closeout unrealized PL.
-    margin_closeout_unrealized_pl: Option<AccountUnits>,
+    #[serde(rename = "marginCloseoutUnrealizedPL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout_unrealized_pl: Option<AccountUnits>,
     /// The Account’s margin closeout NAV.
-    margin_closeout_nav: Option<AccountUnits>,
+    #[serde(rename = "marginCloseoutNAV")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout_nav: Option<AccountUnits>,
     /// The Account’s margin closeout margin used.
-    margin_closeout_margin_used: Option<AccountUnits>,
+    #[serde(rename = "marginCloseoutMarginUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout_margin_used: Option<AccountUnits>,
     /// The Account’s margin closeout percentage. When this value is
     /// 1.0 or above the Account is in a margin closeout situation.
-    margin_closeout_percent: Option<DecimalNumber>,
+    #[serde(rename = "marginCloseoutPercent")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout_percent: Option<DecimalNumber>,
     /// The value of the Account’s open positions as used for margin
     /// closeout calculations represented in the Account’s home
     /// currency.
-    margin_closeout_position_value: Option<DecimalNumber>,
+    #[serde(rename = "marginCloseoutPositionValue")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout_position_value: Option<DecimalNumber>,
     /// The current WithdrawalLimit for the account which will
     /// be zero or a positive value indicating how much can be
     /// withdrawn from the account.
-    withdrawal_limit: Option<AccountUnits>,
+    #[serde(rename = "withdrawalLimit")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub withdrawal_limit: Option<AccountUnits>,
     /// The Account’s margin call margin used.
-    margin_call_margin_used: Option<AccountUnits>,
+    #[serde(rename = "marginCallMarginUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_call_margin_used: Option<AccountUnits>,
     /// The Account’s margin call percentage. When this value is 1.0
     /// or above the Account is in a margin call situation.
-    margin_call_percent: Option<DecimalNumber>,
+    #[serde(rename = "marginCallPercent")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_call_percent: Option<DecimalNumber>,
     /// The current balance of the account.
-    balance: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<AccountUnits>,
     /// The total profit/loss realized over the lifetime of the
     /// Account.
-    pl: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pl: Option<AccountUnits>,
     /// The total realized profit/loss for the account since it was
     /// last reset by the client.
-    resettable_pl: Option<AccountUnits>,
+    #[serde(rename = "resettablePL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resettable_pl: Option<AccountUnits>,
     /// The total amount of financing paid/collected over the
     /// lifetime of the account.
-    financing: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financing: Option<AccountUnits>,
     /// The total amount of commission paid over the lifetime of
     /// the Account.
-    commission: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commission: Option<AccountUnits>,
     /// The total amount of dividend adjustment paid over the
     /// lifetime of the Account in the Account’s home currency.
-    dividend_adjustment: Option<AccountUnits>,
+    #[serde(rename = "dividendAdjustment")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dividend_adjustment: Option<AccountUnits>,
     /// The total amount of fees charged over the lifetime of the
     /// Account for the execution of guaranteed Stop Loss Orders.
-    guaranteed_execution_fees: Option<AccountUnits>,
+    #[serde(rename = "guaranteedExecutionFees")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_execution_fees: Option<AccountUnits>,
     /// The date/time when the Account entered a margin call state.
     /// Only provided if the Account is in a margin call.
-    margin_call_enter_time: Option<DateTime<Utc>>,
+    #[serde(rename = "marginCallEnterTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_call_enter_time: Option<DateTime>,
     /// The number of times that the Account’s current margin call
     /// was extended.
-    margin_call_extension_count: Option<integer>,
+    #[serde(rename = "marginCallExtensionCount")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_call_extension_count: Option<i64>,
     /// The date/time of the Account’s last margin call extension.
-    last_margin_call_extension_time: Option<DateTime<Utc>>,
+    #[serde(rename = "lastMarginCallExtensionTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_margin_call_extension_time: Option<DateTime>,
     /// The ID of the last Transaction created for the Account.
-    last_transaction_id: Option<TransactionID>,
-}
-impl Default for AccountSummary {
-    fn default() -> Self {
-        Self {
-            id: Default::default(),
-            alias: Default::default(),
-            currency: Default::default(),
-            created_by_user_id: Default::default(),
-            created_time: Default::default(),
-            guaranteed_stop_loss_order_parameters: Default::default(),
-            guaranteed_stop_loss_order_mode: Default::default(),
-            resettable_pl_time: Default::default(),
-            margin_rate: Default::default(),
-            open_trade_count: Default::default(),
-            open_position_count: Default::default(),
-            pending_order_count: Default::default(),
-            hedging_enabled: Default::default(),
-            unrealized_pl: Default::default(),
-            nav: Default::default(),
-            margin_used: Default::default(),
-            margin_available: Default::default(),
-            position_value: Default::default(),
-            margin_closeout_unrealized_pl: Default::default(),
-            margin_closeout_nav: Default::default(),
-            margin_closeout_margin_used: Default::default(),
-            margin_closeout_percent: Default::default(),
-            margin_closeout_position_value: Default::default(),
-            withdrawal_limit: Default::default(),
-            margin_call_margin_used: Default::default(),
-            margin_call_percent: Default::default(),
-            balance: Default::default(),
-            pl: Default::default(),
-            resettable_pl: Default::default(),
-            financing: Default::default(),
-            commission: Default::default(),
-            dividend_adjustment: Default::default(),
-            guaranteed_execution_fees: Default::default(),
-            margin_call_enter_time: Default::default(),
-            margin_call_extension_count: Default::default(),
-            last_margin_call_extension_time: Default::default(),
-            last_transaction_id: Default::default(),
-        }
-    }
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
 }
@@ -1,28 +1,27 @@
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::instrument_name::InstrumentName;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CalculatedPositionState {
     /// The Position’s Instrument.
-    instrument: Option<InstrumentName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<InstrumentName>,
     /// The Position’s net unrealized profit/loss
-    net_unrealized_pl: Option<AccountUnits>,
+    #[serde(rename = "netUnrealizedPL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_unrealized_pl: Option<AccountUnits>,
     /// The unrealized profit/loss of the Position’s long open
     /// Trades
-    long_unrealized_pl: Option<AccountUnits>,
+    #[serde(rename = "longUnrealizedPL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_unrealized_pl: Option<AccountUnits>,
     /// The unrealized profit/loss of the Position’s short open
     /// Trades
-    short_unrealized_pl: Option<AccountUnits>,
+    #[serde(rename = "shortUnrealizedPL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_unrealized_pl: Option<AccountUnits>,
     /// Margin currently used by the Position.
-    margin_used: Option<AccountUnits>,
-}
-impl Default for CalculatedPositionState {
-    fn default() -> Self {
-        Self {
-            instrument: Default::default(),
-            net_unrealized_pl: Default::default(),
-            long_unrealized_pl: Default::default(),
-            short_unrealized_pl: Default::default(),
-            margin_used: Default::default(),
-        }
-    }
+    #[serde(rename = "marginUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_used: Option<AccountUnits>,
 }
@@ -1,20 +1,17 @@
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::trade_id::TradeID;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CalculatedTradeState {
     /// The Trade’s ID.
-    id: Option<TradeID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TradeID>,
     /// The Trade’s unrealized profit/loss.
-    unrealized_pl: Option<AccountUnits>,
+    #[serde(rename = "unrealizedPL")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unrealized_pl: Option<AccountUnits>,
     /// Margin currently used by the Trade.
-    margin_used: Option<AccountUnits>,
-}
-impl Default for CalculatedTradeState {
-    fn default() -> Self {
-        Self {
-            id: Default::default(),
-            unrealized_pl: Default::default(),
-            margin_used: Default::default(),
-        }
-    }
+    #[serde(rename = "marginUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_used: Option<AccountUnits>,
 }
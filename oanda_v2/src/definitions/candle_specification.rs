@@ -5,6 +5,18 @@ use serde::{Serialize, Deserialize};
 /// A string containing the following, all delimited by “:”
 /// characters: 1) InstrumentName 2) CandlestickGranularity 3)
 /// PricingComponent e.g. EUR_USD:S10:BM
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct CandleSpecification(String);
 impl std::ops::Deref for CandleSpecification {
     type Target = str;
@@ -12,3 +24,18 @@ impl std::ops::Deref for CandleSpecification {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for CandleSpecification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for CandleSpecification {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for CandleSpecification {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
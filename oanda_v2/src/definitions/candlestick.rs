@@ -1,34 +1,29 @@
+use crate::definitions::candlestick_data::CandlestickData;
+use crate::definitions::date_time::DateTime;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Candlestick {
     /// The start time of the candlestick
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The candlestick data based on bids. Only provided if bid-
     /// based candles were requested.
-    bid: Option<CandlestickData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bid: Option<CandlestickData>,
     /// The candlestick data based on asks. Only provided if ask-
     /// based candles were requested.
-    ask: Option<CandlestickData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ask: Option<CandlestickData>,
     /// The candlestick data based on midpoints. Only provided if
     /// midpoint-based candles were requested.
-    mid: Option<CandlestickData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mid: Option<CandlestickData>,
     /// The number of prices created during the time-range
     /// represented by the candlestick.
-    volume: Option<integer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<i64>,
     /// A flag indicating if the candlestick is complete. A complete
     /// candlestick is one whose ending time is not in the future.
-    complete: Option<boolean>,
-}
-impl Default for Candlestick {
-    fn default() -> Self {
-        Self {
-            time: Default::default(),
-            bid: Default::default(),
-            ask: Default::default(),
-            mid: Default::default(),
-            volume: Default::default(),
-            complete: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete: Option<bool>,
 }
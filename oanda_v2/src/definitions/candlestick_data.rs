@@ -1,27 +1,21 @@
+use crate::definitions::price_value::PriceValue;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CandlestickData {
     /// The first (open) price in the time-range represented by
     /// the candlestick.
-    o: Option<PriceValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub o: Option<PriceValue>,
     /// The highest price in the time-range represented by the
     /// candlestick.
-    h: Option<PriceValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<PriceValue>,
     /// The lowest price in the time-range represented by the
     /// candlestick.
-    l: Option<PriceValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<PriceValue>,
     /// The last (closing) price in the time-range represented by
     /// the candlestick.
-    c: Option<PriceValue>,
-}
-impl Default for CandlestickData {
-    fn default() -> Self {
-        Self {
-            o: Default::default(),
-            h: Default::default(),
-            l: Default::default(),
-            c: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub c: Option<PriceValue>,
 }
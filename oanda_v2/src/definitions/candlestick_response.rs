@@ -1,21 +1,17 @@
+use crate::definitions::candlestick::Candlestick;
+use crate::definitions::candlestick_granularity::CandlestickGranularity;
+use crate::definitions::instrument_name::InstrumentName;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CandlestickResponse {
     /// The instrument whose Prices are represented by the
     /// candlesticks.
-    instrument: Option<InstrumentName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<InstrumentName>,
     /// The granularity of the candlesticks provided.
-    granularity: Option<CandlestickGranularity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<CandlestickGranularity>,
     /// The list of candlesticks that satisfy the request.
-    candles: Vec<Candlestick>,
-}
-impl Default for CandlestickResponse {
-    fn default() -> Self {
-        Self {
-            instrument: Default::default(),
-            granularity: Default::default(),
-            candles: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub candles: Vec<Candlestick>,
 }
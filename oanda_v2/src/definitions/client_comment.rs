@@ -5,6 +5,18 @@ use serde::{Serialize, Deserialize};
 /// Order or Trade.
 ///
 /// This is a client comment
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct ClientComment(String);
 impl std::ops::Deref for ClientComment {
     type Target = str;
@@ -12,3 +24,18 @@ impl std::ops::Deref for ClientComment {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for ClientComment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for ClientComment {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for ClientComment {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
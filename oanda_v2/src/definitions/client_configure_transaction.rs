@@ -1,31 +1,51 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfigureTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to
     /// “CLIENT_CONFIGURE” in a ClientConfigureTransaction.
-    #[serde_inline_default("CLIENT_CONFIGURE")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::ClientConfigure)]
+    pub r#type: TransactionType,
     /// The client-provided alias for the Account.
-    alias: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
     /// The margin rate override for the Account.
-    margin_rate: Option<DecimalNumber>,
+    #[serde(rename = "marginRate")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_rate: Option<DecimalNumber>,
 }
 impl Default for ClientConfigureTransaction {
     fn default() -> Self {
@@ -36,7 +56,7 @@ impl Default for ClientConfigureTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "CLIENT_CONFIGURE",
+            r#type: TransactionType::ClientConfigure,
             alias: Default::default(),
             margin_rate: Default::default(),
         }
@@ -1,20 +1,16 @@
+use crate::definitions::client_comment::ClientComment;
+use crate::definitions::client_id::ClientID;
+use crate::definitions::client_tag::ClientTag;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClientExtensions {
     /// The Client ID of the Order/Trade
-    id: Option<ClientID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<ClientID>,
     /// A tag associated with the Order/Trade
-    tag: Option<ClientTag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<ClientTag>,
     /// A comment associated with the Order/Trade
-    comment: Option<ClientComment>,
-}
-impl Default for ClientExtensions {
-    fn default() -> Self {
-        Self {
-            id: Default::default(),
-            tag: Default::default(),
-            comment: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<ClientComment>,
 }
@@ -4,6 +4,18 @@ use serde::{Serialize, Deserialize};
 /// provided.
 ///
 /// my_order_id
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct ClientID(String);
 impl std::ops::Deref for ClientID {
     type Target = str;
@@ -11,3 +23,18 @@ impl std::ops::Deref for ClientID {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for ClientID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for ClientID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for ClientID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -1,42 +1,56 @@
+use crate::definitions::date_time::DateTime;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::price_bucket::PriceBucket;
+use crate::definitions::price_value::PriceValue;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientPrice {
     /// The string “PRICE”. Used to identify the a Price object when
     /// found in a stream.
-    #[serde_inline_default("PRICE")]
-    r#type: String,
+    #[serde_inline_default(String::from("PRICE"))]
+    pub r#type: String,
     /// The Price’s Instrument.
-    instrument: Option<InstrumentName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<InstrumentName>,
     /// The date/time when the Price was created
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// Flag indicating if the Price is tradeable or not
-    tradeable: Option<boolean>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tradeable: Option<bool>,
     /// The list of prices and liquidity available on the
     /// Instrument’s bid side. It is possible for this list to be
     /// empty if there is no bid liquidity currently available for
     /// the Instrument in the Account.
-    bids: Vec<PriceBucket>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bids: Vec<PriceBucket>,
     /// The list of prices and liquidity available on the
     /// Instrument’s ask side. It is possible for this list to be
     /// empty if there is no ask liquidity currently available for
     /// the Instrument in the Account.
-    asks: Vec<PriceBucket>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub asks: Vec<PriceBucket>,
     /// The closeout bid Price. This Price is used when a bid is
     /// required to closeout a Position (margin closeout or manual)
     /// yet there is no bid liquidity. The closeout bid is never
     /// used to open a new position.
-    closeout_bid: Option<PriceValue>,
+    #[serde(rename = "closeoutBid")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closeout_bid: Option<PriceValue>,
     /// The closeout ask Price. This Price is used when a ask is
     /// required to closeout a Position (margin closeout or manual)
     /// yet there is no ask liquidity. The closeout ask is never
     /// used to open a new position.
-    closeout_ask: Option<PriceValue>,
+    #[serde(rename = "closeoutAsk")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closeout_ask: Option<PriceValue>,
 }
 impl Default for ClientPrice {
     fn default() -> Self {
         Self {
-            r#type: "PRICE",
+            r#type: String::from("PRICE"),
             instrument: Default::default(),
             time: Default::default(),
             tradeable: Default::default(),
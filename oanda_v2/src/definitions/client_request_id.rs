@@ -2,6 +2,18 @@ use serde::{Serialize, Deserialize};
 /// A client provided request identifier.
 ///
 /// my_request_id
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct ClientRequestID(String);
 impl std::ops::Deref for ClientRequestID {
     type Target = str;
@@ -9,3 +21,18 @@ impl std::ops::Deref for ClientRequestID {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for ClientRequestID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for ClientRequestID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for ClientRequestID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
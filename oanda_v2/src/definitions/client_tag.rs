@@ -4,6 +4,18 @@ use serde::{Serialize, Deserialize};
 /// to associate groups of Trades and/or Orders together.
 ///
 /// client_tag_1
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct ClientTag(String);
 impl std::ops::Deref for ClientTag {
     type Target = str;
@@ -11,3 +23,18 @@ impl std::ops::Deref for ClientTag {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for ClientTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for ClientTag {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for ClientTag {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -1,27 +1,43 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CloseTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to “CLOSE” in a
     /// CloseTransaction.
-    #[serde_inline_default("CLOSE")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::Close)]
+    pub r#type: TransactionType,
 }
 impl Default for CloseTransaction {
     fn default() -> Self {
@@ -32,7 +48,7 @@ impl Default for CloseTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "CLOSE",
+            r#type: TransactionType::Close,
         }
     }
 }
@@ -1,14 +1,10 @@
+use crate::definitions::decimal_number::DecimalNumber;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConversionFactor {
     /// The factor by which to multiply the amount in the given
     /// currency to obtain the amount in the home currency of the
     /// Account.
-    factor: Option<DecimalNumber>,
-}
-impl Default for ConversionFactor {
-    fn default() -> Self {
-        Self { factor: Default::default() }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factor: Option<DecimalNumber>,
 }
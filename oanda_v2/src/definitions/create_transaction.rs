@@ -1,37 +1,64 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::currency::Currency;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to “CREATE” in a
     /// CreateTransaction.
-    #[serde_inline_default("CREATE")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::Create)]
+    pub r#type: TransactionType,
     /// The ID of the Division that the Account is in
-    division_id: Option<integer>,
+    #[serde(rename = "divisionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division_id: Option<i64>,
     /// The ID of the Site that the Account was created at
-    site_id: Option<integer>,
+    #[serde(rename = "siteID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<i64>,
     /// The ID of the user that the Account was created for
-    account_user_id: Option<integer>,
+    #[serde(rename = "accountUserID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_user_id: Option<i64>,
     /// The number of the Account within the site/division/user
-    account_number: Option<integer>,
+    #[serde(rename = "accountNumber")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<i64>,
     /// The home currency of the Account
-    home_currency: Option<Currency>,
+    #[serde(rename = "homeCurrency")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_currency: Option<Currency>,
 }
 impl Default for CreateTransaction {
     fn default() -> Self {
@@ -42,7 +69,7 @@ impl Default for CreateTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "CREATE",
+            r#type: TransactionType::Create,
             division_id: Default::default(),
             site_id: Default::default(),
             account_user_id: Default::default(),
@@ -3,6 +3,18 @@ use serde::{Serialize, Deserialize};
 /// currencies.
 ///
 /// A string containing an ISO 4217 currency (
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct Currency(String);
 impl std::ops::Deref for Currency {
     type Target = str;
@@ -10,3 +22,18 @@ impl std::ops::Deref for Currency {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for Currency {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for Currency {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -3,10 +3,62 @@ use serde::{Serialize, Deserialize};
 /// representation.
 ///
 /// The RFC 3339 representation is a string conforming to
-pub struct DateTime<Utc>(String);
-impl std::ops::Deref for DateTime<Utc> {
-    type Target = str;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime(pub chrono::DateTime<chrono::Utc>);
+impl std::ops::Deref for DateTime {
+    type Target = chrono::DateTime<chrono::Utc>;
     fn deref(&self) -> &Self::Target {
-        self.0.as_str()
+        &self.0
+    }
+}
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self(value)
+    }
+}
+impl From<DateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(value: DateTime) -> Self {
+        value.0
+    }
+}
+impl std::str::FromStr for DateTime {
+    type Err = crate::datetime_format::ParseDateTimeError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        crate::datetime_format::parse(s).map(Self)
+    }
+}
+impl DateTime {
+    /// Writes the time in `format`, to the nanosecond
+    pub fn format(
+        &self,
+        format: crate::definitions::accept_datetime_format::AcceptDatetimeFormat,
+    ) -> String {
+        crate::datetime_format::format(&self.0, format)
+    }
+}
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            &self
+                .format(
+                    crate::definitions::accept_datetime_format::AcceptDatetimeFormat::Rfc3339,
+                ),
+        )
+    }
+}
+impl Serialize for DateTime {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        crate::datetime_format::serialize(&self.0, serializer)
+    }
+}
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// The DayOfWeek provides a representation of the day of the
 /// week.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DayOfWeek {
     /// Sunday
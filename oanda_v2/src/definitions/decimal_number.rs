@@ -3,10 +3,76 @@ use serde::{Serialize, Deserialize};
 ///
 /// A decimal number encoded as a string. The amount of
 /// precision provided depends on what the number represents.
-pub struct DecimalNumber(String);
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[serde(transparent)]
+pub struct DecimalNumber(pub crate::decimal::Decimal);
 impl std::ops::Deref for DecimalNumber {
-    type Target = str;
+    type Target = crate::decimal::Decimal;
     fn deref(&self) -> &Self::Target {
-        self.0.as_str()
+        &self.0
+    }
+}
+impl From<crate::decimal::Decimal> for DecimalNumber {
+    fn from(value: crate::decimal::Decimal) -> Self {
+        Self(value)
+    }
+}
+impl From<DecimalNumber> for crate::decimal::Decimal {
+    fn from(value: DecimalNumber) -> Self {
+        value.0
+    }
+}
+impl std::str::FromStr for DecimalNumber {
+    type Err = crate::decimal::ParseDecimalError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+/// Give a precision to round to it, eg. `{:.5}`
+impl std::fmt::Display for DecimalNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+impl std::ops::Neg for DecimalNumber {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+impl std::ops::Add<Self> for DecimalNumber {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(std::ops::Add::add(self.0, rhs.0))
+    }
+}
+impl std::ops::Sub<Self> for DecimalNumber {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(std::ops::Sub::sub(self.0, rhs.0))
+    }
+}
+impl std::ops::Mul<crate::decimal::Decimal> for DecimalNumber {
+    type Output = Self;
+    fn mul(self, rhs: crate::decimal::Decimal) -> Self::Output {
+        Self(std::ops::Mul::mul(self.0, rhs))
+    }
+}
+impl std::ops::Div<crate::decimal::Decimal> for DecimalNumber {
+    type Output = Self;
+    fn div(self, rhs: crate::decimal::Decimal) -> Self::Output {
+        Self(std::ops::Div::div(self.0, rhs))
     }
 }
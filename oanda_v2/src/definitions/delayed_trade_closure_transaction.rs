@@ -1,33 +1,54 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::market_order_reason::MarketOrderReason;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelayedTradeClosureTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always
     /// set to “DELAYED_TRADE_CLOSURE” for an
     /// DelayedTradeClosureTransaction.
-    #[serde_inline_default("DELAYED_TRADE_CLOSURE")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::DelayedTradeClosure)]
+    pub r#type: TransactionType,
     /// The reason for the delayed trade closure
-    reason: Option<MarketOrderReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<MarketOrderReason>,
     /// List of Trade ID’s identifying the open trades that will be
     /// closed when their respective instruments become tradeable
-    trade_i_ds: Option<TradeID>,
+    #[serde(rename = "tradeIDs")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_ids: Option<TradeID>,
 }
 impl Default for DelayedTradeClosureTransaction {
     fn default() -> Self {
@@ -38,9 +59,9 @@ impl Default for DelayedTradeClosureTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "DELAYED_TRADE_CLOSURE",
+            r#type: TransactionType::DelayedTradeClosure,
             reason: Default::default(),
-            trade_i_ds: Default::default(),
+            trade_ids: Default::default(),
         }
     }
 }
@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// In the context of an Order or a Trade, defines whether the
 /// units are positive or negative.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Direction {
     /// A long Order is used to to buy units of an Instrument. A
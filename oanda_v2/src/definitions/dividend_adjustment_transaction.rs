@@ -1,53 +1,85 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::home_conversion_factors::HomeConversionFactors;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::open_trade_dividend_adjustment::OpenTradeDividendAdjustment;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DividendAdjustmentTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to
     /// “DIVIDEND_ADJUSTMENT” for a DividendAdjustmentTransaction.
-    #[serde_inline_default("DIVIDEND_ADJUSTMENT")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::DividendAdjustment)]
+    pub r#type: TransactionType,
     /// The name of the instrument for the dividendAdjustment
     /// transaction
-    instrument: Option<InstrumentName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<InstrumentName>,
     /// The total dividend adjustment amount paid or collected in
     /// the Account’s home currency for the Account as a result of
     /// applying the DividendAdjustment Transaction. This is the
     /// sum of the dividend adjustments paid/collected for each
     /// OpenTradeDividendAdjustment found within the Transaction.
-    dividend_adjustment: Option<AccountUnits>,
+    #[serde(rename = "dividendAdjustment")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dividend_adjustment: Option<AccountUnits>,
     /// The total dividend adjustment amount paid or collected
     /// in the Instrument’s quote currency for the Account as a
     /// result of applying the DividendAdjustment Transaction.
     /// This is the sum of the quote dividend adjustments paid/
     /// collected for each OpenTradeDividendAdjustment found within
     /// the Transaction.
-    quote_dividend_adjustment: Option<DecimalNumber>,
+    #[serde(rename = "quoteDividendAdjustment")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_dividend_adjustment: Option<DecimalNumber>,
     /// The HomeConversionFactors in effect at the time of the
     /// DividendAdjustment.
-    home_conversion_factors: Option<HomeConversionFactors>,
+    #[serde(rename = "homeConversionFactors")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_conversion_factors: Option<HomeConversionFactors>,
     /// The Account balance after applying the DividendAdjustment
     /// Transaction
-    account_balance: Option<AccountUnits>,
+    #[serde(rename = "accountBalance")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_balance: Option<AccountUnits>,
     /// The dividend adjustment payment/collection details for
     /// each open Trade, within the Account, for which a dividend
     /// adjustment is to be paid or collected.
-    open_trade_dividend_adjustments: Vec<OpenTradeDividendAdjustment>,
+    #[serde(rename = "openTradeDividendAdjustments")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_trade_dividend_adjustments: Vec<OpenTradeDividendAdjustment>,
 }
 impl Default for DividendAdjustmentTransaction {
     fn default() -> Self {
@@ -58,7 +90,7 @@ impl Default for DividendAdjustmentTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "DIVIDEND_ADJUSTMENT",
+            r#type: TransactionType::DividendAdjustment,
             instrument: Default::default(),
             dividend_adjustment: Default::default(),
             quote_dividend_adjustment: Default::default(),
@@ -1,18 +1,14 @@
+use crate::definitions::day_of_week::DayOfWeek;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FinancingDayOfWeek {
     /// The day of the week to charge the financing.
-    day_of_week: Option<DayOfWeek>,
+    #[serde(rename = "dayOfWeek")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day_of_week: Option<DayOfWeek>,
     /// The number of days worth of financing to be charged on
     /// dayOfWeek.
-    days_charged: Option<integer>,
-}
-impl Default for FinancingDayOfWeek {
-    fn default() -> Self {
-        Self {
-            day_of_week: Default::default(),
-            days_charged: Default::default(),
-        }
-    }
+    #[serde(rename = "daysCharged")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days_charged: Option<i64>,
 }
@@ -1,93 +1,141 @@
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::order_position_fill::OrderPositionFill;
+use crate::definitions::order_state::OrderState;
+use crate::definitions::order_type::OrderType;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use crate::definitions::transaction_id::TransactionID;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixedPriceOrder {
     /// The Order’s identifier, unique within the Order’s Account.
-    id: Option<OrderID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<OrderID>,
     /// The time when the Order was created.
-    create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "createTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<DateTime>,
     /// The current state of the Order.
-    state: Option<OrderState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrderState>,
     /// The client extensions of the Order. Do not set, modify, or
     /// delete clientExtensions if your account is associated with
     /// MT4.
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The type of the Order. Always set to “FIXED_PRICE” for Fixed
     /// Price Orders.
-    #[serde_inline_default("FIXED_PRICE")]
-    r#type: OrderType,
+    #[serde_inline_default(OrderType::FixedPrice)]
+    pub r#type: OrderType,
     /// The Fixed Price Order’s Instrument.
-    instrument: InstrumentName,
+    pub instrument: InstrumentName,
     /// The quantity requested to be filled by the Fixed Price
     /// Order. A positive number of units results in a long Order,
     /// and a negative number of units results in a short Order.
-    units: DecimalNumber,
+    pub units: DecimalNumber,
     /// The price specified for the Fixed Price Order. This price
     /// is the exact price that the Fixed Price Order will be filled
     /// at.
-    price: PriceValue,
+    pub price: PriceValue,
     /// Specification of how Positions in the Account are modified
     /// when the Order is filled.
-    #[serde_inline_default("DEFAULT")]
-    position_fill: OrderPositionFill,
+    #[serde(rename = "positionFill")]
+    #[serde_inline_default(OrderPositionFill::Default)]
+    pub position_fill: OrderPositionFill,
     /// The state that the trade resulting from the Fixed Price
     /// Order should be set to.
-    trade_state: String,
+    #[serde(rename = "tradeState")]
+    pub trade_state: String,
     /// TakeProfitDetails specifies the details of a Take Profit
     /// Order to be created on behalf of a client. This may happen
     /// when an Order is filled that opens a Trade requiring a Take
     /// Profit, or when a Trade’s dependent Take Profit Order is
     /// modified directly through the Trade.
-    take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(rename = "takeProfitOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
     /// StopLossDetails specifies the details of a Stop Loss Order
     /// to be created on behalf of a client. This may happen when
     /// an Order is filled that opens a Trade requiring a Stop Loss,
     /// or when a Trade’s dependent Stop Loss Order is modified
     /// directly through the Trade.
-    stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(rename = "stopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
     /// GuaranteedStopLossDetails specifies the details of a
     /// Guaranteed Stop Loss Order to be created on behalf of a
     /// client. This may happen when an Order is filled that opens
     /// a Trade requiring a Guaranteed Stop Loss, or when a Trade’s
     /// dependent Guaranteed Stop Loss Order is modified directly
     /// through the Trade.
-    guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(rename = "guaranteedStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
     /// TrailingStopLossDetails specifies the details of a Trailing
     /// Stop Loss Order to be created on behalf of a client. This
     /// may happen when an Order is filled that opens a Trade
     /// requiring a Trailing Stop Loss, or when a Trade’s dependent
     /// Trailing Stop Loss Order is modified directly through the
     /// Trade.
-    trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(rename = "trailingStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
     /// Client Extensions to add to the Trade created when the
     /// Order is filled (if such a Trade is created). Do not set,
     /// modify, or delete tradeClientExtensions if your account is
     /// associated with MT4.
-    trade_client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
     /// ID of the Transaction that filled this Order (only provided
     /// when the Order’s state is FILLED)
-    filling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "fillingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filling_transaction_id: Option<TransactionID>,
     /// Date/time when the Order was filled (only provided when the
     /// Order’s state is FILLED)
-    filled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "filledTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filled_time: Option<DateTime>,
     /// Trade ID of Trade opened when the Order was filled (only
     /// provided when the Order’s state is FILLED and a Trade was
     /// opened as a result of the fill)
-    trade_opened_id: Option<TradeID>,
+    #[serde(rename = "tradeOpenedID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_opened_id: Option<TradeID>,
     /// Trade ID of Trade reduced when the Order was filled (only
     /// provided when the Order’s state is FILLED and a Trade was
     /// reduced as a result of the fill)
-    trade_reduced_id: Option<TradeID>,
+    #[serde(rename = "tradeReducedID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_reduced_id: Option<TradeID>,
     /// Trade IDs of Trades closed when the Order was filled (only
     /// provided when the Order’s state is FILLED and one or more
     /// Trades were closed as a result of the fill)
-    trade_closed_i_ds: Vec<TradeID>,
+    #[serde(rename = "tradeClosedIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trade_closed_ids: Vec<TradeID>,
     /// ID of the Transaction that cancelled the Order (only
     /// provided when the Order’s state is CANCELLED)
-    cancelling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "cancellingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelling_transaction_id: Option<TransactionID>,
     /// Date/time when the Order was cancelled (only provided when
     /// the state of the Order is CANCELLED)
-    cancelled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "cancelledTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelled_time: Option<DateTime>,
 }
 impl Default for FixedPriceOrder {
     fn default() -> Self {
@@ -96,11 +144,11 @@ impl Default for FixedPriceOrder {
             create_time: Default::default(),
             state: Default::default(),
             client_extensions: Default::default(),
-            r#type: "FIXED_PRICE",
+            r#type: OrderType::FixedPrice,
             instrument: Default::default(),
             units: Default::default(),
             price: Default::default(),
-            position_fill: "DEFAULT",
+            position_fill: OrderPositionFill::Default,
             trade_state: Default::default(),
             take_profit_on_fill: Default::default(),
             stop_loss_on_fill: Default::default(),
@@ -111,7 +159,7 @@ impl Default for FixedPriceOrder {
             filled_time: Default::default(),
             trade_opened_id: Default::default(),
             trade_reduced_id: Default::default(),
-            trade_closed_i_ds: Default::default(),
+            trade_closed_ids: Default::default(),
             cancelling_transaction_id: Default::default(),
             cancelled_time: Default::default(),
         }
@@ -1,69 +1,110 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::fixed_price_order_reason::FixedPriceOrderReason;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::order_position_fill::OrderPositionFill;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixedPriceOrderTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to
     /// “FIXED_PRICE_ORDER” in a FixedPriceOrderTransaction.
-    #[serde_inline_default("FIXED_PRICE_ORDER")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::FixedPriceOrder)]
+    pub r#type: TransactionType,
     /// The Fixed Price Order’s Instrument.
-    instrument: InstrumentName,
+    pub instrument: InstrumentName,
     /// The quantity requested to be filled by the Fixed Price
     /// Order. A positive number of units results in a long Order,
     /// and a negative number of units results in a short Order.
-    units: DecimalNumber,
+    pub units: DecimalNumber,
     /// The price specified for the Fixed Price Order. This price
     /// is the exact price that the Fixed Price Order will be filled
     /// at.
-    price: PriceValue,
+    pub price: PriceValue,
     /// Specification of how Positions in the Account are modified
     /// when the Order is filled.
-    #[serde_inline_default("DEFAULT")]
-    position_fill: OrderPositionFill,
+    #[serde(rename = "positionFill")]
+    #[serde_inline_default(OrderPositionFill::Default)]
+    pub position_fill: OrderPositionFill,
     /// The state that the trade resulting from the Fixed Price
     /// Order should be set to.
-    trade_state: String,
+    #[serde(rename = "tradeState")]
+    pub trade_state: String,
     /// The reason that the Fixed Price Order was created
-    reason: Option<FixedPriceOrderReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<FixedPriceOrderReason>,
     /// The client extensions for the Fixed Price Order.
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The specification of the Take Profit Order that should be
     /// created for a Trade opened when the Order is filled (if such
     /// a Trade is created).
-    take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(rename = "takeProfitOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
     /// The specification of the Stop Loss Order that should be
     /// created for a Trade opened when the Order is filled (if such
     /// a Trade is created).
-    stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(rename = "stopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
     /// The specification of the Trailing Stop Loss Order that
     /// should be created for a Trade that is opened when the Order
     /// is filled (if such a Trade is created).
-    trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(rename = "trailingStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
     /// The specification of the Guaranteed Stop Loss Order that
     /// should be created for a Trade that is opened when the Order
     /// is filled (if such a Trade is created).
-    guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(rename = "guaranteedStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
     /// Client Extensions to add to the Trade created when the Order
     /// is filled (if such a Trade is created).  Do not set, modify,
     /// delete tradeClientExtensions if your account is associated
     /// with MT4.
-    trade_client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
 }
 impl Default for FixedPriceOrderTransaction {
     fn default() -> Self {
@@ -74,11 +115,11 @@ impl Default for FixedPriceOrderTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "FIXED_PRICE_ORDER",
+            r#type: TransactionType::FixedPriceOrder,
             instrument: Default::default(),
             units: Default::default(),
             price: Default::default(),
-            position_fill: "DEFAULT",
+            position_fill: OrderPositionFill::Default,
             trade_state: Default::default(),
             reason: Default::default(),
             client_extensions: Default::default(),
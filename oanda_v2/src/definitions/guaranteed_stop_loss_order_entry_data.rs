@@ -1,25 +1,22 @@
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_order_level_restriction::GuaranteedStopLossOrderLevelRestriction;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderEntryData {
     /// The minimum distance allowed between the Trade’s fill price
     /// and the configured price for guaranteed Stop Loss Orders
     /// created for this instrument. Specified in price units.
-    minimum_distance: Option<DecimalNumber>,
+    #[serde(rename = "minimumDistance")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_distance: Option<DecimalNumber>,
     /// The amount that is charged to the account if a guaranteed
     /// Stop Loss Order is triggered and filled. The value is in
     /// price units and is charged for each unit of the Trade.
-    premium: Option<DecimalNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub premium: Option<DecimalNumber>,
     /// The guaranteed Stop Loss Order level restriction for this
     /// instrument.
-    level_restriction: Option<GuaranteedStopLossOrderLevelRestriction>,
-}
-impl Default for GuaranteedStopLossOrderEntryData {
-    fn default() -> Self {
-        Self {
-            minimum_distance: Default::default(),
-            premium: Default::default(),
-            level_restriction: Default::default(),
-        }
-    }
+    #[serde(rename = "levelRestriction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level_restriction: Option<GuaranteedStopLossOrderLevelRestriction>,
 }
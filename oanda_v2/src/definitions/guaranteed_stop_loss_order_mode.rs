@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// The overall behaviour of the Account regarding guaranteed
 /// Stop Loss Orders.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum GuaranteedStopLossOrderMode {
     /// The Account is not permitted to create guaranteed Stop Loss
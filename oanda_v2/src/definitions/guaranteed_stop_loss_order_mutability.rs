@@ -2,7 +2,17 @@ use serde::{Serialize, Deserialize};
 /// For Accounts that support guaranteed Stop Loss Orders,
 /// describes the actions that can be be performed on guaranteed
 /// Stop Loss Orders.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum GuaranteedStopLossOrderMutability {
     /// Once a guaranteed Stop Loss Order has been created it cannot
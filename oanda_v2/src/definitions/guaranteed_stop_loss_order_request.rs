@@ -1,34 +1,51 @@
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::client_id::ClientID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::order_trigger_condition::OrderTriggerCondition;
+use crate::definitions::order_type::OrderType;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::time_in_force::TimeInForce;
+use crate::definitions::trade_id::TradeID;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderRequest {
     /// The type of the Order to Create. Must be set to
     /// “GUARANTEED_STOP_LOSS” when creating a Guaranteed Stop Loss
     /// Order.
-    #[serde_inline_default("GUARANTEED_STOP_LOSS")]
-    r#type: OrderType,
+    #[serde_inline_default(OrderType::GuaranteedStopLoss)]
+    pub r#type: OrderType,
     /// The ID of the Trade to close when the price threshold is
     /// breached.
-    trade_id: TradeID,
+    #[serde(rename = "tradeID")]
+    pub trade_id: TradeID,
     /// The client ID of the Trade to be closed when the price
     /// threshold is breached.
-    client_trade_id: Option<ClientID>,
+    #[serde(rename = "clientTradeID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<ClientID>,
     /// The price threshold specified for the Guaranteed Stop Loss
     /// Order. The associated Trade will be closed at this price.
-    price: PriceValue,
+    pub price: PriceValue,
     /// Specifies the distance (in price units) from the Account’s
     /// current price to use as the Guaranteed Stop Loss Order
     /// price. If the Trade is short the Instrument’s bid price is
     /// used, and for long Trades the ask is used.
-    distance: Option<DecimalNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<DecimalNumber>,
     /// The time-in-force requested for the GuaranteedStopLoss
     /// Order. Restricted to “GTC”, “GFD” and “GTD” for
     /// GuaranteedStopLoss Orders.
-    #[serde_inline_default("GTC")]
-    time_in_force: TimeInForce,
+    #[serde(rename = "timeInForce")]
+    #[serde_inline_default(TimeInForce::Gtc)]
+    pub time_in_force: TimeInForce,
     /// The date/time when the GuaranteedStopLoss Order will be
     /// cancelled if its timeInForce is “GTD”.
-    gtd_time: Option<DateTime<Utc>>,
+    #[serde(rename = "gtdTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime>,
     /// Specification of which price component should be used when
     /// determining if an Order should be triggered and filled.
     /// This allows Orders to be triggered based on the bid, ask,
@@ -50,24 +67,27 @@ pub struct GuaranteedStopLossOrderRequest {
     /// results in. So for a Guaranteed Stop Loss Order for a long
     /// trade valid values are “DEFAULT” and “BID”, and for short
     /// trades “DEFAULT” and “ASK” are valid.
-    #[serde_inline_default("DEFAULT")]
-    trigger_condition: OrderTriggerCondition,
+    #[serde(rename = "triggerCondition")]
+    #[serde_inline_default(OrderTriggerCondition::Default)]
+    pub trigger_condition: OrderTriggerCondition,
     /// The client extensions to add to the Order. Do not set,
     /// modify, or delete clientExtensions if your account is
     /// associated with MT4.
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
 }
 impl Default for GuaranteedStopLossOrderRequest {
     fn default() -> Self {
         Self {
-            r#type: "GUARANTEED_STOP_LOSS",
+            r#type: OrderType::GuaranteedStopLoss,
             trade_id: Default::default(),
             client_trade_id: Default::default(),
             price: Default::default(),
             distance: Default::default(),
-            time_in_force: "GTC",
+            time_in_force: TimeInForce::Gtc,
             gtd_time: Default::default(),
-            trigger_condition: "DEFAULT",
+            trigger_condition: OrderTriggerCondition::Default,
             client_extensions: Default::default(),
         }
     }
@@ -1,50 +1,82 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::client_id::ClientID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_order_reason::GuaranteedStopLossOrderReason;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::order_trigger_condition::OrderTriggerCondition;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::time_in_force::TimeInForce;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set
     /// to “GUARANTEED_STOP_LOSS_ORDER” in a
     /// GuaranteedStopLossOrderTransaction.
-    #[serde_inline_default("GUARANTEED_STOP_LOSS_ORDER")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::GuaranteedStopLossOrder)]
+    pub r#type: TransactionType,
     /// The ID of the Trade to close when the price threshold is
     /// breached.
-    trade_id: TradeID,
+    #[serde(rename = "tradeID")]
+    pub trade_id: TradeID,
     /// The client ID of the Trade to be closed when the price
     /// threshold is breached.
-    client_trade_id: Option<ClientID>,
+    #[serde(rename = "clientTradeID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<ClientID>,
     /// The price threshold specified for the Guaranteed Stop Loss
     /// Order. The associated Trade will be closed at this price.
-    price: PriceValue,
+    pub price: PriceValue,
     /// Specifies the distance (in price units) from the Account’s
     /// current price to use as the Guaranteed Stop Loss Order
     /// price. If the Trade is short the Instrument’s bid price is
     /// used, and for long Trades the ask is used.
-    distance: Option<DecimalNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<DecimalNumber>,
     /// The time-in-force requested for the GuaranteedStopLoss
     /// Order. Restricted to “GTC”, “GFD” and “GTD” for
     /// GuaranteedStopLoss Orders.
-    #[serde_inline_default("GTC")]
-    time_in_force: TimeInForce,
+    #[serde(rename = "timeInForce")]
+    #[serde_inline_default(TimeInForce::Gtc)]
+    pub time_in_force: TimeInForce,
     /// The date/time when the GuaranteedStopLoss Order will be
     /// cancelled if its timeInForce is “GTD”.
-    gtd_time: Option<DateTime<Utc>>,
+    #[serde(rename = "gtdTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime>,
     /// Specification of which price component should be used when
     /// determining if an Order should be triggered and filled.
     /// This allows Orders to be triggered based on the bid, ask,
@@ -66,28 +98,40 @@ pub struct GuaranteedStopLossOrderTransaction {
     /// results in. So for a Guaranteed Stop Loss Order for a long
     /// trade valid values are “DEFAULT” and “BID”, and for short
     /// trades “DEFAULT” and “ASK” are valid.
-    #[serde_inline_default("DEFAULT")]
-    trigger_condition: OrderTriggerCondition,
+    #[serde(rename = "triggerCondition")]
+    #[serde_inline_default(OrderTriggerCondition::Default)]
+    pub trigger_condition: OrderTriggerCondition,
     /// The fee that will be charged if the Guaranteed Stop Loss
     /// Order is filled at the guaranteed price. The value is
     /// determined at Order creation time. It is in price units and
     /// is charged for each unit of the Trade.
-    guaranteed_execution_premium: Option<DecimalNumber>,
+    #[serde(rename = "guaranteedExecutionPremium")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_execution_premium: Option<DecimalNumber>,
     /// The reason that the Guaranteed Stop Loss Order was initiated
-    reason: Option<GuaranteedStopLossOrderReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<GuaranteedStopLossOrderReason>,
     /// Client Extensions to add to the Order (only provided if the
     /// Order is being created with client extensions).
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The ID of the OrderFill Transaction that caused this Order
     /// to be created (only provided if this Order was created
     /// automatically when another Order was filled).
-    order_fill_transaction_id: Option<TransactionID>,
+    #[serde(rename = "orderFillTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction_id: Option<TransactionID>,
     /// The ID of the Order that this Order replaces (only provided
     /// if this Order replaces an existing Order).
-    replaces_order_id: Option<OrderID>,
+    #[serde(rename = "replacesOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaces_order_id: Option<OrderID>,
     /// The ID of the Transaction that cancels the replaced Order
     /// (only provided if this Order replaces an existing Order).
-    cancelling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "cancellingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelling_transaction_id: Option<TransactionID>,
 }
 impl Default for GuaranteedStopLossOrderTransaction {
     fn default() -> Self {
@@ -98,14 +142,14 @@ impl Default for GuaranteedStopLossOrderTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "GUARANTEED_STOP_LOSS_ORDER",
+            r#type: TransactionType::GuaranteedStopLossOrder,
             trade_id: Default::default(),
             client_trade_id: Default::default(),
             price: Default::default(),
             distance: Default::default(),
-            time_in_force: "GTC",
+            time_in_force: TimeInForce::Gtc,
             gtd_time: Default::default(),
-            trigger_condition: "DEFAULT",
+            trigger_condition: OrderTriggerCondition::Default,
             guaranteed_execution_premium: Default::default(),
             reason: Default::default(),
             client_extensions: Default::default(),
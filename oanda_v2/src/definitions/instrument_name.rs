@@ -4,6 +4,18 @@ use serde::{Serialize, Deserialize};
 ///
 /// A string containing the base currency and quote currency
 /// delimited by a “_”.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct InstrumentName(String);
 impl std::ops::Deref for InstrumentName {
     type Target = str;
@@ -11,3 +23,18 @@ impl std::ops::Deref for InstrumentName {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for InstrumentName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for InstrumentName {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for InstrumentName {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
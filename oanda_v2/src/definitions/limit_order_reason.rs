@@ -1,6 +1,16 @@
 use serde::{Serialize, Deserialize};
 /// The reason that the Limit Order was initiated
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum LimitOrderReason {
     /// The Limit Order was initiated at the request of a client
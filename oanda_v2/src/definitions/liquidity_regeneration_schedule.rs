@@ -1,12 +1,8 @@
+use crate::definitions::liquidity_regeneration_schedule_step::LiquidityRegenerationScheduleStep;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LiquidityRegenerationSchedule {
     /// The steps in the Liquidity Regeneration Schedule
-    steps: Vec<LiquidityRegenerationScheduleStep>,
-}
-impl Default for LiquidityRegenerationSchedule {
-    fn default() -> Self {
-        Self { steps: Default::default() }
-    }
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<LiquidityRegenerationScheduleStep>,
 }
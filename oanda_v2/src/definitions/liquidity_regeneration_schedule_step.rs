@@ -1,22 +1,19 @@
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LiquidityRegenerationScheduleStep {
     /// The timestamp of the schedule step.
-    timestamp: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime>,
     /// The amount of bid liquidity used at this step in the
     /// schedule.
-    bid_liquidity_used: Option<DecimalNumber>,
+    #[serde(rename = "bidLiquidityUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bid_liquidity_used: Option<DecimalNumber>,
     /// The amount of ask liquidity used at this step in the
     /// schedule.
-    ask_liquidity_used: Option<DecimalNumber>,
-}
-impl Default for LiquidityRegenerationScheduleStep {
-    fn default() -> Self {
-        Self {
-            timestamp: Default::default(),
-            bid_liquidity_used: Default::default(),
-            ask_liquidity_used: Default::default(),
-        }
-    }
+    #[serde(rename = "askLiquidityUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ask_liquidity_used: Option<DecimalNumber>,
 }
@@ -1,27 +1,51 @@
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::order_position_fill::OrderPositionFill;
+use crate::definitions::order_state::OrderState;
+use crate::definitions::order_trigger_condition::OrderTriggerCondition;
+use crate::definitions::order_type::OrderType;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::time_in_force::TimeInForce;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use crate::definitions::transaction_id::TransactionID;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrder {
     /// The Order’s identifier, unique within the Order’s Account.
-    id: Option<OrderID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<OrderID>,
     /// The time when the Order was created.
-    create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "createTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<DateTime>,
     /// The current state of the Order.
-    state: Option<OrderState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrderState>,
     /// The client extensions of the Order. Do not set, modify, or
     /// delete clientExtensions if your account is associated with
     /// MT4.
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The type of the Order. Always set to “MARKET_IF_TOUCHED” for
     /// Market If Touched Orders.
-    #[serde_inline_default("MARKET_IF_TOUCHED")]
-    r#type: OrderType,
+    #[serde_inline_default(OrderType::MarketIfTouched)]
+    pub r#type: OrderType,
     /// The MarketIfTouched Order’s Instrument.
-    instrument: InstrumentName,
+    pub instrument: InstrumentName,
     /// The quantity requested to be filled by the MarketIfTouched
     /// Order. A positive number of units results in a long Order,
     /// and a negative number of units results in a short Order.
-    units: DecimalNumber,
+    pub units: DecimalNumber,
     /// The price threshold specified for the MarketIfTouched Order.
     /// The MarketIfTouched Order will only be filled by a market
     /// price that crosses this price from the direction of the
@@ -29,22 +53,28 @@ pub struct MarketIfTouchedOrder {
     /// initialMarketPrice). Depending on the value of the Order’s
     /// price and initialMarketPrice, the MarketIfTouchedOrder will
     /// behave like a Limit or a Stop Order.
-    price: PriceValue,
+    pub price: PriceValue,
     /// The worst market price that may be used to fill this
     /// MarketIfTouched Order.
-    price_bound: Option<PriceValue>,
+    #[serde(rename = "priceBound")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_bound: Option<PriceValue>,
     /// The time-in-force requested for the MarketIfTouched Order.
     /// Restricted to “GTC”, “GFD” and “GTD” for MarketIfTouched
     /// Orders.
-    #[serde_inline_default("GTC")]
-    time_in_force: TimeInForce,
+    #[serde(rename = "timeInForce")]
+    #[serde_inline_default(TimeInForce::Gtc)]
+    pub time_in_force: TimeInForce,
     /// The date/time when the MarketIfTouched Order will be
     /// cancelled if its timeInForce is “GTD”.
-    gtd_time: Option<DateTime<Utc>>,
+    #[serde(rename = "gtdTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime>,
     /// Specification of how Positions in the Account are modified
     /// when the Order is filled.
-    #[serde_inline_default("DEFAULT")]
-    position_fill: OrderPositionFill,
+    #[serde(rename = "positionFill")]
+    #[serde_inline_default(OrderPositionFill::Default)]
+    pub position_fill: OrderPositionFill,
     /// Specification of which price component should be used when
     /// determining if an Order should be triggered and filled.
     /// This allows Orders to be triggered based on the bid, ask,
@@ -66,73 +96,104 @@ pub struct MarketIfTouchedOrder {
     /// results in. So for a Guaranteed Stop Loss Order for a long
     /// trade valid values are “DEFAULT” and “BID”, and for short
     /// trades “DEFAULT” and “ASK” are valid.
-    #[serde_inline_default("DEFAULT")]
-    trigger_condition: OrderTriggerCondition,
+    #[serde(rename = "triggerCondition")]
+    #[serde_inline_default(OrderTriggerCondition::Default)]
+    pub trigger_condition: OrderTriggerCondition,
     /// The Market price at the time when the MarketIfTouched Order
     /// was created.
-    initial_market_price: Option<PriceValue>,
+    #[serde(rename = "initialMarketPrice")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_market_price: Option<PriceValue>,
     /// TakeProfitDetails specifies the details of a Take Profit
     /// Order to be created on behalf of a client. This may happen
     /// when an Order is filled that opens a Trade requiring a Take
     /// Profit, or when a Trade’s dependent Take Profit Order is
     /// modified directly through the Trade.
-    take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(rename = "takeProfitOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
     /// StopLossDetails specifies the details of a Stop Loss Order
     /// to be created on behalf of a client. This may happen when
     /// an Order is filled that opens a Trade requiring a Stop Loss,
     /// or when a Trade’s dependent Stop Loss Order is modified
     /// directly through the Trade.
-    stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(rename = "stopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
     /// GuaranteedStopLossDetails specifies the details of a
     /// Guaranteed Stop Loss Order to be created on behalf of a
     /// client. This may happen when an Order is filled that opens
     /// a Trade requiring a Guaranteed Stop Loss, or when a Trade’s
     /// dependent Guaranteed Stop Loss Order is modified directly
     /// through the Trade.
-    guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(rename = "guaranteedStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
     /// TrailingStopLossDetails specifies the details of a Trailing
     /// Stop Loss Order to be created on behalf of a client. This
     /// may happen when an Order is filled that opens a Trade
     /// requiring a Trailing Stop Loss, or when a Trade’s dependent
     /// Trailing Stop Loss Order is modified directly through the
     /// Trade.
-    trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(rename = "trailingStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
     /// Client Extensions to add to the Trade created when the
     /// Order is filled (if such a Trade is created). Do not set,
     /// modify, or delete tradeClientExtensions if your account is
     /// associated with MT4.
-    trade_client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
     /// ID of the Transaction that filled this Order (only provided
     /// when the Order’s state is FILLED)
-    filling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "fillingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filling_transaction_id: Option<TransactionID>,
     /// Date/time when the Order was filled (only provided when the
     /// Order’s state is FILLED)
-    filled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "filledTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filled_time: Option<DateTime>,
     /// Trade ID of Trade opened when the Order was filled (only
     /// provided when the Order’s state is FILLED and a Trade was
     /// opened as a result of the fill)
-    trade_opened_id: Option<TradeID>,
+    #[serde(rename = "tradeOpenedID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_opened_id: Option<TradeID>,
     /// Trade ID of Trade reduced when the Order was filled (only
     /// provided when the Order’s state is FILLED and a Trade was
     /// reduced as a result of the fill)
-    trade_reduced_id: Option<TradeID>,
+    #[serde(rename = "tradeReducedID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_reduced_id: Option<TradeID>,
     /// Trade IDs of Trades closed when the Order was filled (only
     /// provided when the Order’s state is FILLED and one or more
     /// Trades were closed as a result of the fill)
-    trade_closed_i_ds: Vec<TradeID>,
+    #[serde(rename = "tradeClosedIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trade_closed_ids: Vec<TradeID>,
     /// ID of the Transaction that cancelled the Order (only
     /// provided when the Order’s state is CANCELLED)
-    cancelling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "cancellingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelling_transaction_id: Option<TransactionID>,
     /// Date/time when the Order was cancelled (only provided when
     /// the state of the Order is CANCELLED)
-    cancelled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "cancelledTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelled_time: Option<DateTime>,
     /// The ID of the Order that was replaced by this Order (only
     /// provided if this Order was created as part of a cancel/
     /// replace).
-    replaces_order_id: Option<OrderID>,
+    #[serde(rename = "replacesOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaces_order_id: Option<OrderID>,
     /// The ID of the Order that replaced this Order (only provided
     /// if this Order was cancelled as part of a cancel/replace).
-    replaced_by_order_id: Option<OrderID>,
+    #[serde(rename = "replacedByOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by_order_id: Option<OrderID>,
 }
 impl Default for MarketIfTouchedOrder {
     fn default() -> Self {
@@ -141,15 +202,15 @@ impl Default for MarketIfTouchedOrder {
             create_time: Default::default(),
             state: Default::default(),
             client_extensions: Default::default(),
-            r#type: "MARKET_IF_TOUCHED",
+            r#type: OrderType::MarketIfTouched,
             instrument: Default::default(),
             units: Default::default(),
             price: Default::default(),
             price_bound: Default::default(),
-            time_in_force: "GTC",
+            time_in_force: TimeInForce::Gtc,
             gtd_time: Default::default(),
-            position_fill: "DEFAULT",
-            trigger_condition: "DEFAULT",
+            position_fill: OrderPositionFill::Default,
+            trigger_condition: OrderTriggerCondition::Default,
             initial_market_price: Default::default(),
             take_profit_on_fill: Default::default(),
             stop_loss_on_fill: Default::default(),
@@ -160,7 +221,7 @@ impl Default for MarketIfTouchedOrder {
             filled_time: Default::default(),
             trade_opened_id: Default::default(),
             trade_reduced_id: Default::default(),
-            trade_closed_i_ds: Default::default(),
+            trade_closed_ids: Default::default(),
             cancelling_transaction_id: Default::default(),
             cancelled_time: Default::default(),
             replaces_order_id: Default::default(),
@@ -1,34 +1,64 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::market_if_touched_order_reason::MarketIfTouchedOrderReason;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::order_position_fill::OrderPositionFill;
+use crate::definitions::order_trigger_condition::OrderTriggerCondition;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::time_in_force::TimeInForce;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_reject_reason::TransactionRejectReason;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderRejectTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set
     /// to “MARKET_IF_TOUCHED_ORDER_REJECT” in a
     /// MarketIfTouchedOrderRejectTransaction.
-    #[serde_inline_default("MARKET_IF_TOUCHED_ORDER_REJECT")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::MarketIfTouchedOrderReject)]
+    pub r#type: TransactionType,
     /// The MarketIfTouched Order’s Instrument.
-    instrument: InstrumentName,
+    pub instrument: InstrumentName,
     /// The quantity requested to be filled by the MarketIfTouched
     /// Order. A positive number of units results in a long Order,
     /// and a negative number of units results in a short Order.
-    units: DecimalNumber,
+    pub units: DecimalNumber,
     /// The price threshold specified for the MarketIfTouched Order.
     /// The MarketIfTouched Order will only be filled by a market
     /// price that crosses this price from the direction of the
@@ -36,22 +66,28 @@ pub struct MarketIfTouchedOrderRejectTransaction {
     /// initialMarketPrice). Depending on the value of the Order’s
     /// price and initialMarketPrice, the MarketIfTouchedOrder will
     /// behave like a Limit or a Stop Order.
-    price: PriceValue,
+    pub price: PriceValue,
     /// The worst market price that may be used to fill this
     /// MarketIfTouched Order.
-    price_bound: Option<PriceValue>,
+    #[serde(rename = "priceBound")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_bound: Option<PriceValue>,
     /// The time-in-force requested for the MarketIfTouched Order.
     /// Restricted to “GTC”, “GFD” and “GTD” for MarketIfTouched
     /// Orders.
-    #[serde_inline_default("GTC")]
-    time_in_force: TimeInForce,
+    #[serde(rename = "timeInForce")]
+    #[serde_inline_default(TimeInForce::Gtc)]
+    pub time_in_force: TimeInForce,
     /// The date/time when the MarketIfTouched Order will be
     /// cancelled if its timeInForce is “GTD”.
-    gtd_time: Option<DateTime<Utc>>,
+    #[serde(rename = "gtdTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gtd_time: Option<DateTime>,
     /// Specification of how Positions in the Account are modified
     /// when the Order is filled.
-    #[serde_inline_default("DEFAULT")]
-    position_fill: OrderPositionFill,
+    #[serde(rename = "positionFill")]
+    #[serde_inline_default(OrderPositionFill::Default)]
+    pub position_fill: OrderPositionFill,
     /// Specification of which price component should be used when
     /// determining if an Order should be triggered and filled.
     /// This allows Orders to be triggered based on the bid, ask,
@@ -73,40 +109,58 @@ pub struct MarketIfTouchedOrderRejectTransaction {
     /// results in. So for a Guaranteed Stop Loss Order for a long
     /// trade valid values are “DEFAULT” and “BID”, and for short
     /// trades “DEFAULT” and “ASK” are valid.
-    #[serde_inline_default("DEFAULT")]
-    trigger_condition: OrderTriggerCondition,
+    #[serde(rename = "triggerCondition")]
+    #[serde_inline_default(OrderTriggerCondition::Default)]
+    pub trigger_condition: OrderTriggerCondition,
     /// The reason that the Market-if-touched Order was initiated
-    reason: Option<MarketIfTouchedOrderReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<MarketIfTouchedOrderReason>,
     /// Client Extensions to add to the Order (only provided if the
     /// Order is being created with client extensions).
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The specification of the Take Profit Order that should be
     /// created for a Trade opened when the Order is filled (if such
     /// a Trade is created).
-    take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(rename = "takeProfitOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
     /// The specification of the Stop Loss Order that should be
     /// created for a Trade opened when the Order is filled (if such
     /// a Trade is created).
-    stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(rename = "stopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
     /// The specification of the Trailing Stop Loss Order that
     /// should be created for a Trade that is opened when the Order
     /// is filled (if such a Trade is created).
-    trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(rename = "trailingStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
     /// The specification of the Guaranteed Stop Loss Order that
     /// should be created for a Trade that is opened when the Order
     /// is filled (if such a Trade is created).
-    guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(rename = "guaranteedStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
     /// Client Extensions to add to the Trade created when the Order
     /// is filled (if such a Trade is created).  Do not set, modify,
     /// delete tradeClientExtensions if your account is associated
     /// with MT4.
-    trade_client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
     /// The ID of the Order that this Order was intended to replace
     /// (only provided if this Order was intended to replace an
     /// existing Order).
-    intended_replaces_order_id: Option<OrderID>,
+    #[serde(rename = "intendedReplacesOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intended_replaces_order_id: Option<OrderID>,
     /// The reason that the Reject Transaction was created
-    reject_reason: Option<TransactionRejectReason>,
+    #[serde(rename = "rejectReason")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<TransactionRejectReason>,
 }
 impl Default for MarketIfTouchedOrderRejectTransaction {
     fn default() -> Self {
@@ -117,15 +171,15 @@ impl Default for MarketIfTouchedOrderRejectTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "MARKET_IF_TOUCHED_ORDER_REJECT",
+            r#type: TransactionType::MarketIfTouchedOrderReject,
             instrument: Default::default(),
             units: Default::default(),
             price: Default::default(),
             price_bound: Default::default(),
-            time_in_force: "GTC",
+            time_in_force: TimeInForce::Gtc,
             gtd_time: Default::default(),
-            position_fill: "DEFAULT",
-            trigger_condition: "DEFAULT",
+            position_fill: OrderPositionFill::Default,
+            trigger_condition: OrderTriggerCondition::Default,
             reason: Default::default(),
             client_extensions: Default::default(),
             take_profit_on_fill: Default::default(),
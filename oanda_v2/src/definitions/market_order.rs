@@ -1,111 +1,176 @@
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::market_order_delayed_trade_close::MarketOrderDelayedTradeClose;
+use crate::definitions::market_order_margin_closeout::MarketOrderMarginCloseout;
+use crate::definitions::market_order_position_closeout::MarketOrderPositionCloseout;
+use crate::definitions::market_order_trade_close::MarketOrderTradeClose;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::order_position_fill::OrderPositionFill;
+use crate::definitions::order_state::OrderState;
+use crate::definitions::order_type::OrderType;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::time_in_force::TimeInForce;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use crate::definitions::transaction_id::TransactionID;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrder {
     /// The Order’s identifier, unique within the Order’s Account.
-    id: Option<OrderID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<OrderID>,
     /// The time when the Order was created.
-    create_time: Option<DateTime<Utc>>,
+    #[serde(rename = "createTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<DateTime>,
     /// The current state of the Order.
-    state: Option<OrderState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrderState>,
     /// The client extensions of the Order. Do not set, modify, or
     /// delete clientExtensions if your account is associated with
     /// MT4.
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The type of the Order. Always set to “MARKET” for Market
     /// Orders.
-    #[serde_inline_default("MARKET")]
-    r#type: OrderType,
+    #[serde_inline_default(OrderType::Market)]
+    pub r#type: OrderType,
     /// The Market Order’s Instrument.
-    instrument: InstrumentName,
+    pub instrument: InstrumentName,
     /// The quantity requested to be filled by the Market Order.
     /// A positive number of units results in a long Order, and a
     /// negative number of units results in a short Order.
-    units: DecimalNumber,
+    pub units: DecimalNumber,
     /// The time-in-force requested for the Market Order. Restricted
     /// to FOK or IOC for a MarketOrder.
-    #[serde_inline_default("FOK")]
-    time_in_force: TimeInForce,
+    #[serde(rename = "timeInForce")]
+    #[serde_inline_default(TimeInForce::Fok)]
+    pub time_in_force: TimeInForce,
     /// The worst price that the client is willing to have the
     /// Market Order filled at.
-    price_bound: Option<PriceValue>,
+    #[serde(rename = "priceBound")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_bound: Option<PriceValue>,
     /// Specification of how Positions in the Account are modified
     /// when the Order is filled.
-    #[serde_inline_default("DEFAULT")]
-    position_fill: OrderPositionFill,
+    #[serde(rename = "positionFill")]
+    #[serde_inline_default(OrderPositionFill::Default)]
+    pub position_fill: OrderPositionFill,
     /// Details of the Trade requested to be closed, only provided
     /// when the Market Order is being used to explicitly close
     /// a Trade.
-    trade_close: Option<MarketOrderTradeClose>,
+    #[serde(rename = "tradeClose")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_close: Option<MarketOrderTradeClose>,
     /// Details of the long Position requested to be closed
     /// out, only provided when a Market Order is being used to
     /// explicitly closeout a long Position.
-    long_position_closeout: Option<MarketOrderPositionCloseout>,
+    #[serde(rename = "longPositionCloseout")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_position_closeout: Option<MarketOrderPositionCloseout>,
     /// Details of the short Position requested to be closed
     /// out, only provided when a Market Order is being used to
     /// explicitly closeout a short Position.
-    short_position_closeout: Option<MarketOrderPositionCloseout>,
+    #[serde(rename = "shortPositionCloseout")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_position_closeout: Option<MarketOrderPositionCloseout>,
     /// Details of the Margin Closeout that this Market Order was
     /// created for
-    margin_closeout: Option<MarketOrderMarginCloseout>,
+    #[serde(rename = "marginCloseout")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout: Option<MarketOrderMarginCloseout>,
     /// Details of the delayed Trade close that this Market Order
     /// was created for
-    delayed_trade_close: Option<MarketOrderDelayedTradeClose>,
+    #[serde(rename = "delayedTradeClose")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delayed_trade_close: Option<MarketOrderDelayedTradeClose>,
     /// TakeProfitDetails specifies the details of a Take Profit
     /// Order to be created on behalf of a client. This may happen
     /// when an Order is filled that opens a Trade requiring a Take
     /// Profit, or when a Trade’s dependent Take Profit Order is
     /// modified directly through the Trade.
-    take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(rename = "takeProfitOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
     /// StopLossDetails specifies the details of a Stop Loss Order
     /// to be created on behalf of a client. This may happen when
     /// an Order is filled that opens a Trade requiring a Stop Loss,
     /// or when a Trade’s dependent Stop Loss Order is modified
     /// directly through the Trade.
-    stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(rename = "stopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
     /// GuaranteedStopLossDetails specifies the details of a
     /// Guaranteed Stop Loss Order to be created on behalf of a
     /// client. This may happen when an Order is filled that opens
     /// a Trade requiring a Guaranteed Stop Loss, or when a Trade’s
     /// dependent Guaranteed Stop Loss Order is modified directly
     /// through the Trade.
-    guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(rename = "guaranteedStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
     /// TrailingStopLossDetails specifies the details of a Trailing
     /// Stop Loss Order to be created on behalf of a client. This
     /// may happen when an Order is filled that opens a Trade
     /// requiring a Trailing Stop Loss, or when a Trade’s dependent
     /// Trailing Stop Loss Order is modified directly through the
     /// Trade.
-    trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(rename = "trailingStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
     /// Client Extensions to add to the Trade created when the
     /// Order is filled (if such a Trade is created). Do not set,
     /// modify, or delete tradeClientExtensions if your account is
     /// associated with MT4.
-    trade_client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
     /// ID of the Transaction that filled this Order (only provided
     /// when the Order’s state is FILLED)
-    filling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "fillingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filling_transaction_id: Option<TransactionID>,
     /// Date/time when the Order was filled (only provided when the
     /// Order’s state is FILLED)
-    filled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "filledTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filled_time: Option<DateTime>,
     /// Trade ID of Trade opened when the Order was filled (only
     /// provided when the Order’s state is FILLED and a Trade was
     /// opened as a result of the fill)
-    trade_opened_id: Option<TradeID>,
+    #[serde(rename = "tradeOpenedID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_opened_id: Option<TradeID>,
     /// Trade ID of Trade reduced when the Order was filled (only
     /// provided when the Order’s state is FILLED and a Trade was
     /// reduced as a result of the fill)
-    trade_reduced_id: Option<TradeID>,
+    #[serde(rename = "tradeReducedID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_reduced_id: Option<TradeID>,
     /// Trade IDs of Trades closed when the Order was filled (only
     /// provided when the Order’s state is FILLED and one or more
     /// Trades were closed as a result of the fill)
-    trade_closed_i_ds: Vec<TradeID>,
+    #[serde(rename = "tradeClosedIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trade_closed_ids: Vec<TradeID>,
     /// ID of the Transaction that cancelled the Order (only
     /// provided when the Order’s state is CANCELLED)
-    cancelling_transaction_id: Option<TransactionID>,
+    #[serde(rename = "cancellingTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelling_transaction_id: Option<TransactionID>,
     /// Date/time when the Order was cancelled (only provided when
     /// the state of the Order is CANCELLED)
-    cancelled_time: Option<DateTime<Utc>>,
+    #[serde(rename = "cancelledTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelled_time: Option<DateTime>,
 }
 impl Default for MarketOrder {
     fn default() -> Self {
@@ -114,12 +179,12 @@ impl Default for MarketOrder {
             create_time: Default::default(),
             state: Default::default(),
             client_extensions: Default::default(),
-            r#type: "MARKET",
+            r#type: OrderType::Market,
             instrument: Default::default(),
             units: Default::default(),
-            time_in_force: "FOK",
+            time_in_force: TimeInForce::Fok,
             price_bound: Default::default(),
-            position_fill: "DEFAULT",
+            position_fill: OrderPositionFill::Default,
             trade_close: Default::default(),
             long_position_closeout: Default::default(),
             short_position_closeout: Default::default(),
@@ -134,7 +199,7 @@ impl Default for MarketOrder {
             filled_time: Default::default(),
             trade_opened_id: Default::default(),
             trade_reduced_id: Default::default(),
-            trade_closed_i_ds: Default::default(),
+            trade_closed_ids: Default::default(),
             cancelling_transaction_id: Default::default(),
             cancelled_time: Default::default(),
         }
@@ -1,13 +1,9 @@
+use crate::definitions::market_order_margin_closeout_reason::MarketOrderMarginCloseoutReason;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MarketOrderMarginCloseout {
     /// The reason the Market Order was created to perform a margin
     /// closeout
-    reason: Option<MarketOrderMarginCloseoutReason>,
-}
-impl Default for MarketOrderMarginCloseout {
-    fn default() -> Self {
-        Self { reason: Default::default() }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<MarketOrderMarginCloseoutReason>,
 }
@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// The reason that the Market Order was created to perform a
 /// margin closeout
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum MarketOrderMarginCloseoutReason {
     /// Trade closures resulted from violating OANDA’s margin policy
@@ -1,88 +1,146 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::market_order_delayed_trade_close::MarketOrderDelayedTradeClose;
+use crate::definitions::market_order_margin_closeout::MarketOrderMarginCloseout;
+use crate::definitions::market_order_position_closeout::MarketOrderPositionCloseout;
+use crate::definitions::market_order_reason::MarketOrderReason;
+use crate::definitions::market_order_trade_close::MarketOrderTradeClose;
+use crate::definitions::order_position_fill::OrderPositionFill;
+use crate::definitions::price_value::PriceValue;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::time_in_force::TimeInForce;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to “MARKET_ORDER” in
     /// a MarketOrderTransaction.
-    #[serde_inline_default("MARKET_ORDER")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::MarketOrder)]
+    pub r#type: TransactionType,
     /// The Market Order’s Instrument.
-    instrument: InstrumentName,
+    pub instrument: InstrumentName,
     /// The quantity requested to be filled by the Market Order.
     /// A positive number of units results in a long Order, and a
     /// negative number of units results in a short Order.
-    units: DecimalNumber,
+    pub units: DecimalNumber,
     /// The time-in-force requested for the Market Order. Restricted
     /// to FOK or IOC for a MarketOrder.
-    #[serde_inline_default("FOK")]
-    time_in_force: TimeInForce,
+    #[serde(rename = "timeInForce")]
+    #[serde_inline_default(TimeInForce::Fok)]
+    pub time_in_force: TimeInForce,
     /// The worst price that the client is willing to have the
     /// Market Order filled at.
-    price_bound: Option<PriceValue>,
+    #[serde(rename = "priceBound")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_bound: Option<PriceValue>,
     /// Specification of how Positions in the Account are modified
     /// when the Order is filled.
-    #[serde_inline_default("DEFAULT")]
-    position_fill: OrderPositionFill,
+    #[serde(rename = "positionFill")]
+    #[serde_inline_default(OrderPositionFill::Default)]
+    pub position_fill: OrderPositionFill,
     /// Details of the Trade requested to be closed, only provided
     /// when the Market Order is being used to explicitly close
     /// a Trade.
-    trade_close: Option<MarketOrderTradeClose>,
+    #[serde(rename = "tradeClose")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_close: Option<MarketOrderTradeClose>,
     /// Details of the long Position requested to be closed
     /// out, only provided when a Market Order is being used to
     /// explicitly closeout a long Position.
-    long_position_closeout: Option<MarketOrderPositionCloseout>,
+    #[serde(rename = "longPositionCloseout")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_position_closeout: Option<MarketOrderPositionCloseout>,
     /// Details of the short Position requested to be closed
     /// out, only provided when a Market Order is being used to
     /// explicitly closeout a short Position.
-    short_position_closeout: Option<MarketOrderPositionCloseout>,
+    #[serde(rename = "shortPositionCloseout")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_position_closeout: Option<MarketOrderPositionCloseout>,
     /// Details of the Margin Closeout that this Market Order was
     /// created for
-    margin_closeout: Option<MarketOrderMarginCloseout>,
+    #[serde(rename = "marginCloseout")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_closeout: Option<MarketOrderMarginCloseout>,
     /// Details of the delayed Trade close that this Market Order
     /// was created for
-    delayed_trade_close: Option<MarketOrderDelayedTradeClose>,
+    #[serde(rename = "delayedTradeClose")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delayed_trade_close: Option<MarketOrderDelayedTradeClose>,
     /// The reason that the Market Order was created
-    reason: Option<MarketOrderReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<MarketOrderReason>,
     /// Client Extensions to add to the Order (only provided if the
     /// Order is being created with client extensions).
-    client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
     /// The specification of the Take Profit Order that should be
     /// created for a Trade opened when the Order is filled (if such
     /// a Trade is created).
-    take_profit_on_fill: Option<TakeProfitDetails>,
+    #[serde(rename = "takeProfitOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_on_fill: Option<TakeProfitDetails>,
     /// The specification of the Stop Loss Order that should be
     /// created for a Trade opened when the Order is filled (if such
     /// a Trade is created).
-    stop_loss_on_fill: Option<StopLossDetails>,
+    #[serde(rename = "stopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_on_fill: Option<StopLossDetails>,
     /// The specification of the Trailing Stop Loss Order that
     /// should be created for a Trade that is opened when the Order
     /// is filled (if such a Trade is created).
-    trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
+    #[serde(rename = "trailingStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_on_fill: Option<TrailingStopLossDetails>,
     /// The specification of the Guaranteed Stop Loss Order that
     /// should be created for a Trade that is opened when the Order
     /// is filled (if such a Trade is created).
-    guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
+    #[serde(rename = "guaranteedStopLossOnFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_on_fill: Option<GuaranteedStopLossDetails>,
     /// Client Extensions to add to the Trade created when the Order
     /// is filled (if such a Trade is created).  Do not set, modify,
     /// delete tradeClientExtensions if your account is associated
     /// with MT4.
-    trade_client_extensions: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
 }
 impl Default for MarketOrderTransaction {
     fn default() -> Self {
@@ -93,12 +151,12 @@ impl Default for MarketOrderTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "MARKET_ORDER",
+            r#type: TransactionType::MarketOrder,
             instrument: Default::default(),
             units: Default::default(),
-            time_in_force: "FOK",
+            time_in_force: TimeInForce::Fok,
             price_bound: Default::default(),
-            position_fill: "DEFAULT",
+            position_fill: OrderPositionFill::Default,
             trade_close: Default::default(),
             long_position_closeout: Default::default(),
             short_position_closeout: Default::default(),
@@ -1,18 +1,27 @@
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::trade_id::TradeID;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OpenTradeFinancing {
     /// The ID of the Trade that financing is being paid/collected
     /// for.
-    trade_id: Option<TradeID>,
+    #[serde(rename = "tradeID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_id: Option<TradeID>,
     /// The amount of financing paid/collected for the Trade.
-    financing: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financing: Option<AccountUnits>,
     /// The amount of financing paid/collected in the Instrument’s
     /// base currency for the Trade.
-    base_financing: Option<DecimalNumber>,
+    #[serde(rename = "baseFinancing")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_financing: Option<DecimalNumber>,
     /// The amount of financing paid/collected in the Instrument’s
     /// quote currency for the Trade.
-    quote_financing: Option<DecimalNumber>,
+    #[serde(rename = "quoteFinancing")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_financing: Option<DecimalNumber>,
     /// The financing rate in effect for the instrument used
     /// to calculate the the amount of financing paid/collected
     /// for the Trade. This field will only be set if the
@@ -20,16 +29,7 @@ pub struct OpenTradeFinancing {
     /// DAILY_INSTRUMENT or SECOND_BY_SECOND_INSTRUMENT. The value
     /// is in decimal rather than percentage points, e.g. 5% is
     /// represented as 0.05.
-    financing_rate: Option<DecimalNumber>,
-}
-impl Default for OpenTradeFinancing {
-    fn default() -> Self {
-        Self {
-            trade_id: Default::default(),
-            financing: Default::default(),
-            base_financing: Default::default(),
-            quote_financing: Default::default(),
-            financing_rate: Default::default(),
-        }
-    }
+    #[serde(rename = "financingRate")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financing_rate: Option<DecimalNumber>,
 }
@@ -1,25 +1,100 @@
+use crate::definitions::fixed_price_order::FixedPriceOrder;
+use crate::definitions::guaranteed_stop_loss_order::GuaranteedStopLossOrder;
+use crate::definitions::limit_order::LimitOrder;
+use crate::definitions::market_if_touched_order::MarketIfTouchedOrder;
+use crate::definitions::market_order::MarketOrder;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::stop_loss_order::StopLossOrder;
+use crate::definitions::stop_order::StopOrder;
+use crate::definitions::take_profit_order::TakeProfitOrder;
+use crate::definitions::trailing_stop_loss_order::TrailingStopLossOrder;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
-pub struct Order {
-    /// The Order’s identifier, unique within the Order’s Account.
-    id: Option<OrderID>,
-    /// The time when the Order was created.
-    create_time: Option<DateTime<Utc>>,
-    /// The current state of the Order.
-    state: Option<OrderState>,
-    /// The client extensions of the Order. Do not set, modify, or
-    /// delete clientExtensions if your account is associated with
-    /// MT4.
-    client_extensions: Option<ClientExtensions>,
+/// The base Order definition specifies the properties that are
+/// common to all Orders.
+#[derive(Debug, Clone)]
+pub enum Order {
+    /// A Market Order
+    Market(Box<MarketOrder>),
+    /// A Limit Order
+    Limit(Box<LimitOrder>),
+    /// A Stop Order
+    Stop(Box<StopOrder>),
+    /// A Market-if-touched Order
+    MarketIfTouched(Box<MarketIfTouchedOrder>),
+    /// A Take Profit Order
+    TakeProfit(Box<TakeProfitOrder>),
+    /// A Stop Loss Order
+    StopLoss(Box<StopLossOrder>),
+    /// A Guaranteed Stop Loss Order
+    GuaranteedStopLoss(Box<GuaranteedStopLossOrder>),
+    /// A Trailing Stop Loss Order
+    TrailingStopLoss(Box<TrailingStopLossOrder>),
+    /// A Fixed Price Order
+    FixedPrice(Box<FixedPriceOrder>),
+    /// A type that isn't documented yet, as the json the server sent
+    Unknown(serde_json::Value),
 }
-impl Default for Order {
-    fn default() -> Self {
-        Self {
-            id: Default::default(),
-            create_time: Default::default(),
-            state: Default::default(),
-            client_extensions: Default::default(),
+impl<'de> Deserialize<'de> for Order {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("type").and_then(serde_json::Value::as_str) {
+            Some("MARKET") => crate::error::from_value(value).map(Order::Market),
+            Some("LIMIT") => crate::error::from_value(value).map(Order::Limit),
+            Some("STOP") => crate::error::from_value(value).map(Order::Stop),
+            Some("MARKET_IF_TOUCHED") => {
+                crate::error::from_value(value).map(Order::MarketIfTouched)
+            }
+            Some("TAKE_PROFIT") => crate::error::from_value(value).map(Order::TakeProfit),
+            Some("STOP_LOSS") => crate::error::from_value(value).map(Order::StopLoss),
+            Some("GUARANTEED_STOP_LOSS") => {
+                crate::error::from_value(value).map(Order::GuaranteedStopLoss)
+            }
+            Some("TRAILING_STOP_LOSS") => {
+                crate::error::from_value(value).map(Order::TrailingStopLoss)
+            }
+            Some("FIXED_PRICE") => crate::error::from_value(value).map(Order::FixedPrice),
+            _ => Ok(Order::Unknown(value)),
+        }
+    }
+}
+impl Serialize for Order {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Order::Market(inner) => inner.serialize(serializer),
+            Order::Limit(inner) => inner.serialize(serializer),
+            Order::Stop(inner) => inner.serialize(serializer),
+            Order::MarketIfTouched(inner) => inner.serialize(serializer),
+            Order::TakeProfit(inner) => inner.serialize(serializer),
+            Order::StopLoss(inner) => inner.serialize(serializer),
+            Order::GuaranteedStopLoss(inner) => inner.serialize(serializer),
+            Order::TrailingStopLoss(inner) => inner.serialize(serializer),
+            Order::FixedPrice(inner) => inner.serialize(serializer),
+            Order::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+impl Order {
+    /// The `id` of the type it holds. For an `Unknown` type, it's read from the json, if
+    /// it's there
+    pub fn id(&self) -> Option<OrderID> {
+        match self {
+            Order::Market(inner) => inner.id.clone(),
+            Order::Limit(inner) => inner.id.clone(),
+            Order::Stop(inner) => inner.id.clone(),
+            Order::MarketIfTouched(inner) => inner.id.clone(),
+            Order::TakeProfit(inner) => inner.id.clone(),
+            Order::StopLoss(inner) => inner.id.clone(),
+            Order::GuaranteedStopLoss(inner) => inner.id.clone(),
+            Order::TrailingStopLoss(inner) => inner.id.clone(),
+            Order::FixedPrice(inner) => inner.id.clone(),
+            Order::Unknown(value) => {
+                value.get("id").and_then(|id| serde_json::from_value(id.clone()).ok())
+            }
         }
     }
 }
@@ -1,6 +1,16 @@
 use serde::{Serialize, Deserialize};
 /// The reason that an Order was cancelled.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderCancelReason {
     /// The Order was cancelled because at the time of filling, an
@@ -1,34 +1,58 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_reject_reason::TransactionRejectReason;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderCancelRejectTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to
     /// “ORDER_CANCEL_REJECT” for an OrderCancelRejectTransaction.
-    #[serde_inline_default("ORDER_CANCEL_REJECT")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::OrderCancelReject)]
+    pub r#type: TransactionType,
     /// The ID of the Order intended to be cancelled
-    order_id: Option<OrderID>,
+    #[serde(rename = "orderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<OrderID>,
     /// The client ID of the Order intended to be cancelled (only
     /// provided if the Order has a client Order ID).
-    client_order_id: Option<OrderID>,
+    #[serde(rename = "clientOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<OrderID>,
     /// The reason that the Reject Transaction was created
-    reject_reason: Option<TransactionRejectReason>,
+    #[serde(rename = "rejectReason")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<TransactionRejectReason>,
 }
 impl Default for OrderCancelRejectTransaction {
     fn default() -> Self {
@@ -39,7 +63,7 @@ impl Default for OrderCancelRejectTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "ORDER_CANCEL_REJECT",
+            r#type: TransactionType::OrderCancelReject,
             order_id: Default::default(),
             client_order_id: Default::default(),
             reject_reason: Default::default(),
@@ -1,37 +1,62 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::order_cancel_reason::OrderCancelReason;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderCancelTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to “ORDER_CANCEL”
     /// for an OrderCancelTransaction.
-    #[serde_inline_default("ORDER_CANCEL")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::OrderCancel)]
+    pub r#type: TransactionType,
     /// The ID of the Order cancelled
-    order_id: Option<OrderID>,
+    #[serde(rename = "orderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<OrderID>,
     /// The client ID of the Order cancelled (only provided if the
     /// Order has a client Order ID).
-    client_order_id: Option<OrderID>,
+    #[serde(rename = "clientOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<OrderID>,
     /// The reason that the Order was cancelled.
-    reason: Option<OrderCancelReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<OrderCancelReason>,
     /// The ID of the Order that replaced this Order (only provided
     /// if this Order was cancelled for replacement).
-    replaced_by_order_id: Option<OrderID>,
+    #[serde(rename = "replacedByOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by_order_id: Option<OrderID>,
 }
 impl Default for OrderCancelTransaction {
     fn default() -> Self {
@@ -42,7 +67,7 @@ impl Default for OrderCancelTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "ORDER_CANCEL",
+            r#type: TransactionType::OrderCancel,
             order_id: Default::default(),
             client_order_id: Default::default(),
             reason: Default::default(),
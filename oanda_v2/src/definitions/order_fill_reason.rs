@@ -1,6 +1,16 @@
 use serde::{Serialize, Deserialize};
 /// The reason that an Order was filled
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderFillReason {
     /// The Order filled was a Limit Order
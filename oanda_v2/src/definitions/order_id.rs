@@ -7,6 +7,18 @@ use serde::{Serialize, Deserialize};
 /// created the Order.
 ///
 /// Example: 1523
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct OrderID(String);
 impl std::ops::Deref for OrderID {
     type Target = str;
@@ -14,3 +26,18 @@ impl std::ops::Deref for OrderID {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for OrderID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for OrderID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for OrderID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -1,17 +1,14 @@
+use crate::definitions::client_id::ClientID;
+use crate::definitions::order_id::OrderID;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OrderIdentifier {
     /// The OANDA-assigned Order ID
-    order_id: Option<OrderID>,
+    #[serde(rename = "orderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<OrderID>,
     /// The client-provided client Order ID
-    client_order_id: Option<ClientID>,
-}
-impl Default for OrderIdentifier {
-    fn default() -> Self {
-        Self {
-            order_id: Default::default(),
-            client_order_id: Default::default(),
-        }
-    }
+    #[serde(rename = "clientOrderID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<ClientID>,
 }
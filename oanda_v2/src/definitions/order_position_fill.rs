@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// Specification of how Positions in the Account are modified
 /// when the Order is filled.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderPositionFill {
     /// When the Order is filled, only allow Positions to be opened
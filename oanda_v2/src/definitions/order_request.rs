@@ -1,14 +1,79 @@
+use crate::definitions::guaranteed_stop_loss_order_request::GuaranteedStopLossOrderRequest;
+use crate::definitions::limit_order_request::LimitOrderRequest;
+use crate::definitions::market_if_touched_order_request::MarketIfTouchedOrderRequest;
+use crate::definitions::market_order_request::MarketOrderRequest;
+use crate::definitions::stop_loss_order_request::StopLossOrderRequest;
+use crate::definitions::stop_order_request::StopOrderRequest;
+use crate::definitions::take_profit_order_request::TakeProfitOrderRequest;
+use crate::definitions::trailing_stop_loss_order_request::TrailingStopLossOrderRequest;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-pub struct OrderRequest(String);
-impl ToString for OrderRequest {
-    fn to_string(&self) -> String {
-        self.0
+/// The base Order specification used when requesting that an
+/// Order be created. Each specific Order-type extends this
+/// definition.
+#[derive(Debug, Clone)]
+pub enum OrderRequest {
+    /// A Market Order
+    Market(Box<MarketOrderRequest>),
+    /// A Limit Order
+    Limit(Box<LimitOrderRequest>),
+    /// A Stop Order
+    Stop(Box<StopOrderRequest>),
+    /// A Market-if-touched Order
+    MarketIfTouched(Box<MarketIfTouchedOrderRequest>),
+    /// A Take Profit Order
+    TakeProfit(Box<TakeProfitOrderRequest>),
+    /// A Stop Loss Order
+    StopLoss(Box<StopLossOrderRequest>),
+    /// A Guaranteed Stop Loss Order
+    GuaranteedStopLoss(Box<GuaranteedStopLossOrderRequest>),
+    /// A Trailing Stop Loss Order
+    TrailingStopLoss(Box<TrailingStopLossOrderRequest>),
+    /// A type that isn't documented yet, as the json the server sent
+    Unknown(serde_json::Value),
+}
+impl<'de> Deserialize<'de> for OrderRequest {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("type").and_then(serde_json::Value::as_str) {
+            Some("MARKET") => crate::error::from_value(value).map(OrderRequest::Market),
+            Some("LIMIT") => crate::error::from_value(value).map(OrderRequest::Limit),
+            Some("STOP") => crate::error::from_value(value).map(OrderRequest::Stop),
+            Some("MARKET_IF_TOUCHED") => {
+                crate::error::from_value(value).map(OrderRequest::MarketIfTouched)
+            }
+            Some("TAKE_PROFIT") => {
+                crate::error::from_value(value).map(OrderRequest::TakeProfit)
+            }
+            Some("STOP_LOSS") => {
+                crate::error::from_value(value).map(OrderRequest::StopLoss)
+            }
+            Some("GUARANTEED_STOP_LOSS") => {
+                crate::error::from_value(value).map(OrderRequest::GuaranteedStopLoss)
+            }
+            Some("TRAILING_STOP_LOSS") => {
+                crate::error::from_value(value).map(OrderRequest::TrailingStopLoss)
+            }
+            _ => Ok(OrderRequest::Unknown(value)),
+        }
     }
 }
-impl std::ops::Deref for X {
-    type Target = str;
-    fn deref(&self) -> &Self::Target {
-        self.0.as_str()
+impl Serialize for OrderRequest {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            OrderRequest::Market(inner) => inner.serialize(serializer),
+            OrderRequest::Limit(inner) => inner.serialize(serializer),
+            OrderRequest::Stop(inner) => inner.serialize(serializer),
+            OrderRequest::MarketIfTouched(inner) => inner.serialize(serializer),
+            OrderRequest::TakeProfit(inner) => inner.serialize(serializer),
+            OrderRequest::StopLoss(inner) => inner.serialize(serializer),
+            OrderRequest::GuaranteedStopLoss(inner) => inner.serialize(serializer),
+            OrderRequest::TrailingStopLoss(inner) => inner.serialize(serializer),
+            OrderRequest::Unknown(value) => value.serialize(serializer),
+        }
     }
 }
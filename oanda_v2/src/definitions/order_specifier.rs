@@ -5,6 +5,18 @@ use serde::{Serialize, Deserialize};
 /// Order’s client-provided ClientID prefixed by the “@” symbol
 ///
 /// Example: 1523
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct OrderSpecifier(String);
 impl std::ops::Deref for OrderSpecifier {
     type Target = str;
@@ -12,3 +24,18 @@ impl std::ops::Deref for OrderSpecifier {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for OrderSpecifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for OrderSpecifier {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for OrderSpecifier {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -21,7 +21,17 @@ use serde::{Serialize, Deserialize};
 /// “DEFAULT” results in. So for a Guaranteed Stop Loss Order
 /// for a long trade valid values are “DEFAULT” and “BID”, and
 /// for short trades “DEFAULT” and “ASK” are valid.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderTriggerCondition {
     /// Trigger an Order the “natural” way: compare its price to the
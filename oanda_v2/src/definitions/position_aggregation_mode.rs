@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// The way that position values for an Account are calculated
 /// and aggregated.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PositionAggregationMode {
     /// The Position value or margin for each side (long and
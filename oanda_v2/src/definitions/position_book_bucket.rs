@@ -1,24 +1,21 @@
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::price_value::PriceValue;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PositionBookBucket {
     /// The lowest price (inclusive) covered by the bucket. The
     /// bucket covers the price range from the price to price + the
     /// position book’s bucketWidth.
-    price: Option<PriceValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceValue>,
     /// The percentage of the total number of positions represented
     /// by the long positions found in this bucket.
-    long_count_percent: Option<DecimalNumber>,
+    #[serde(rename = "longCountPercent")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_count_percent: Option<DecimalNumber>,
     /// The percentage of the total number of positions represented
     /// by the short positions found in this bucket.
-    short_count_percent: Option<DecimalNumber>,
-}
-impl Default for PositionBookBucket {
-    fn default() -> Self {
-        Self {
-            price: Default::default(),
-            long_count_percent: Default::default(),
-            short_count_percent: Default::default(),
-        }
-    }
+    #[serde(rename = "shortCountPercent")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_count_percent: Option<DecimalNumber>,
 }
@@ -1,38 +1,42 @@
+use crate::definitions::account_financing_mode::AccountFinancingMode;
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::home_conversion_factors::HomeConversionFactors;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::open_trade_financing::OpenTradeFinancing;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PositionFinancing {
     /// The instrument of the Position that financing is being paid/
     /// collected for.
-    instrument: Option<InstrumentName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<InstrumentName>,
     /// The amount of financing paid/collected for the Position.
-    financing: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub financing: Option<AccountUnits>,
     /// The amount of base financing paid/collected for the
     /// Position.
-    base_financing: Option<DecimalNumber>,
+    #[serde(rename = "baseFinancing")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_financing: Option<DecimalNumber>,
     /// The amount of quote financing paid/collected for the
     /// Position.
-    quote_financing: Option<DecimalNumber>,
+    #[serde(rename = "quoteFinancing")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_financing: Option<DecimalNumber>,
     /// The HomeConversionFactors in effect for the Position’s
     /// Instrument at the time of the DailyFinancing.
-    home_conversion_factors: Option<HomeConversionFactors>,
+    #[serde(rename = "homeConversionFactors")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_conversion_factors: Option<HomeConversionFactors>,
     /// The financing paid/collected for each open Trade within
     /// the Position.
-    open_trade_financings: Vec<OpenTradeFinancing>,
+    #[serde(rename = "openTradeFinancings")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_trade_financings: Vec<OpenTradeFinancing>,
     /// The account financing mode at the time of the daily
     /// financing.
-    account_financing_mode: Option<AccountFinancingMode>,
-}
-impl Default for PositionFinancing {
-    fn default() -> Self {
-        Self {
-            instrument: Default::default(),
-            financing: Default::default(),
-            base_financing: Default::default(),
-            quote_financing: Default::default(),
-            home_conversion_factors: Default::default(),
-            open_trade_financings: Default::default(),
-            account_financing_mode: Default::default(),
-        }
-    }
+    #[serde(rename = "accountFinancingMode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_financing_mode: Option<AccountFinancingMode>,
 }
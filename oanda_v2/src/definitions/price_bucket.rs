@@ -1,17 +1,12 @@
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::price_value::PriceValue;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PriceBucket {
     /// The Price offered by the PriceBucket
-    price: Option<PriceValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceValue>,
     /// The amount of liquidity offered by the PriceBucket
-    liquidity: Option<Decimal>,
-}
-impl Default for PriceBucket {
-    fn default() -> Self {
-        Self {
-            price: Default::default(),
-            liquidity: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity: Option<DecimalNumber>,
 }
@@ -3,6 +3,18 @@ use serde::{Serialize, Deserialize};
 ///
 /// Can contain any combination of the characters “M” (midpoint
 /// candles) “B” (bid candles) and “A” (ask candles).
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct PricingComponent(String);
 impl std::ops::Deref for PricingComponent {
     type Target = str;
@@ -10,3 +22,18 @@ impl std::ops::Deref for PricingComponent {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for PricingComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for PricingComponent {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for PricingComponent {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
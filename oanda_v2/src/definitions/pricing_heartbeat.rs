@@ -1,17 +1,20 @@
+use crate::definitions::date_time::DateTime;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PricingHeartbeat {
     /// The string “HEARTBEAT”
-    #[serde_inline_default("HEARTBEAT")]
-    r#type: String,
+    #[serde_inline_default(String::from("HEARTBEAT"))]
+    pub r#type: String,
     /// The date/time when the Heartbeat was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
 }
 impl Default for PricingHeartbeat {
     fn default() -> Self {
         Self {
-            r#type: "HEARTBEAT",
+            r#type: String::from("HEARTBEAT"),
             time: Default::default(),
         }
     }
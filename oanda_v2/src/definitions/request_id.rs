@@ -1,7 +1,18 @@
 use serde::{Serialize, Deserialize};
 /// The request identifier.
 ///
-///
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct RequestID(String);
 impl std::ops::Deref for RequestID {
     type Target = str;
@@ -9,3 +20,18 @@ impl std::ops::Deref for RequestID {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for RequestID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for RequestID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for RequestID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -1,17 +1,10 @@
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Tag {
     /// The type of the tag.
-    r#type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
     /// The name of the tag.
-    name: Option<String>,
-}
-impl Default for Tag {
-    fn default() -> Self {
-        Self {
-            r#type: Default::default(),
-            name: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
@@ -2,7 +2,17 @@ use serde::{Serialize, Deserialize};
 /// The time-in-force of an Order. TimeInForce describes
 /// how long an Order should remain pending before being
 /// automatically cancelled by the execution system.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TimeInForce {
     /// The Order is “Good unTil Cancelled”
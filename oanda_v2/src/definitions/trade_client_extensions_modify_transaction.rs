@@ -1,36 +1,61 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::client_id::ClientID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeClientExtensionsModifyTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set
     /// to “TRADE_CLIENT_EXTENSIONS_MODIFY” for a
     /// TradeClientExtensionsModifyTransaction.
-    #[serde_inline_default("TRADE_CLIENT_EXTENSIONS_MODIFY")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::TradeClientExtensionsModify)]
+    pub r#type: TransactionType,
     /// The ID of the Trade who’s client extensions are to be
     /// modified.
-    trade_id: Option<TradeID>,
+    #[serde(rename = "tradeID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_id: Option<TradeID>,
     /// The original Client ID of the Trade who’s client extensions
     /// are to be modified.
-    client_trade_id: Option<ClientID>,
+    #[serde(rename = "clientTradeID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_trade_id: Option<ClientID>,
     /// The new Client Extensions for the Trade.
-    trade_client_extensions_modify: Option<ClientExtensions>,
+    #[serde(rename = "tradeClientExtensionsModify")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions_modify: Option<ClientExtensions>,
 }
 impl Default for TradeClientExtensionsModifyTransaction {
     fn default() -> Self {
@@ -41,7 +66,7 @@ impl Default for TradeClientExtensionsModifyTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "TRADE_CLIENT_EXTENSIONS_MODIFY",
+            r#type: TransactionType::TradeClientExtensionsModify,
             trade_id: Default::default(),
             client_trade_id: Default::default(),
             trade_client_extensions_modify: Default::default(),
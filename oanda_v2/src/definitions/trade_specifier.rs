@@ -5,6 +5,18 @@ use serde::{Serialize, Deserialize};
 /// Trade’s client-provided ClientID prefixed by the “@” symbol
 ///
 /// Example: @my_trade_id
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct TradeSpecifier(String);
 impl std::ops::Deref for TradeSpecifier {
     type Target = str;
@@ -12,3 +24,18 @@ impl std::ops::Deref for TradeSpecifier {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for TradeSpecifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for TradeSpecifier {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for TradeSpecifier {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
@@ -1,33 +1,357 @@
+use crate::definitions::client_configure_reject_transaction::ClientConfigureRejectTransaction;
+use crate::definitions::client_configure_transaction::ClientConfigureTransaction;
+use crate::definitions::close_transaction::CloseTransaction;
+use crate::definitions::create_transaction::CreateTransaction;
+use crate::definitions::daily_financing_transaction::DailyFinancingTransaction;
+use crate::definitions::delayed_trade_closure_transaction::DelayedTradeClosureTransaction;
+use crate::definitions::dividend_adjustment_transaction::DividendAdjustmentTransaction;
+use crate::definitions::fixed_price_order_transaction::FixedPriceOrderTransaction;
+use crate::definitions::guaranteed_stop_loss_order_reject_transaction::GuaranteedStopLossOrderRejectTransaction;
+use crate::definitions::guaranteed_stop_loss_order_transaction::GuaranteedStopLossOrderTransaction;
+use crate::definitions::limit_order_reject_transaction::LimitOrderRejectTransaction;
+use crate::definitions::limit_order_transaction::LimitOrderTransaction;
+use crate::definitions::margin_call_enter_transaction::MarginCallEnterTransaction;
+use crate::definitions::margin_call_exit_transaction::MarginCallExitTransaction;
+use crate::definitions::margin_call_extend_transaction::MarginCallExtendTransaction;
+use crate::definitions::market_if_touched_order_reject_transaction::MarketIfTouchedOrderRejectTransaction;
+use crate::definitions::market_if_touched_order_transaction::MarketIfTouchedOrderTransaction;
+use crate::definitions::market_order_reject_transaction::MarketOrderRejectTransaction;
+use crate::definitions::market_order_transaction::MarketOrderTransaction;
+use crate::definitions::order_cancel_reject_transaction::OrderCancelRejectTransaction;
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::order_client_extensions_modify_reject_transaction::OrderClientExtensionsModifyRejectTransaction;
+use crate::definitions::order_client_extensions_modify_transaction::OrderClientExtensionsModifyTransaction;
+use crate::definitions::order_fill_transaction::OrderFillTransaction;
+use crate::definitions::reopen_transaction::ReopenTransaction;
+use crate::definitions::reset_resettable_pl_transaction::ResetResettablePLTransaction;
+use crate::definitions::stop_loss_order_reject_transaction::StopLossOrderRejectTransaction;
+use crate::definitions::stop_loss_order_transaction::StopLossOrderTransaction;
+use crate::definitions::stop_order_reject_transaction::StopOrderRejectTransaction;
+use crate::definitions::stop_order_transaction::StopOrderTransaction;
+use crate::definitions::take_profit_order_reject_transaction::TakeProfitOrderRejectTransaction;
+use crate::definitions::take_profit_order_transaction::TakeProfitOrderTransaction;
+use crate::definitions::trade_client_extensions_modify_reject_transaction::TradeClientExtensionsModifyRejectTransaction;
+use crate::definitions::trade_client_extensions_modify_transaction::TradeClientExtensionsModifyTransaction;
+use crate::definitions::trailing_stop_loss_order_reject_transaction::TrailingStopLossOrderRejectTransaction;
+use crate::definitions::trailing_stop_loss_order_transaction::TrailingStopLossOrderTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transfer_funds_reject_transaction::TransferFundsRejectTransaction;
+use crate::definitions::transfer_funds_transaction::TransferFundsTransaction;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
-pub struct Transaction {
-    /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
-    /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
-    /// The ID of the user that initiated the creation of the
-    /// Transaction.
-    user_id: Option<integer>,
-    /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
-    /// The ID of the “batch” that the Transaction belongs to.
-    /// Transactions in the same batch are applied to the Account
-    /// simultaneously.
-    batch_id: Option<TransactionID>,
-    /// The Request ID of the request which generated the
-    /// transaction.
-    request_id: Option<RequestID>,
+/// The base Transaction specification. Specifies properties
+/// that are common between all Transaction.
+#[derive(Debug, Clone)]
+pub enum Transaction {
+    /// Account Create Transaction
+    Create(Box<CreateTransaction>),
+    /// Account Close Transaction
+    Close(Box<CloseTransaction>),
+    /// Account Reopen Transaction
+    Reopen(Box<ReopenTransaction>),
+    /// Client Configuration Transaction
+    ClientConfigure(Box<ClientConfigureTransaction>),
+    /// Client Configuration Reject Transaction
+    ClientConfigureReject(Box<ClientConfigureRejectTransaction>),
+    /// Transfer Funds Transaction
+    TransferFunds(Box<TransferFundsTransaction>),
+    /// Transfer Funds Reject Transaction
+    TransferFundsReject(Box<TransferFundsRejectTransaction>),
+    /// Market Order Transaction
+    MarketOrder(Box<MarketOrderTransaction>),
+    /// Market Order Reject Transaction
+    MarketOrderReject(Box<MarketOrderRejectTransaction>),
+    /// Fixed Price Order Transaction
+    FixedPriceOrder(Box<FixedPriceOrderTransaction>),
+    /// Limit Order Transaction
+    LimitOrder(Box<LimitOrderTransaction>),
+    /// Limit Order Reject Transaction
+    LimitOrderReject(Box<LimitOrderRejectTransaction>),
+    /// Stop Order Transaction
+    StopOrder(Box<StopOrderTransaction>),
+    /// Stop Order Reject Transaction
+    StopOrderReject(Box<StopOrderRejectTransaction>),
+    /// Market if Touched Order Transaction
+    MarketIfTouchedOrder(Box<MarketIfTouchedOrderTransaction>),
+    /// Market if Touched Order Reject Transaction
+    MarketIfTouchedOrderReject(Box<MarketIfTouchedOrderRejectTransaction>),
+    /// Take Profit Order Transaction
+    TakeProfitOrder(Box<TakeProfitOrderTransaction>),
+    /// Take Profit Order Reject Transaction
+    TakeProfitOrderReject(Box<TakeProfitOrderRejectTransaction>),
+    /// Stop Loss Order Transaction
+    StopLossOrder(Box<StopLossOrderTransaction>),
+    /// Stop Loss Order Reject Transaction
+    StopLossOrderReject(Box<StopLossOrderRejectTransaction>),
+    /// Guaranteed Stop Loss Order Transaction
+    GuaranteedStopLossOrder(Box<GuaranteedStopLossOrderTransaction>),
+    /// Guaranteed Stop Loss Order Reject Transaction
+    GuaranteedStopLossOrderReject(Box<GuaranteedStopLossOrderRejectTransaction>),
+    /// Trailing Stop Loss Order Transaction
+    TrailingStopLossOrder(Box<TrailingStopLossOrderTransaction>),
+    /// Trailing Stop Loss Order Reject Transaction
+    TrailingStopLossOrderReject(Box<TrailingStopLossOrderRejectTransaction>),
+    /// Order Fill Transaction
+    OrderFill(Box<OrderFillTransaction>),
+    /// Order Cancel Transaction
+    OrderCancel(Box<OrderCancelTransaction>),
+    /// Order Cancel Reject Transaction
+    OrderCancelReject(Box<OrderCancelRejectTransaction>),
+    /// Order Client Extensions Modify Transaction
+    OrderClientExtensionsModify(Box<OrderClientExtensionsModifyTransaction>),
+    /// Order Client Extensions Modify Reject Transaction
+    OrderClientExtensionsModifyReject(Box<OrderClientExtensionsModifyRejectTransaction>),
+    /// Trade Client Extensions Modify Transaction
+    TradeClientExtensionsModify(Box<TradeClientExtensionsModifyTransaction>),
+    /// Trade Client Extensions Modify Reject Transaction
+    TradeClientExtensionsModifyReject(Box<TradeClientExtensionsModifyRejectTransaction>),
+    /// Margin Call Enter Transaction
+    MarginCallEnter(Box<MarginCallEnterTransaction>),
+    /// Margin Call Extend Transaction
+    MarginCallExtend(Box<MarginCallExtendTransaction>),
+    /// Margin Call Exit Transaction
+    MarginCallExit(Box<MarginCallExitTransaction>),
+    /// Delayed Trade Closure Transaction
+    DelayedTradeClosure(Box<DelayedTradeClosureTransaction>),
+    /// Daily Financing Transaction
+    DailyFinancing(Box<DailyFinancingTransaction>),
+    /// Dividend Adjustment Transaction
+    DividendAdjustment(Box<DividendAdjustmentTransaction>),
+    /// Reset Resettable PL Transaction
+    ResetResettablePL(Box<ResetResettablePLTransaction>),
+    /// A type that isn't documented yet, as the json the server sent
+    Unknown(serde_json::Value),
 }
-impl Default for Transaction {
-    fn default() -> Self {
-        Self {
-            id: Default::default(),
-            time: Default::default(),
-            user_id: Default::default(),
-            account_id: Default::default(),
-            batch_id: Default::default(),
-            request_id: Default::default(),
+impl<'de> Deserialize<'de> for Transaction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("type").and_then(serde_json::Value::as_str) {
+            Some("CREATE") => crate::error::from_value(value).map(Transaction::Create),
+            Some("CLOSE") => crate::error::from_value(value).map(Transaction::Close),
+            Some("REOPEN") => crate::error::from_value(value).map(Transaction::Reopen),
+            Some("CLIENT_CONFIGURE") => {
+                crate::error::from_value(value).map(Transaction::ClientConfigure)
+            }
+            Some("CLIENT_CONFIGURE_REJECT") => {
+                crate::error::from_value(value).map(Transaction::ClientConfigureReject)
+            }
+            Some("TRANSFER_FUNDS") => {
+                crate::error::from_value(value).map(Transaction::TransferFunds)
+            }
+            Some("TRANSFER_FUNDS_REJECT") => {
+                crate::error::from_value(value).map(Transaction::TransferFundsReject)
+            }
+            Some("MARKET_ORDER") => {
+                crate::error::from_value(value).map(Transaction::MarketOrder)
+            }
+            Some("MARKET_ORDER_REJECT") => {
+                crate::error::from_value(value).map(Transaction::MarketOrderReject)
+            }
+            Some("FIXED_PRICE_ORDER") => {
+                crate::error::from_value(value).map(Transaction::FixedPriceOrder)
+            }
+            Some("LIMIT_ORDER") => {
+                crate::error::from_value(value).map(Transaction::LimitOrder)
+            }
+            Some("LIMIT_ORDER_REJECT") => {
+                crate::error::from_value(value).map(Transaction::LimitOrderReject)
+            }
+            Some("STOP_ORDER") => {
+                crate::error::from_value(value).map(Transaction::StopOrder)
+            }
+            Some("STOP_ORDER_REJECT") => {
+                crate::error::from_value(value).map(Transaction::StopOrderReject)
+            }
+            Some("MARKET_IF_TOUCHED_ORDER") => {
+                crate::error::from_value(value).map(Transaction::MarketIfTouchedOrder)
+            }
+            Some("MARKET_IF_TOUCHED_ORDER_REJECT") => {
+                crate::error::from_value(value)
+                    .map(Transaction::MarketIfTouchedOrderReject)
+            }
+            Some("TAKE_PROFIT_ORDER") => {
+                crate::error::from_value(value).map(Transaction::TakeProfitOrder)
+            }
+            Some("TAKE_PROFIT_ORDER_REJECT") => {
+                crate::error::from_value(value).map(Transaction::TakeProfitOrderReject)
+            }
+            Some("STOP_LOSS_ORDER") => {
+                crate::error::from_value(value).map(Transaction::StopLossOrder)
+            }
+            Some("STOP_LOSS_ORDER_REJECT") => {
+                crate::error::from_value(value).map(Transaction::StopLossOrderReject)
+            }
+            Some("GUARANTEED_STOP_LOSS_ORDER") => {
+                crate::error::from_value(value).map(Transaction::GuaranteedStopLossOrder)
+            }
+            Some("GUARANTEED_STOP_LOSS_ORDER_REJECT") => {
+                crate::error::from_value(value)
+                    .map(Transaction::GuaranteedStopLossOrderReject)
+            }
+            Some("TRAILING_STOP_LOSS_ORDER") => {
+                crate::error::from_value(value).map(Transaction::TrailingStopLossOrder)
+            }
+            Some("TRAILING_STOP_LOSS_ORDER_REJECT") => {
+                crate::error::from_value(value)
+                    .map(Transaction::TrailingStopLossOrderReject)
+            }
+            Some("ORDER_FILL") => {
+                crate::error::from_value(value).map(Transaction::OrderFill)
+            }
+            Some("ORDER_CANCEL") => {
+                crate::error::from_value(value).map(Transaction::OrderCancel)
+            }
+            Some("ORDER_CANCEL_REJECT") => {
+                crate::error::from_value(value).map(Transaction::OrderCancelReject)
+            }
+            Some("ORDER_CLIENT_EXTENSIONS_MODIFY") => {
+                crate::error::from_value(value)
+                    .map(Transaction::OrderClientExtensionsModify)
+            }
+            Some("ORDER_CLIENT_EXTENSIONS_MODIFY_REJECT") => {
+                crate::error::from_value(value)
+                    .map(Transaction::OrderClientExtensionsModifyReject)
+            }
+            Some("TRADE_CLIENT_EXTENSIONS_MODIFY") => {
+                crate::error::from_value(value)
+                    .map(Transaction::TradeClientExtensionsModify)
+            }
+            Some("TRADE_CLIENT_EXTENSIONS_MODIFY_REJECT") => {
+                crate::error::from_value(value)
+                    .map(Transaction::TradeClientExtensionsModifyReject)
+            }
+            Some("MARGIN_CALL_ENTER") => {
+                crate::error::from_value(value).map(Transaction::MarginCallEnter)
+            }
+            Some("MARGIN_CALL_EXTEND") => {
+                crate::error::from_value(value).map(Transaction::MarginCallExtend)
+            }
+            Some("MARGIN_CALL_EXIT") => {
+                crate::error::from_value(value).map(Transaction::MarginCallExit)
+            }
+            Some("DELAYED_TRADE_CLOSURE") => {
+                crate::error::from_value(value).map(Transaction::DelayedTradeClosure)
+            }
+            Some("DAILY_FINANCING") => {
+                crate::error::from_value(value).map(Transaction::DailyFinancing)
+            }
+            Some("DIVIDEND_ADJUSTMENT") => {
+                crate::error::from_value(value).map(Transaction::DividendAdjustment)
+            }
+            Some("RESET_RESETTABLE_PL") => {
+                crate::error::from_value(value).map(Transaction::ResetResettablePL)
+            }
+            _ => Ok(Transaction::Unknown(value)),
+        }
+    }
+}
+impl Serialize for Transaction {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Transaction::Create(inner) => inner.serialize(serializer),
+            Transaction::Close(inner) => inner.serialize(serializer),
+            Transaction::Reopen(inner) => inner.serialize(serializer),
+            Transaction::ClientConfigure(inner) => inner.serialize(serializer),
+            Transaction::ClientConfigureReject(inner) => inner.serialize(serializer),
+            Transaction::TransferFunds(inner) => inner.serialize(serializer),
+            Transaction::TransferFundsReject(inner) => inner.serialize(serializer),
+            Transaction::MarketOrder(inner) => inner.serialize(serializer),
+            Transaction::MarketOrderReject(inner) => inner.serialize(serializer),
+            Transaction::FixedPriceOrder(inner) => inner.serialize(serializer),
+            Transaction::LimitOrder(inner) => inner.serialize(serializer),
+            Transaction::LimitOrderReject(inner) => inner.serialize(serializer),
+            Transaction::StopOrder(inner) => inner.serialize(serializer),
+            Transaction::StopOrderReject(inner) => inner.serialize(serializer),
+            Transaction::MarketIfTouchedOrder(inner) => inner.serialize(serializer),
+            Transaction::MarketIfTouchedOrderReject(inner) => inner.serialize(serializer),
+            Transaction::TakeProfitOrder(inner) => inner.serialize(serializer),
+            Transaction::TakeProfitOrderReject(inner) => inner.serialize(serializer),
+            Transaction::StopLossOrder(inner) => inner.serialize(serializer),
+            Transaction::StopLossOrderReject(inner) => inner.serialize(serializer),
+            Transaction::GuaranteedStopLossOrder(inner) => inner.serialize(serializer),
+            Transaction::GuaranteedStopLossOrderReject(inner) => {
+                inner.serialize(serializer)
+            }
+            Transaction::TrailingStopLossOrder(inner) => inner.serialize(serializer),
+            Transaction::TrailingStopLossOrderReject(inner) => {
+                inner.serialize(serializer)
+            }
+            Transaction::OrderFill(inner) => inner.serialize(serializer),
+            Transaction::OrderCancel(inner) => inner.serialize(serializer),
+            Transaction::OrderCancelReject(inner) => inner.serialize(serializer),
+            Transaction::OrderClientExtensionsModify(inner) => {
+                inner.serialize(serializer)
+            }
+            Transaction::OrderClientExtensionsModifyReject(inner) => {
+                inner.serialize(serializer)
+            }
+            Transaction::TradeClientExtensionsModify(inner) => {
+                inner.serialize(serializer)
+            }
+            Transaction::TradeClientExtensionsModifyReject(inner) => {
+                inner.serialize(serializer)
+            }
+            Transaction::MarginCallEnter(inner) => inner.serialize(serializer),
+            Transaction::MarginCallExtend(inner) => inner.serialize(serializer),
+            Transaction::MarginCallExit(inner) => inner.serialize(serializer),
+            Transaction::DelayedTradeClosure(inner) => inner.serialize(serializer),
+            Transaction::DailyFinancing(inner) => inner.serialize(serializer),
+            Transaction::DividendAdjustment(inner) => inner.serialize(serializer),
+            Transaction::ResetResettablePL(inner) => inner.serialize(serializer),
+            Transaction::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+impl Transaction {
+    /// The `id` of the type it holds. For an `Unknown` type, it's read from the json, if
+    /// it's there
+    pub fn id(&self) -> Option<TransactionID> {
+        match self {
+            Transaction::Create(inner) => inner.id.clone(),
+            Transaction::Close(inner) => inner.id.clone(),
+            Transaction::Reopen(inner) => inner.id.clone(),
+            Transaction::ClientConfigure(inner) => inner.id.clone(),
+            Transaction::ClientConfigureReject(inner) => inner.id.clone(),
+            Transaction::TransferFunds(inner) => inner.id.clone(),
+            Transaction::TransferFundsReject(inner) => inner.id.clone(),
+            Transaction::MarketOrder(inner) => inner.id.clone(),
+            Transaction::MarketOrderReject(inner) => inner.id.clone(),
+            Transaction::FixedPriceOrder(inner) => inner.id.clone(),
+            Transaction::LimitOrder(inner) => inner.id.clone(),
+            Transaction::LimitOrderReject(inner) => inner.id.clone(),
+            Transaction::StopOrder(inner) => inner.id.clone(),
+            Transaction::StopOrderReject(inner) => inner.id.clone(),
+            Transaction::MarketIfTouchedOrder(inner) => inner.id.clone(),
+            Transaction::MarketIfTouchedOrderReject(inner) => inner.id.clone(),
+            Transaction::TakeProfitOrder(inner) => inner.id.clone(),
+            Transaction::TakeProfitOrderReject(inner) => inner.id.clone(),
+            Transaction::StopLossOrder(inner) => inner.id.clone(),
+            Transaction::StopLossOrderReject(inner) => inner.id.clone(),
+            Transaction::GuaranteedStopLossOrder(inner) => inner.id.clone(),
+            Transaction::GuaranteedStopLossOrderReject(inner) => inner.id.clone(),
+            Transaction::TrailingStopLossOrder(inner) => inner.id.clone(),
+            Transaction::TrailingStopLossOrderReject(inner) => inner.id.clone(),
+            Transaction::OrderFill(inner) => inner.id.clone(),
+            Transaction::OrderCancel(inner) => inner.id.clone(),
+            Transaction::OrderCancelReject(inner) => inner.id.clone(),
+            Transaction::OrderClientExtensionsModify(inner) => inner.id.clone(),
+            Transaction::OrderClientExtensionsModifyReject(inner) => inner.id.clone(),
+            Transaction::TradeClientExtensionsModify(inner) => inner.id.clone(),
+            Transaction::TradeClientExtensionsModifyReject(inner) => inner.id.clone(),
+            Transaction::MarginCallEnter(inner) => inner.id.clone(),
+            Transaction::MarginCallExtend(inner) => inner.id.clone(),
+            Transaction::MarginCallExit(inner) => inner.id.clone(),
+            Transaction::DelayedTradeClosure(inner) => inner.id.clone(),
+            Transaction::DailyFinancing(inner) => inner.id.clone(),
+            Transaction::DividendAdjustment(inner) => inner.id.clone(),
+            Transaction::ResetResettablePL(inner) => inner.id.clone(),
+            Transaction::Unknown(value) => {
+                value.get("id").and_then(|id| serde_json::from_value(id.clone()).ok())
+            }
         }
     }
 }
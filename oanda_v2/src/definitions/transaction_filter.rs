@@ -1,6 +1,16 @@
 use serde::{Serialize, Deserialize};
 /// A filter that can be used when fetching Transactions
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransactionFilter {
     /// Order-related Transactions. These are the Transactions that
@@ -1,20 +1,26 @@
+use crate::definitions::date_time::DateTime;
+use crate::definitions::transaction_id::TransactionID;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionHeartbeat {
     /// The string “HEARTBEAT”
-    #[serde_inline_default("HEARTBEAT")]
-    r#type: String,
+    #[serde_inline_default(String::from("HEARTBEAT"))]
+    pub r#type: String,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The date/time when the TransactionHeartbeat was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
 }
 impl Default for TransactionHeartbeat {
     fn default() -> Self {
         Self {
-            r#type: "HEARTBEAT",
+            r#type: String::from("HEARTBEAT"),
             last_transaction_id: Default::default(),
             time: Default::default(),
         }
@@ -5,6 +5,18 @@ use serde::{Serialize, Deserialize};
 /// assigned TransactionID
 ///
 /// Example: 1523
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
 pub struct TransactionID(String);
 impl std::ops::Deref for TransactionID {
     type Target = str;
@@ -12,3 +24,18 @@ impl std::ops::Deref for TransactionID {
         self.0.as_str()
     }
 }
+impl std::fmt::Display for TransactionID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<String> for TransactionID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+impl From<&str> for TransactionID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
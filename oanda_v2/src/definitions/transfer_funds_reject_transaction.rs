@@ -1,38 +1,63 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::funding_reason::FundingReason;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_reject_reason::TransactionRejectReason;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferFundsRejectTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to
     /// “TRANSFER_FUNDS_REJECT” in a TransferFundsRejectTransaction.
-    #[serde_inline_default("TRANSFER_FUNDS_REJECT")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::TransferFundsReject)]
+    pub r#type: TransactionType,
     /// The amount to deposit/withdraw from the Account in the
     /// Account’s home currency. A positive value indicates a
     /// deposit, a negative value indicates a withdrawal.
-    amount: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<AccountUnits>,
     /// The reason that an Account is being funded.
-    funding_reason: Option<FundingReason>,
+    #[serde(rename = "fundingReason")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funding_reason: Option<FundingReason>,
     /// An optional comment that may be attached to a fund transfer
     /// for audit purposes
-    comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
     /// The reason that the Reject Transaction was created
-    reject_reason: Option<TransactionRejectReason>,
+    #[serde(rename = "rejectReason")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<TransactionRejectReason>,
 }
 impl Default for TransferFundsRejectTransaction {
     fn default() -> Self {
@@ -43,7 +68,7 @@ impl Default for TransferFundsRejectTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "TRANSFER_FUNDS_REJECT",
+            r#type: TransactionType::TransferFundsReject,
             amount: Default::default(),
             funding_reason: Default::default(),
             comment: Default::default(),
@@ -1,38 +1,62 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::account_units::AccountUnits;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::funding_reason::FundingReason;
+use crate::definitions::request_id::RequestID;
+use crate::definitions::transaction_id::TransactionID;
+use crate::definitions::transaction_type::TransactionType;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
 #[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferFundsTransaction {
     /// The Transaction’s Identifier.
-    id: Option<TransactionID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<TransactionID>,
     /// The date/time when the Transaction was created.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
     /// The ID of the user that initiated the creation of the
     /// Transaction.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The ID of the Account the Transaction was created for.
-    account_id: Option<AccountID>,
+    #[serde(rename = "accountID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountID>,
     /// The ID of the “batch” that the Transaction belongs to.
     /// Transactions in the same batch are applied to the Account
     /// simultaneously.
-    batch_id: Option<TransactionID>,
+    #[serde(rename = "batchID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<TransactionID>,
     /// The Request ID of the request which generated the
     /// transaction.
-    request_id: Option<RequestID>,
+    #[serde(rename = "requestID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestID>,
     /// The Type of the Transaction. Always set to “TRANSFER_FUNDS”
     /// in a TransferFundsTransaction.
-    #[serde_inline_default("TRANSFER_FUNDS")]
-    r#type: TransactionType,
+    #[serde_inline_default(TransactionType::TransferFunds)]
+    pub r#type: TransactionType,
     /// The amount to deposit/withdraw from the Account in the
     /// Account’s home currency. A positive value indicates a
     /// deposit, a negative value indicates a withdrawal.
-    amount: Option<AccountUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<AccountUnits>,
     /// The reason that an Account is being funded.
-    funding_reason: Option<FundingReason>,
+    #[serde(rename = "fundingReason")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funding_reason: Option<FundingReason>,
     /// An optional comment that may be attached to a fund transfer
     /// for audit purposes
-    comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
     /// The Account’s balance after funds are transferred.
-    account_balance: Option<AccountUnits>,
+    #[serde(rename = "accountBalance")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_balance: Option<AccountUnits>,
 }
 impl Default for TransferFundsTransaction {
     fn default() -> Self {
@@ -43,7 +67,7 @@ impl Default for TransferFundsTransaction {
             account_id: Default::default(),
             batch_id: Default::default(),
             request_id: Default::default(),
-            r#type: "TRANSFER_FUNDS",
+            r#type: TransactionType::TransferFunds,
             amount: Default::default(),
             funding_reason: Default::default(),
             comment: Default::default(),
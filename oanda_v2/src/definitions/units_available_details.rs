@@ -1,17 +1,11 @@
+use crate::definitions::decimal_number::DecimalNumber;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UnitsAvailableDetails {
     /// The units available for long Orders.
-    long: Option<DecimalNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long: Option<DecimalNumber>,
     /// The units available for short Orders.
-    short: Option<DecimalNumber>,
-}
-impl Default for UnitsAvailableDetails {
-    fn default() -> Self {
-        Self {
-            long: Default::default(),
-            short: Default::default(),
-        }
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short: Option<DecimalNumber>,
 }
@@ -1,35 +1,33 @@
+use crate::definitions::currency::Currency;
 use serde::{Serialize, Deserialize};
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UserAttributes {
     /// The user’s OANDA-assigned user ID.
-    user_id: Option<integer>,
+    #[serde(rename = "userID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
     /// The user-provided username.
-    username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
     /// The user’s title.
-    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// The user’s name.
-    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// The user’s email address.
-    email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
     /// The OANDA division the user belongs to.
-    division_abbreviation: Option<String>,
+    #[serde(rename = "divisionAbbreviation")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division_abbreviation: Option<String>,
     /// The user’s preferred language.
-    language_abbreviation: Option<String>,
+    #[serde(rename = "languageAbbreviation")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_abbreviation: Option<String>,
     /// The home currency of the Account.
-    home_currency: Option<Currency>,
-}
-impl Default for UserAttributes {
-    fn default() -> Self {
-        Self {
-            user_id: Default::default(),
-            username: Default::default(),
-            title: Default::default(),
-            name: Default::default(),
-            email: Default::default(),
-            division_abbreviation: Default::default(),
-            language_abbreviation: Default::default(),
-            home_currency: Default::default(),
-        }
-    }
+    #[serde(rename = "homeCurrency")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_currency: Option<Currency>,
 }
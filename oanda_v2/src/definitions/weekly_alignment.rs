@@ -1,7 +1,17 @@
 use serde::{Serialize, Deserialize};
 /// The day of the week to use for candlestick granularities
 /// with weekly alignment.
-#[derive(Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parse_display::Display,
+    Deserialize,
+    Serialize
+)]
+#[display(style = "SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WeeklyAlignment {
     /// Monday
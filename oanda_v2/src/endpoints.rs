@@ -1,3 +1,4 @@
+pub mod instrument;
 pub mod account;
 pub mod order;
 pub mod trade;
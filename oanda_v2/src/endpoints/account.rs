@@ -1,71 +1,310 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_request_id::ClientRequestID;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::transaction_id::TransactionID;
 use serde::{Serialize, Deserialize};
+use crate::client::Client;
 pub mod responses;
-struct Account<'a> {
-    client: &'a Client,
+/// The json body for: Set the client-configurable portions of
+/// an Account
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfigurationBody {
+    /// Client-defined alias (name) for the Account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// The string representation of a decimal number.
+    #[serde(rename = "marginRate")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_rate: Option<DecimalNumber>,
+}
+/// The request for: Get a list of all Accounts authorized for
+/// the provided token
+#[derive(Default)]
+pub struct AccountsRequest {}
+impl AccountsRequest {
+    /// Creates the request. All of its parameters are optional
+    pub fn new() -> AccountsRequest {
+        AccountsRequest {}
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::accounts::Accounts,
+            responses::accounts::Headers,
+        >,
+        responses::accounts::Error,
+    > {
+        let Self {} = self;
+        let url = "/v3/accounts";
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get the full details for a single Account
+/// that a client has access to. Full pending Order, open Trade
+/// and open Position representations are provided
+pub struct GetRequest {
+    account_id: AccountID,
+}
+impl GetRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> GetRequest {
+        GetRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get a summary for a single Account that a
+/// client has access to
+pub struct SummaryRequest {
+    account_id: AccountID,
+}
+impl SummaryRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> SummaryRequest {
+        SummaryRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::summary::Summary,
+            responses::summary::Headers,
+        >,
+        responses::summary::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}/summary";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get the list of tradeable instruments for
+/// the given Account. The list of tradeable instruments is
+/// dependent on the regulatory division that the Account is
+/// located in, thus should be the same for all Accounts owned
+/// by a single user
+pub struct InstrumentsRequest {
+    account_id: AccountID,
+    instruments: Vec<InstrumentName>,
+}
+impl InstrumentsRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> InstrumentsRequest {
+        InstrumentsRequest {
+            account_id,
+            instruments: Default::default(),
+        }
+    }
+    /// List of instruments to query specifically.
+    pub fn instruments(
+        mut self,
+        instruments: impl IntoIterator<Item = InstrumentName>,
+    ) -> Self {
+        self.instruments = instruments.into_iter().collect();
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::instruments::Instruments,
+            responses::instruments::Headers,
+        >,
+        responses::instruments::Error,
+    > {
+        let Self { account_id, instruments } = self;
+        let url = "/v3/accounts/{accountID}/instruments";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if !instruments.is_empty() {
+            query
+                .push((
+                    "instruments",
+                    instruments
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Set the client-configurable portions of
+/// an Account
+pub struct ConfigurationRequest {
+    account_id: AccountID,
+    client_request_id: Option<ClientRequestID>,
+    body: ConfigurationBody,
+}
+impl ConfigurationRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID, body: ConfigurationBody) -> ConfigurationRequest {
+        ConfigurationRequest {
+            account_id,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::configuration::Configuration,
+            responses::configuration::Headers,
+        >,
+        responses::configuration::Error,
+    > {
+        let Self { account_id, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/configuration";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .patch(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+/// The request for: Endpoint used to poll an Account for its
+/// current state and changes since a specified TransactionID
+pub struct ChangesRequest {
+    account_id: AccountID,
+    since_transaction_id: Option<TransactionID>,
+}
+impl ChangesRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> ChangesRequest {
+        ChangesRequest {
+            account_id,
+            since_transaction_id: Default::default(),
+        }
+    }
+    /// ID of the Transaction to get Account changes since.
+    pub fn since_transaction_id(mut self, since_transaction_id: TransactionID) -> Self {
+        self.since_transaction_id = Some(since_transaction_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::changes::Changes,
+            responses::changes::Headers,
+        >,
+        responses::changes::Error,
+    > {
+        let Self { account_id, since_transaction_id } = self;
+        let url = "/v3/accounts/{accountID}/changes";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(since_transaction_id) = &since_transaction_id {
+            query.push(("sinceTransactionID", since_transaction_id.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+pub struct Account<'a> {
+    pub(crate) client: &'a Client,
 }
 impl<'a> Account<'a> {
     /// Get a list of all Accounts authorized for the provided
     /// token.
-    pub async fn accounts(&self, authorization: String) -> Result<()> {
-        let url = "/v3/accounts";
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+    pub async fn accounts(
+        &self,
+        request: AccountsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::accounts::Accounts,
+            responses::accounts::Headers,
+        >,
+        responses::accounts::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get the full details for a single Account that a client has
     /// access to. Full pending Order, open Trade and open Position
     /// representations are provided.
     pub async fn get(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: GetRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get a summary for a single Account that a client has access
     /// to.
     pub async fn summary(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/summary";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: SummaryRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::summary::Summary,
+            responses::summary::Headers,
+        >,
+        responses::summary::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get the list of tradeable instruments for the given Account.
     /// The list of tradeable instruments is dependent on the
@@ -73,67 +312,41 @@ impl<'a> Account<'a> {
     /// should be the same for all Accounts owned by a single user.
     pub async fn instruments(
         &self,
-        authorization: String,
-        account_id: AccountId,
-        instruments: ListOf,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/instruments";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [("instruments", instruments)];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: InstrumentsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::instruments::Instruments,
+            responses::instruments::Headers,
+        >,
+        responses::instruments::Error,
+    > {
+        request.send(self.client).await
     }
     /// Set the client-configurable portions of an Account.
     pub async fn configuration(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/configuration";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .patch(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: ConfigurationRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::configuration::Configuration,
+            responses::configuration::Headers,
+        >,
+        responses::configuration::Error,
+    > {
+        request.send(self.client).await
     }
     /// Endpoint used to poll an Account for its current state and
     /// changes since a specified TransactionID.
     pub async fn changes(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        since_transaction_id: TransactionId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/changes";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [("sinceTransactionID", since_transaction_id)];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: ChangesRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::changes::Changes,
+            responses::changes::Headers,
+        >,
+        responses::changes::Error,
+    > {
+        request.send(self.client).await
     }
 }
@@ -1,6 +1,6 @@
-pub mod get;
-pub mod instruments;
 pub mod accounts;
 pub mod changes;
-pub mod summary;
 pub mod configuration;
+pub mod get;
+pub mod instruments;
+pub mod summary;
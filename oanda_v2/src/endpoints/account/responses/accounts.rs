@@ -1,15 +1,69 @@
+use crate::definitions::account_properties::AccountProperties;
+use serde::{Serialize, Deserialize};
 /// The list of authorized Accounts has been provided.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Accounts {
     /// The list of Accounts the client is authorized to access and
     /// their associated properties.
-    accounts: Vec<AccountProperties>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<AccountProperties>,
 }
-impl Default for Accounts {
-    fn default() -> Self {
-        Self {
-            accounts: Default::default(),
+impl crate::response::ResponseBody for Accounts {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        None
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
+}
@@ -1,25 +1,90 @@
+use crate::definitions::account_changes::AccountChanges;
+use crate::definitions::account_changes_state::AccountChangesState;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Account state and changes are provided.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Changes {
     /// The changes to the Account’s Orders, Trades and Positions
     /// since the specified Transaction ID. Only provided if the
     /// sinceTransactionID is supplied to the poll request.
-    changes: Option<AccountChanges>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changes: Option<AccountChanges>,
     /// The Account’s current price-dependent state.
-    state: Option<AccountChangesState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<AccountChangesState>,
     /// The ID of the last Transaction created for the Account.
     /// This Transaction ID should be used for future poll requests,
     /// as the client has already observed all changes up to and
     /// including it.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
 }
-impl Default for Changes {
-    fn default() -> Self {
-        Self {
-            changes: Default::default(),
-            state: Default::default(),
-            last_transaction_id: Default::default(),
+impl crate::response::ResponseBody for Changes {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 404
+    #[error("HTTP status 404: {}", .0.error_message)]
+    E404(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with HTTP status 416
+    #[error("HTTP status 416: {}", .0.error_message)]
+    E416(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                416 => Error::E416(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
+}
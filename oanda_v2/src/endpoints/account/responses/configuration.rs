@@ -1,75 +1,131 @@
+use crate::definitions::client_configure_reject_transaction::ClientConfigureRejectTransaction;
+use crate::definitions::client_configure_transaction::ClientConfigureTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Account was configured successfully.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Configuration {
     /// The transaction that configures the Account.
-    client_configure_transaction: Option<ClientConfigureTransaction>,
+    #[serde(rename = "clientConfigureTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_configure_transaction: Option<ClientConfigureTransaction>,
     /// The ID of the last Transaction created for the Account.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for Configuration {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
 }
-impl Default for Configuration {
-    fn default() -> Self {
-        Self {
-            client_configure_transaction: Default::default(),
-            last_transaction_id: Default::default(),
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The configuration specification was invalid.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Configuration400 {
     /// The transaction that rejects the configuration of the
     /// Account.
-    client_configure_reject_transaction: Option<ClientConfigureRejectTransaction>,
+    #[serde(rename = "clientConfigureRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_configure_reject_transaction: Option<ClientConfigureRejectTransaction>,
     /// The ID of the last Transaction created for the Account.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
-}
-impl Default for Configuration400 {
-    fn default() -> Self {
-        Self {
-            client_configure_reject_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
 /// The configuration operation was forbidden on the Account.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Configuration403 {
     /// The transaction that rejects the configuration of the
     /// Account.
-    client_configure_reject_transaction: Option<ClientConfigureRejectTransaction>,
+    #[serde(rename = "clientConfigureRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_configure_reject_transaction: Option<ClientConfigureRejectTransaction>,
     /// The ID of the last Transaction created for the Account.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-impl Default for Configuration403 {
-    fn default() -> Self {
-        Self {
-            client_configure_reject_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
-}
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E400(Configuration400),
-    E403(Configuration403),
+    /// The configuration specification was invalid.
+    #[error("400: The configuration specification was invalid.")]
+    E400(Box<Configuration400>),
+    /// The configuration operation was forbidden on the Account.
+    #[error("403: The configuration operation was forbidden on the Account.")]
+    E403(Box<Configuration403>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 404
+    #[error("HTTP status 404: {}", .0.error_message)]
+    E404(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                403 => Error::E403(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
@@ -1,18 +1,78 @@
+use crate::definitions::account::Account;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The full Account details are provided
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Get {
     /// The full details of the requested Account.
-    account: Option<Account>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<Account>,
     /// The ID of the most recent Transaction created for the
     /// Account.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
 }
-impl Default for Get {
-    fn default() -> Self {
-        Self {
-            account: Default::default(),
-            last_transaction_id: Default::default(),
+impl crate::response::ResponseBody for Get {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The server replied with HTTP status 400
+    #[error("HTTP status 400: {}", .0.error_message)]
+    E400(crate::error::ErrorBody),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
+}
@@ -0,0 +1,318 @@
+use crate::definitions::candlestick_granularity::CandlestickGranularity;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::pricing_component::PricingComponent;
+use crate::definitions::weekly_alignment::WeeklyAlignment;
+use crate::client::Client;
+pub mod responses;
+/// The request for: Fetch candlestick data for an instrument
+pub struct CandlesRequest {
+    instrument: InstrumentName,
+    price: Option<PricingComponent>,
+    granularity: Option<CandlestickGranularity>,
+    count: Option<i64>,
+    from: Option<DateTime>,
+    to: Option<DateTime>,
+    smooth: Option<bool>,
+    include_first: Option<bool>,
+    daily_alignment: Option<i64>,
+    alignment_timezone: Option<String>,
+    weekly_alignment: Option<WeeklyAlignment>,
+}
+impl CandlesRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `instrument` - Name of the Instrument
+    pub fn new(instrument: InstrumentName) -> CandlesRequest {
+        CandlesRequest {
+            instrument,
+            price: Default::default(),
+            granularity: Default::default(),
+            count: Default::default(),
+            from: Default::default(),
+            to: Default::default(),
+            smooth: Default::default(),
+            include_first: Default::default(),
+            daily_alignment: Default::default(),
+            alignment_timezone: Default::default(),
+            weekly_alignment: Default::default(),
+        }
+    }
+    /// The Price component(s) to get candlestick data for.
+    ///
+    /// The server uses `M` if this isn't set
+    pub fn price(mut self, price: PricingComponent) -> Self {
+        self.price = Some(price);
+        self
+    }
+    /// The granularity of the candlesticks to fetch
+    ///
+    /// The server uses `S5` if this isn't set
+    pub fn granularity(mut self, granularity: CandlestickGranularity) -> Self {
+        self.granularity = Some(granularity);
+        self
+    }
+    /// The number of candlesticks to return in the response.
+    /// Count should not be specified if both the start and end
+    /// parameters are provided, as the time range combined with
+    /// the granularity will determine the number of candlesticks
+    /// to return.
+    ///
+    /// The server uses `500` if this isn't set
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+    /// The start of the time range to fetch candlesticks for.
+    pub fn from(mut self, from: DateTime) -> Self {
+        self.from = Some(from);
+        self
+    }
+    /// The end of the time range to fetch candlesticks for.
+    pub fn to(mut self, to: DateTime) -> Self {
+        self.to = Some(to);
+        self
+    }
+    /// A flag that controls whether the candlestick is “smoothed”
+    /// or not. A smoothed candlestick uses the previous candle’s
+    /// close price as its open price, while an un-smoothed
+    /// candlestick uses the first price from its time range as its
+    /// open price.
+    ///
+    /// The server uses `False` if this isn't set
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+    /// A flag that controls whether the candlestick that is
+    /// covered by the from time should be included in the results.
+    /// This flag enables clients to use the timestamp of the
+    /// last completed candlestick received to poll for future
+    /// candlesticks but avoid receiving the previous candlestick
+    /// repeatedly.
+    ///
+    /// The server uses `True` if this isn't set
+    pub fn include_first(mut self, include_first: bool) -> Self {
+        self.include_first = Some(include_first);
+        self
+    }
+    /// The hour of the day (in the specified timezone) to use for
+    /// granularities that have daily alignments.
+    ///
+    /// The server uses `17` if this isn't set
+    pub fn daily_alignment(mut self, daily_alignment: i64) -> Self {
+        self.daily_alignment = Some(daily_alignment);
+        self
+    }
+    /// The timezone to use for the dailyAlignment parameter.
+    /// Candlesticks with daily alignment will be aligned to the
+    /// dailyAlignment hour within the alignmentTimezone. Note that
+    /// the returned times will still be represented in UTC.
+    ///
+    /// The server uses `America/New_York` if this isn't set
+    pub fn alignment_timezone(mut self, alignment_timezone: String) -> Self {
+        self.alignment_timezone = Some(alignment_timezone);
+        self
+    }
+    /// The day of the week used for granularities that have weekly
+    /// alignment.
+    ///
+    /// The server uses `Friday` if this isn't set
+    pub fn weekly_alignment(mut self, weekly_alignment: WeeklyAlignment) -> Self {
+        self.weekly_alignment = Some(weekly_alignment);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::candles::Candles,
+            responses::candles::Headers,
+        >,
+        responses::candles::Error,
+    > {
+        let Self {
+            instrument,
+            price,
+            granularity,
+            count,
+            from,
+            to,
+            smooth,
+            include_first,
+            daily_alignment,
+            alignment_timezone,
+            weekly_alignment,
+        } = self;
+        let url = "/v3/instruments/{instrument}/candles";
+        let url = url.replace("{instrument}", &instrument.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(price) = &price {
+            query.push(("price", price.to_string()));
+        }
+        if let Some(granularity) = &granularity {
+            query.push(("granularity", granularity.to_string()));
+        }
+        if let Some(count) = &count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(from) = &from {
+            query.push(("from", from.format(client.datetime_format)));
+        }
+        if let Some(to) = &to {
+            query.push(("to", to.format(client.datetime_format)));
+        }
+        if let Some(smooth) = &smooth {
+            query.push(("smooth", smooth.to_string()));
+        }
+        if let Some(include_first) = &include_first {
+            query.push(("includeFirst", include_first.to_string()));
+        }
+        if let Some(daily_alignment) = &daily_alignment {
+            query.push(("dailyAlignment", daily_alignment.to_string()));
+        }
+        if let Some(alignment_timezone) = &alignment_timezone {
+            query.push(("alignmentTimezone", alignment_timezone.to_string()));
+        }
+        if let Some(weekly_alignment) = &weekly_alignment {
+            query.push(("weeklyAlignment", weekly_alignment.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Fetch an order book for an instrument
+pub struct OrderBookRequest {
+    instrument: InstrumentName,
+    time: Option<DateTime>,
+}
+impl OrderBookRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `instrument` - Name of the Instrument
+    pub fn new(instrument: InstrumentName) -> OrderBookRequest {
+        OrderBookRequest {
+            instrument,
+            time: Default::default(),
+        }
+    }
+    /// The time of the snapshot to fetch. If not specified, then
+    /// the most recent snapshot is fetched.
+    pub fn time(mut self, time: DateTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::order_book::OrderBook,
+            responses::order_book::Headers,
+        >,
+        responses::order_book::Error,
+    > {
+        let Self { instrument, time } = self;
+        let url = "/v3/instruments/{instrument}/orderBook";
+        let url = url.replace("{instrument}", &instrument.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(time) = &time {
+            query.push(("time", time.format(client.datetime_format)));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Fetch a position book for an instrument
+pub struct PositionBookRequest {
+    instrument: InstrumentName,
+    time: Option<DateTime>,
+}
+impl PositionBookRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `instrument` - Name of the Instrument
+    pub fn new(instrument: InstrumentName) -> PositionBookRequest {
+        PositionBookRequest {
+            instrument,
+            time: Default::default(),
+        }
+    }
+    /// The time of the snapshot to fetch. If not specified, then
+    /// the most recent snapshot is fetched.
+    pub fn time(mut self, time: DateTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::position_book::PositionBook,
+            responses::position_book::Headers,
+        >,
+        responses::position_book::Error,
+    > {
+        let Self { instrument, time } = self;
+        let url = "/v3/instruments/{instrument}/positionBook";
+        let url = url.replace("{instrument}", &instrument.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(time) = &time {
+            query.push(("time", time.format(client.datetime_format)));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+pub struct Instrument<'a> {
+    pub(crate) client: &'a Client,
+}
+impl<'a> Instrument<'a> {
+    /// Fetch candlestick data for an instrument.
+    pub async fn candles(
+        &self,
+        request: CandlesRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::candles::Candles,
+            responses::candles::Headers,
+        >,
+        responses::candles::Error,
+    > {
+        request.send(self.client).await
+    }
+    /// Fetch an order book for an instrument.
+    pub async fn order_book(
+        &self,
+        request: OrderBookRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::order_book::OrderBook,
+            responses::order_book::Headers,
+        >,
+        responses::order_book::Error,
+    > {
+        request.send(self.client).await
+    }
+    /// Fetch a position book for an instrument.
+    pub async fn position_book(
+        &self,
+        request: PositionBookRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::position_book::PositionBook,
+            responses::position_book::Headers,
+        >,
+        responses::position_book::Error,
+    > {
+        request.send(self.client).await
+    }
+}
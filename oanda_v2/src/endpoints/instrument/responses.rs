@@ -0,0 +1,3 @@
+pub mod candles;
+pub mod order_book;
+pub mod position_book;
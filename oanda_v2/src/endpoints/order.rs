@@ -1,183 +1,508 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::client_request_id::ClientRequestID;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::order_id::OrderID;
+use crate::definitions::order_request::OrderRequest;
+use crate::definitions::order_specifier::OrderSpecifier;
+use crate::definitions::order_state_filter::OrderStateFilter;
 use serde::{Serialize, Deserialize};
+use crate::client::Client;
 pub mod responses;
-struct Order<'a> {
-    client: &'a Client,
+/// The json body for: Create an Order for an Account
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PostOrdersBody {
+    /// Specification of the Order to create
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<OrderRequest>,
+}
+/// The json body for: Replace an Order in an Account by
+/// simultaneously cancelling it and creating a replacement
+/// Order
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PutBody {
+    /// Specification of the replacing Order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<OrderRequest>,
+}
+/// The json body for: Update the Client Extensions for
+/// an Order in an Account. Do not set, modify, or delete
+/// clientExtensions if your account is associated with MT4
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientExtensionsBody {
+    /// The Client Extensions to update for the Order. Do not
+    /// set, modify, or delete clientExtensions if your account is
+    /// associated with MT4.
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+    /// The Client Extensions to update for the Trade created
+    /// when the Order is filled. Do not set, modify, or delete
+    /// clientExtensions if your account is associated with MT4.
+    #[serde(rename = "tradeClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_client_extensions: Option<ClientExtensions>,
+}
+/// The request for: Create an Order for an Account
+pub struct PostOrdersRequest {
+    account_id: AccountID,
+    client_request_id: Option<ClientRequestID>,
+    body: PostOrdersBody,
+}
+impl PostOrdersRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID, body: PostOrdersBody) -> PostOrdersRequest {
+        PostOrdersRequest {
+            account_id,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::post_orders::PostOrders,
+            responses::post_orders::Headers,
+        >,
+        responses::post_orders::Error,
+    > {
+        let Self { account_id, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/orders";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .post(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+/// The request for: Get a list of Orders for an Account
+pub struct OrdersRequest {
+    account_id: AccountID,
+    ids: Vec<OrderID>,
+    state: Option<OrderStateFilter>,
+    instrument: Option<InstrumentName>,
+    count: Option<i64>,
+    before_id: Option<OrderID>,
+}
+impl OrdersRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> OrdersRequest {
+        OrdersRequest {
+            account_id,
+            ids: Default::default(),
+            state: Default::default(),
+            instrument: Default::default(),
+            count: Default::default(),
+            before_id: Default::default(),
+        }
+    }
+    /// List of Order IDs to retrieve
+    pub fn ids(mut self, ids: impl IntoIterator<Item = OrderID>) -> Self {
+        self.ids = ids.into_iter().collect();
+        self
+    }
+    /// The state to filter the requested Orders by
+    ///
+    /// The server uses `PENDING` if this isn't set
+    pub fn state(mut self, state: OrderStateFilter) -> Self {
+        self.state = Some(state);
+        self
+    }
+    /// The instrument to filter the requested orders by
+    pub fn instrument(mut self, instrument: InstrumentName) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+    /// The maximum number of Orders to return
+    ///
+    /// The server uses `50` if this isn't set
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+    /// The maximum Order ID to return. If not provided the most
+    /// recent Orders in the Account are returned
+    pub fn before_id(mut self, before_id: OrderID) -> Self {
+        self.before_id = Some(before_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::orders::Orders, responses::orders::Headers>,
+        responses::orders::Error,
+    > {
+        let Self { account_id, ids, state, instrument, count, before_id } = self;
+        let url = "/v3/accounts/{accountID}/orders";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if !ids.is_empty() {
+            query
+                .push((
+                    "ids",
+                    ids
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ));
+        }
+        if let Some(state) = &state {
+            query.push(("state", state.to_string()));
+        }
+        if let Some(instrument) = &instrument {
+            query.push(("instrument", instrument.to_string()));
+        }
+        if let Some(count) = &count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(before_id) = &before_id {
+            query.push(("beforeID", before_id.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: List all pending Orders in an Account
+pub struct PendingOrdersRequest {
+    account_id: AccountID,
+}
+impl PendingOrdersRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> PendingOrdersRequest {
+        PendingOrdersRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::pending_orders::PendingOrders,
+            responses::pending_orders::Headers,
+        >,
+        responses::pending_orders::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}/pendingOrders";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get details for a single Order in an
+/// Account
+pub struct GetRequest {
+    account_id: AccountID,
+    order_specifier: OrderSpecifier,
+}
+impl GetRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `order_specifier` - The Order Specifier
+    pub fn new(account_id: AccountID, order_specifier: OrderSpecifier) -> GetRequest {
+        GetRequest {
+            account_id,
+            order_specifier,
+        }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        let Self { account_id, order_specifier } = self;
+        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{orderSpecifier}", &order_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Replace an Order in an Account by
+/// simultaneously cancelling it and creating a replacement
+/// Order
+pub struct PutRequest {
+    client_request_id: Option<ClientRequestID>,
+    account_id: AccountID,
+    order_specifier: OrderSpecifier,
+    body: PutBody,
+}
+impl PutRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `order_specifier` - The Order Specifier
+    pub fn new(
+        account_id: AccountID,
+        order_specifier: OrderSpecifier,
+        body: PutBody,
+    ) -> PutRequest {
+        PutRequest {
+            client_request_id: Default::default(),
+            account_id,
+            order_specifier,
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::put::Put, responses::put::Headers>,
+        responses::put::Error,
+    > {
+        let Self { client_request_id, account_id, order_specifier, body } = self;
+        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{orderSpecifier}", &order_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .put(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+/// The request for: Cancel a pending Order in an Account
+pub struct CancelRequest {
+    client_request_id: Option<ClientRequestID>,
+    account_id: AccountID,
+    order_specifier: OrderSpecifier,
+}
+impl CancelRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `order_specifier` - The Order Specifier
+    pub fn new(account_id: AccountID, order_specifier: OrderSpecifier) -> CancelRequest {
+        CancelRequest {
+            client_request_id: Default::default(),
+            account_id,
+            order_specifier,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::cancel::Cancel, responses::cancel::Headers>,
+        responses::cancel::Error,
+    > {
+        let Self { client_request_id, account_id, order_specifier } = self;
+        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}/cancel";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{orderSpecifier}", &order_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.put(&url).query(&query);
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+/// The request for: Update the Client Extensions for an
+/// Order in an Account. Do not set, modify, or delete
+/// clientExtensions if your account is associated with MT4
+pub struct ClientExtensionsRequest {
+    account_id: AccountID,
+    order_specifier: OrderSpecifier,
+    client_request_id: Option<ClientRequestID>,
+    body: ClientExtensionsBody,
+}
+impl ClientExtensionsRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `order_specifier` - The Order Specifier
+    pub fn new(
+        account_id: AccountID,
+        order_specifier: OrderSpecifier,
+        body: ClientExtensionsBody,
+    ) -> ClientExtensionsRequest {
+        ClientExtensionsRequest {
+            account_id,
+            order_specifier,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::client_extensions::ClientExtensions,
+            responses::client_extensions::Headers,
+        >,
+        responses::client_extensions::Error,
+    > {
+        let Self { account_id, order_specifier, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}/clientExtensions";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{orderSpecifier}", &order_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .put(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+pub struct Order<'a> {
+    pub(crate) client: &'a Client,
 }
 impl<'a> Order<'a> {
     /// Create an Order for an Account
     pub async fn post_orders(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/orders";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .post(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: PostOrdersRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::post_orders::PostOrders,
+            responses::post_orders::Headers,
+        >,
+        responses::post_orders::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get a list of Orders for an Account
     pub async fn orders(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        ids: ListOf,
-        state: OrderStateFilter,
-        instrument: InstrumentName,
-        count: Integer,
-        before_id: OrderId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/orders";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [
-            ("ids", ids),
-            ("state", state),
-            ("instrument", instrument),
-            ("count", count),
-            ("beforeID", before_id),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: OrdersRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::orders::Orders, responses::orders::Headers>,
+        responses::orders::Error,
+    > {
+        request.send(self.client).await
     }
     /// List all pending Orders in an Account
     pub async fn pending_orders(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/pendingOrders";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: PendingOrdersRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::pending_orders::PendingOrders,
+            responses::pending_orders::Headers,
+        >,
+        responses::pending_orders::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get details for a single Order in an Account
     pub async fn get(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        order_specifier: OrderSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "orderSpecifier" + "}", "order_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: GetRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        request.send(self.client).await
     }
     /// Replace an Order in an Account by simultaneously cancelling
     /// it and creating a replacement Order
     pub async fn put(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        client_request_id: ClientRequestId,
-        account_id: AccountId,
-        order_specifier: OrderSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "orderSpecifier" + "}", "order_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .header("ClientRequestID", client_request_id)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: PutRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::put::Put, responses::put::Headers>,
+        responses::put::Error,
+    > {
+        request.send(self.client).await
     }
     /// Cancel a pending Order in an Account
     pub async fn cancel(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        client_request_id: ClientRequestId,
-        account_id: AccountId,
-        order_specifier: OrderSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}/cancel";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "orderSpecifier" + "}", "order_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .header("ClientRequestID", client_request_id)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: CancelRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::cancel::Cancel, responses::cancel::Headers>,
+        responses::cancel::Error,
+    > {
+        request.send(self.client).await
     }
     /// Update the Client Extensions for an Order in an Account. Do
     /// not set, modify, or delete clientExtensions if your account
     /// is associated with MT4.
     pub async fn client_extensions(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        order_specifier: OrderSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/orders/{orderSpecifier}/clientExtensions";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "orderSpecifier" + "}", "order_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: ClientExtensionsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::client_extensions::ClientExtensions,
+            responses::client_extensions::Headers,
+        >,
+        responses::client_extensions::Error,
+    > {
+        request.send(self.client).await
     }
 }
@@ -1,7 +1,7 @@
 pub mod cancel;
-pub mod post_orders;
-pub mod pending_orders;
-pub mod get;
-pub mod put;
 pub mod client_extensions;
+pub mod get;
 pub mod orders;
+pub mod pending_orders;
+pub mod post_orders;
+pub mod put;
@@ -1,58 +1,113 @@
+use crate::definitions::order_cancel_reject_transaction::OrderCancelRejectTransaction;
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Order was cancelled as specified
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Cancel {
     /// The Transaction that cancelled the Order
-    order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "orderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for Cancel {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
 }
-impl Default for Cancel {
-    fn default() -> Self {
-        Self {
-            order_cancel_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The Account or Order specified does not exist.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Cancel404 {
     /// The Transaction that rejected the cancellation of the Order.
     /// Only present if the Account exists.
-    order_cancel_reject_transaction: Option<OrderCancelRejectTransaction>,
+    #[serde(rename = "orderCancelRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_cancel_reject_transaction: Option<OrderCancelRejectTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request. Only present if the Account exists.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account. Only present if the Account exists.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
-}
-impl Default for Cancel404 {
-    fn default() -> Self {
-        Self {
-            order_cancel_reject_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E404(Cancel404),
+    /// The Account or Order specified does not exist.
+    #[error("404: The Account or Order specified does not exist.")]
+    E404(Box<Cancel404>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
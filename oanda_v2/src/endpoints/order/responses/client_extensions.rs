@@ -1,98 +1,153 @@
+use crate::definitions::order_client_extensions_modify_reject_transaction::OrderClientExtensionsModifyRejectTransaction;
+use crate::definitions::order_client_extensions_modify_transaction::OrderClientExtensionsModifyTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Order’s Client Extensions were successfully modified
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClientExtensions {
     /// The Transaction that modified the Client Extensions for
     /// the Order
-    order_client_extensions_modify_transaction: Option<
+    #[serde(rename = "orderClientExtensionsModifyTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_client_extensions_modify_transaction: Option<
         OrderClientExtensionsModifyTransaction,
     >,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
+}
+impl crate::response::ResponseBody for ClientExtensions {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
 }
-impl Default for ClientExtensions {
-    fn default() -> Self {
-        Self {
-            order_client_extensions_modify_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            related_transaction_i_ds: Default::default(),
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The Order Client Extensions specification was invalid
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClientExtensions400 {
     /// The Transaction that rejected the modification of the Client
     /// Extensions for the Order
-    order_client_extensions_modify_reject_transaction: Option<
+    #[serde(rename = "orderClientExtensionsModifyRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_client_extensions_modify_reject_transaction: Option<
         OrderClientExtensionsModifyRejectTransaction,
     >,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
-}
-impl Default for ClientExtensions400 {
-    fn default() -> Self {
-        Self {
-            order_client_extensions_modify_reject_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
 /// The Account or Order specified does not exist.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClientExtensions404 {
     /// The Transaction that rejected the modification of the
     /// Client Extensions for the Order. Only present if the Account
     /// exists.
-    order_client_extensions_modify_reject_transaction: Option<
+    #[serde(rename = "orderClientExtensionsModifyRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_client_extensions_modify_reject_transaction: Option<
         OrderClientExtensionsModifyRejectTransaction,
     >,
     /// The ID of the most recent Transaction created for the
     /// Account. Only present if the Account exists.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request. Only present if the Account exists.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-impl Default for ClientExtensions404 {
-    fn default() -> Self {
-        Self {
-            order_client_extensions_modify_reject_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
-}
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E400(ClientExtensions400),
-    E404(ClientExtensions404),
+    /// The Order Client Extensions specification was invalid
+    #[error("400: The Order Client Extensions specification was invalid")]
+    E400(Box<ClientExtensions400>),
+    /// The Account or Order specified does not exist.
+    #[error("404: The Account or Order specified does not exist.")]
+    E404(Box<ClientExtensions404>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
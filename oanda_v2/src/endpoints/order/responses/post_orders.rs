@@ -0,0 +1,177 @@
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::order_fill_transaction::OrderFillTransaction;
+use crate::definitions::transaction::Transaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
+/// The Order was created as specified
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PostOrders {
+    /// The Transaction that created the Order specified by the
+    /// request.
+    #[serde(rename = "orderCreateTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_create_transaction: Option<Transaction>,
+    /// The Transaction that filled the newly created Order. Only
+    /// provided when the Order was immediately filled.
+    #[serde(rename = "orderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction: Option<OrderFillTransaction>,
+    /// The Transaction that cancelled the newly created Order. Only
+    /// provided when the Order was immediately cancelled.
+    #[serde(rename = "orderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
+    /// The Transaction that reissues the Order. Only provided when
+    /// the Order is configured to be reissued for its remaining
+    /// units after a partial fill and the reissue was successful.
+    #[serde(rename = "orderReissueTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reissue_transaction: Option<Transaction>,
+    /// The Transaction that rejects the reissue of the Order. Only
+    /// provided when the Order is configured to be reissued for
+    /// its remaining units after a partial fill and the reissue
+    /// was rejected.
+    #[serde(rename = "orderReissueRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reissue_reject_transaction: Option<Transaction>,
+    /// The IDs of all Transactions that were created while
+    /// satisfying the request.
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
+    /// The ID of the most recent Transaction created for the
+    /// Account
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for PostOrders {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// A link to the Order that was just created
+    pub location: Option<String>,
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            location: crate::response::header(headers, "Location"),
+            request_id: crate::response::header(headers, "RequestID"),
+        }
+    }
+}
+/// The Order specification was invalid
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PostOrders400 {
+    /// The Transaction that rejected the creation of the Order
+    /// as requested
+    #[serde(rename = "orderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reject_transaction: Option<Transaction>,
+    /// The IDs of all Transactions that were created while
+    /// satisfying the request.
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
+    /// The ID of the most recent Transaction created for the
+    /// Account
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+    /// The code of the error that has occurred. This field may not
+    /// be returned for some errors.
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// The human-readable description of the error that has
+    /// occurred.
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
+}
+/// The Order or Account specified does not exist.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PostOrders404 {
+    /// The Transaction that rejected the creation of the Order as
+    /// requested. Only present if the Account exists.
+    #[serde(rename = "orderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reject_transaction: Option<Transaction>,
+    /// The IDs of all Transactions that were created while
+    /// satisfying the request. Only present if the Account exists.
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
+    /// The ID of the most recent Transaction created for the
+    /// Account. Only present if the Account exists.
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+    /// The code of the error that has occurred. This field may not
+    /// be returned for some errors.
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// The human-readable description of the error that has
+    /// occurred.
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
+}
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The Order specification was invalid
+    #[error("400: The Order specification was invalid")]
+    E400(Box<PostOrders400>),
+    /// The Order or Account specified does not exist.
+    #[error("404: The Order or Account specified does not exist.")]
+    E404(Box<PostOrders404>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 403
+    #[error("HTTP status 403: {}", .0.error_message)]
+    E403(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                403 => Error::E403(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
+}
@@ -1,115 +1,179 @@
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::order_fill_transaction::OrderFillTransaction;
+use crate::definitions::transaction::Transaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Order was successfully cancelled and replaced
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Put {
     /// The Transaction that cancelled the Order to be replaced.
-    order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "orderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction that created the replacing Order as
     /// requested.
-    order_create_transaction: Option<Transaction>,
+    #[serde(rename = "orderCreateTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_create_transaction: Option<Transaction>,
     /// The Transaction that filled the replacing Order. This is
     /// only provided when the replacing Order was immediately
     /// filled.
-    order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(rename = "orderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction: Option<OrderFillTransaction>,
     /// The Transaction that reissues the replacing Order. Only
     /// provided when the replacing Order was partially filled
     /// immediately and is configured to be reissued for its
     /// remaining units.
-    order_reissue_transaction: Option<Transaction>,
+    #[serde(rename = "orderReissueTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reissue_transaction: Option<Transaction>,
     /// The Transaction that rejects the reissue of the Order.
     /// Only provided when the replacing Order was partially filled
     /// immediately and was configured to be reissued, however the
     /// reissue was rejected.
-    order_reissue_reject_transaction: Option<Transaction>,
+    #[serde(rename = "orderReissueRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reissue_reject_transaction: Option<Transaction>,
     /// The Transaction that cancelled the replacing Order. Only
     /// provided when the replacing Order was immediately cancelled.
-    replacing_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "replacingOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacing_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for Put {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// A link to the replacing Order
+    pub location: Option<String>,
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
 }
-impl Default for Put {
-    fn default() -> Self {
-        Self {
-            order_cancel_transaction: Default::default(),
-            order_create_transaction: Default::default(),
-            order_fill_transaction: Default::default(),
-            order_reissue_transaction: Default::default(),
-            order_reissue_reject_transaction: Default::default(),
-            replacing_order_cancel_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            location: crate::response::header(headers, "Location"),
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The Order specification was invalid
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Put400 {
     /// The Transaction that rejected the creation of the replacing
     /// Order
-    order_reject_transaction: Option<Transaction>,
+    #[serde(rename = "orderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reject_transaction: Option<Transaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
-}
-impl Default for Put400 {
-    fn default() -> Self {
-        Self {
-            order_reject_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
 /// The Account or Order specified does not exist.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Put404 {
     /// The Transaction that rejected the cancellation of the Order
     /// to be replaced. Only present if the Account exists.
-    order_cancel_reject_transaction: Option<Transaction>,
+    #[serde(rename = "orderCancelRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_cancel_reject_transaction: Option<Transaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request. Only present if the Account exists.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account. Only present if the Account exists.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-impl Default for Put404 {
-    fn default() -> Self {
-        Self {
-            order_cancel_reject_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
-}
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E400(Put400),
-    E404(Put404),
+    /// The Order specification was invalid
+    #[error("400: The Order specification was invalid")]
+    E400(Box<Put400>),
+    /// The Account or Order specified does not exist.
+    #[error("404: The Account or Order specified does not exist.")]
+    E404(Box<Put404>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
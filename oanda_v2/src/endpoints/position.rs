@@ -1,7 +1,213 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::client_request_id::ClientRequestID;
+use crate::definitions::instrument_name::InstrumentName;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
+use crate::client::Client;
 pub mod responses;
-struct Position<'a> {
-    client: &'a Client,
+/// The json body for: Closeout the open Position for a specific
+/// instrument in an Account
+#[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloseBody {
+    /// Indication of how much of the long Position to closeout.
+    /// Either the string "ALL", the string "NONE", or a
+    /// DecimalNumber representing how many units of the long
+    /// position to close using a PositionCloseout MarketOrder. The
+    /// units specified must always be positive.
+    #[serde(rename = "longUnits")]
+    #[serde_inline_default(String::from("ALL"))]
+    pub long_units: String,
+    /// The client extensions to add to the MarketOrder used to
+    /// close the long position.
+    #[serde(rename = "longClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_client_extensions: Option<ClientExtensions>,
+    /// Indication of how much of the short Position to closeout.
+    /// Either the string "ALL", the string "NONE", or a
+    /// DecimalNumber representing how many units of the short
+    /// position to close using a PositionCloseout MarketOrder. The
+    /// units specified must always be positive.
+    #[serde(rename = "shortUnits")]
+    #[serde_inline_default(String::from("ALL"))]
+    pub short_units: String,
+    /// The client extensions to add to the MarketOrder used to
+    /// close the short position.
+    #[serde(rename = "shortClientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_client_extensions: Option<ClientExtensions>,
+}
+impl Default for CloseBody {
+    fn default() -> Self {
+        Self {
+            long_units: String::from("ALL"),
+            long_client_extensions: Default::default(),
+            short_units: String::from("ALL"),
+            short_client_extensions: Default::default(),
+        }
+    }
+}
+/// The request for: List all Positions for an Account. The
+/// Positions returned are for every instrument that has had a
+/// position during the lifetime of an the Account
+pub struct PositionsRequest {
+    account_id: AccountID,
+}
+impl PositionsRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> PositionsRequest {
+        PositionsRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::positions::Positions,
+            responses::positions::Headers,
+        >,
+        responses::positions::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}/positions";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: List all open Positions for an Account. An
+/// open Position is a Position in an Account that currently has
+/// a Trade opened for it
+pub struct OpenPositionsRequest {
+    account_id: AccountID,
+}
+impl OpenPositionsRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> OpenPositionsRequest {
+        OpenPositionsRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::open_positions::OpenPositions,
+            responses::open_positions::Headers,
+        >,
+        responses::open_positions::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}/openPositions";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get the details of a single Instrument’s
+/// Position in an Account. The Position may by open or not
+pub struct GetRequest {
+    account_id: AccountID,
+    instrument: InstrumentName,
+}
+impl GetRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `instrument` - Name of the Instrument
+    pub fn new(account_id: AccountID, instrument: InstrumentName) -> GetRequest {
+        GetRequest {
+            account_id,
+            instrument,
+        }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        let Self { account_id, instrument } = self;
+        let url = "/v3/accounts/{accountID}/positions/{instrument}";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{instrument}", &instrument.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Closeout the open Position for a specific
+/// instrument in an Account
+pub struct CloseRequest {
+    account_id: AccountID,
+    instrument: InstrumentName,
+    client_request_id: Option<ClientRequestID>,
+    body: CloseBody,
+}
+impl CloseRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `instrument` - Name of the Instrument
+    pub fn new(
+        account_id: AccountID,
+        instrument: InstrumentName,
+        body: CloseBody,
+    ) -> CloseRequest {
+        CloseRequest {
+            account_id,
+            instrument,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::close::Close, responses::close::Headers>,
+        responses::close::Error,
+    > {
+        let Self { account_id, instrument, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/positions/{instrument}/close";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{instrument}", &instrument.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .put(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+pub struct Position<'a> {
+    pub(crate) client: &'a Client,
 }
 impl<'a> Position<'a> {
     /// List all Positions for an Account. The Positions returned
@@ -9,90 +215,51 @@ impl<'a> Position<'a> {
     /// lifetime of an the Account.
     pub async fn positions(
         &self,
-        authorization: String,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/positions";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: PositionsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::positions::Positions,
+            responses::positions::Headers,
+        >,
+        responses::positions::Error,
+    > {
+        request.send(self.client).await
     }
     /// List all open Positions for an Account. An open Position is
     /// a Position in an Account that currently has a Trade opened
     /// for it.
     pub async fn open_positions(
         &self,
-        authorization: String,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/openPositions";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: OpenPositionsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::open_positions::OpenPositions,
+            responses::open_positions::Headers,
+        >,
+        responses::open_positions::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get the details of a single Instrument’s Position in an
     /// Account. The Position may by open or not.
     pub async fn get(
         &self,
-        authorization: String,
-        account_id: AccountId,
-        instrument: InstrumentName,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/positions/{instrument}";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "instrument" + "}", "instrument");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: GetRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        request.send(self.client).await
     }
     /// Closeout the open Position for a specific instrument in
     /// an Account.
     pub async fn close(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        instrument: InstrumentName,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/positions/{instrument}/close";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "instrument" + "}", "instrument");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: CloseRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::close::Close, responses::close::Headers>,
+        responses::close::Error,
+    > {
+        request.send(self.client).await
     }
 }
@@ -1,4 +1,4 @@
-pub mod get;
-pub mod positions;
 pub mod close;
+pub mod get;
 pub mod open_positions;
+pub mod positions;
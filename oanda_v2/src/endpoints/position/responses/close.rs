@@ -1,122 +1,195 @@
+use crate::definitions::market_order_reject_transaction::MarketOrderRejectTransaction;
+use crate::definitions::market_order_transaction::MarketOrderTransaction;
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::order_fill_transaction::OrderFillTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Position closeout request has been successfully
 /// processed.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Close {
     /// The MarketOrderTransaction created to close the long
     /// Position.
-    long_order_create_transaction: Option<MarketOrderTransaction>,
+    #[serde(rename = "longOrderCreateTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_order_create_transaction: Option<MarketOrderTransaction>,
     /// OrderFill Transaction that closes the long Position
-    long_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(rename = "longOrderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_order_fill_transaction: Option<OrderFillTransaction>,
     /// OrderCancel Transaction that cancels the MarketOrder created
     /// to close the long Position
-    long_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "longOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The MarketOrderTransaction created to close the short
     /// Position.
-    short_order_create_transaction: Option<MarketOrderTransaction>,
+    #[serde(rename = "shortOrderCreateTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_order_create_transaction: Option<MarketOrderTransaction>,
     /// OrderFill Transaction that closes the short Position
-    short_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(rename = "shortOrderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_order_fill_transaction: Option<OrderFillTransaction>,
     /// OrderCancel Transaction that cancels the MarketOrder created
     /// to close the short Position
-    short_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "shortOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for Close {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// A link to the Position that was just closed out
+    pub location: Option<String>,
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
 }
-impl Default for Close {
-    fn default() -> Self {
-        Self {
-            long_order_create_transaction: Default::default(),
-            long_order_fill_transaction: Default::default(),
-            long_order_cancel_transaction: Default::default(),
-            short_order_create_transaction: Default::default(),
-            short_order_fill_transaction: Default::default(),
-            short_order_cancel_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            location: crate::response::header(headers, "Location"),
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The Parameters provided that describe the Position closeout
 /// are invalid.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Close400 {
     /// The Transaction created that rejects the creation of a
     /// MarketOrder to close the long Position.
-    long_order_reject_transaction: Option<MarketOrderRejectTransaction>,
+    #[serde(rename = "longOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_order_reject_transaction: Option<MarketOrderRejectTransaction>,
     /// The Transaction created that rejects the creation of a
     /// MarketOrder to close the short Position.
-    short_order_reject_transaction: Option<MarketOrderRejectTransaction>,
+    #[serde(rename = "shortOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_order_reject_transaction: Option<MarketOrderRejectTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
-}
-impl Default for Close400 {
-    fn default() -> Self {
-        Self {
-            long_order_reject_transaction: Default::default(),
-            short_order_reject_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
 /// The Account or one or more of the Positions specified does
 /// not exist.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Close404 {
     /// The Transaction created that rejects the creation of a
     /// MarketOrder to close the long Position. Only present if the
     /// Account exists and a long Position was specified.
-    long_order_reject_transaction: Option<MarketOrderRejectTransaction>,
+    #[serde(rename = "longOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_order_reject_transaction: Option<MarketOrderRejectTransaction>,
     /// The Transaction created that rejects the creation of a
     /// MarketOrder to close the short Position. Only present if the
     /// Account exists and a short Position was specified.
-    short_order_reject_transaction: Option<MarketOrderRejectTransaction>,
+    #[serde(rename = "shortOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_order_reject_transaction: Option<MarketOrderRejectTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request. Only present if the Account exists.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account. Only present if the Account exists.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-impl Default for Close404 {
-    fn default() -> Self {
-        Self {
-            long_order_reject_transaction: Default::default(),
-            short_order_reject_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
-}
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E400(Close400),
-    E404(Close404),
+    /// The Parameters provided that describe the Position closeout
+    /// are invalid.
+    #[error(
+        "400: The Parameters provided that describe the Position closeout are invalid."
+    )]
+    E400(Box<Close400>),
+    /// The Account or one or more of the Positions specified does
+    /// not exist.
+    #[error(
+        "404: The Account or one or more of the Positions specified does not exist."
+    )]
+    E404(Box<Close404>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
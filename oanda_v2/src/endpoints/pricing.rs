@@ -1,7 +1,529 @@
-use serde::{Serialize, Deserialize};
+use crate::definitions::account_id::AccountID;
+use crate::definitions::candle_specification::CandleSpecification;
+use crate::definitions::candlestick_granularity::CandlestickGranularity;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::decimal_number::DecimalNumber;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::pricing_component::PricingComponent;
+use crate::definitions::weekly_alignment::WeeklyAlignment;
+use crate::client::Client;
 pub mod responses;
-struct Pricing<'a> {
-    client: &'a Client,
+/// The request for: Get dancing bears and most recently
+/// completed candles within an Account for specified
+/// combinations of instrument, granularity, and price component
+pub struct LatestRequest {
+    account_id: AccountID,
+    candle_specifications: Vec<CandleSpecification>,
+    units: Option<DecimalNumber>,
+    smooth: Option<bool>,
+    daily_alignment: Option<i64>,
+    alignment_timezone: Option<String>,
+    weekly_alignment: Option<WeeklyAlignment>,
+}
+impl LatestRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `candle_specifications` - List of candle specifications to
+    ///   get pricing for.
+    pub fn new(
+        account_id: AccountID,
+        candle_specifications: Vec<CandleSpecification>,
+    ) -> LatestRequest {
+        LatestRequest {
+            account_id,
+            candle_specifications,
+            units: Default::default(),
+            smooth: Default::default(),
+            daily_alignment: Default::default(),
+            alignment_timezone: Default::default(),
+            weekly_alignment: Default::default(),
+        }
+    }
+    /// The number of units used to calculate the volume-weighted
+    /// average bid and ask prices in the returned candles.
+    ///
+    /// The server uses `1` if this isn't set
+    pub fn units(mut self, units: DecimalNumber) -> Self {
+        self.units = Some(units);
+        self
+    }
+    /// A flag that controls whether the candlestick is “smoothed”
+    /// or not. A smoothed candlestick uses the previous candle’s
+    /// close price as its open price, while an unsmoothed
+    /// candlestick uses the first price from its time range as its
+    /// open price.
+    ///
+    /// The server uses `False` if this isn't set
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+    /// The hour of the day (in the specified timezone) to use for
+    /// granularities that have daily alignments.
+    ///
+    /// The server uses `17` if this isn't set
+    pub fn daily_alignment(mut self, daily_alignment: i64) -> Self {
+        self.daily_alignment = Some(daily_alignment);
+        self
+    }
+    /// The timezone to use for the dailyAlignment parameter.
+    /// Candlesticks with daily alignment will be aligned to the
+    /// dailyAlignment hour within the alignmentTimezone. Note that
+    /// the returned times will still be represented in UTC.
+    ///
+    /// The server uses `America/New_York` if this isn't set
+    pub fn alignment_timezone(mut self, alignment_timezone: String) -> Self {
+        self.alignment_timezone = Some(alignment_timezone);
+        self
+    }
+    /// The day of the week used for granularities that have weekly
+    /// alignment.
+    ///
+    /// The server uses `Friday` if this isn't set
+    pub fn weekly_alignment(mut self, weekly_alignment: WeeklyAlignment) -> Self {
+        self.weekly_alignment = Some(weekly_alignment);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::latest::Latest, responses::latest::Headers>,
+        responses::latest::Error,
+    > {
+        let Self {
+            account_id,
+            candle_specifications,
+            units,
+            smooth,
+            daily_alignment,
+            alignment_timezone,
+            weekly_alignment,
+        } = self;
+        let url = "/v3/accounts/{accountID}/candles/latest";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        query
+            .push((
+                "candleSpecifications",
+                candle_specifications
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(","),
+            ));
+        if let Some(units) = &units {
+            query.push(("units", units.to_string()));
+        }
+        if let Some(smooth) = &smooth {
+            query.push(("smooth", smooth.to_string()));
+        }
+        if let Some(daily_alignment) = &daily_alignment {
+            query.push(("dailyAlignment", daily_alignment.to_string()));
+        }
+        if let Some(alignment_timezone) = &alignment_timezone {
+            query.push(("alignmentTimezone", alignment_timezone.to_string()));
+        }
+        if let Some(weekly_alignment) = &weekly_alignment {
+            query.push(("weeklyAlignment", weekly_alignment.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get pricing information for a specified
+/// list of Instruments within an Account
+pub struct PricingRequest {
+    account_id: AccountID,
+    instruments: Vec<InstrumentName>,
+    since: Option<DateTime>,
+    include_units_available: Option<bool>,
+    include_home_conversions: Option<bool>,
+}
+impl PricingRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `instruments` - List of Instruments to get pricing for.
+    pub fn new(
+        account_id: AccountID,
+        instruments: Vec<InstrumentName>,
+    ) -> PricingRequest {
+        PricingRequest {
+            account_id,
+            instruments,
+            since: Default::default(),
+            include_units_available: Default::default(),
+            include_home_conversions: Default::default(),
+        }
+    }
+    /// Date/Time filter to apply to the response. Only prices and
+    /// home conversions (if requested) with a time later than this
+    /// filter (i.e. the price has changed after the since time)
+    /// will be provided, and are filtered independently.
+    pub fn since(mut self, since: DateTime) -> Self {
+        self.since = Some(since);
+        self
+    }
+    /// Flag that enables the inclusion of the unitsAvailable field
+    /// in the returned Price objects.
+    ///
+    /// The server uses `True` if this isn't set
+    pub fn include_units_available(mut self, include_units_available: bool) -> Self {
+        self.include_units_available = Some(include_units_available);
+        self
+    }
+    /// Flag that enables the inclusion of the homeConversions field
+    /// in the returned response. An entry will be returned for each
+    /// currency in the set of all base and quote currencies present
+    /// in the requested instruments list.
+    ///
+    /// The server uses `False` if this isn't set
+    pub fn include_home_conversions(mut self, include_home_conversions: bool) -> Self {
+        self.include_home_conversions = Some(include_home_conversions);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::pricing::Pricing,
+            responses::pricing::Headers,
+        >,
+        responses::pricing::Error,
+    > {
+        let Self {
+            account_id,
+            instruments,
+            since,
+            include_units_available,
+            include_home_conversions,
+        } = self;
+        let url = "/v3/accounts/{accountID}/pricing";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        query
+            .push((
+                "instruments",
+                instruments
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(","),
+            ));
+        if let Some(since) = &since {
+            query.push(("since", since.format(client.datetime_format)));
+        }
+        if let Some(include_units_available) = &include_units_available {
+            query.push(("includeUnitsAvailable", include_units_available.to_string()));
+        }
+        if let Some(include_home_conversions) = &include_home_conversions {
+            query.push(("includeHomeConversions", include_home_conversions.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get a stream of Account Prices starting
+/// from when the request is made.
+/// This pricing stream does not include every single price
+/// created for the Account, but instead will provide at most
+/// 4 prices per second (every 250 milliseconds) for each
+/// instrument being requested.
+/// If more than one price is created for an instrument during
+/// the 250 millisecond window, only the price in effect at the
+/// end of the window is sent. This means that during periods of
+/// rapid price movement, subscribers to this stream will not be
+/// sent every price.
+/// Pricing windows for different connections to the price
+/// stream are not all aligned in the same way (i.e. they
+/// are not all aligned to the top of the second). This means
+/// that during periods of rapid price movement, different
+/// subscribers may observe different prices depending on their
+/// alignment
+pub struct StreamRequest {
+    account_id: AccountID,
+    instruments: Vec<InstrumentName>,
+    snapshot: Option<bool>,
+    include_home_conversions: Option<bool>,
+}
+impl StreamRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `instruments` - List of Instruments to stream Prices for.
+    pub fn new(
+        account_id: AccountID,
+        instruments: Vec<InstrumentName>,
+    ) -> StreamRequest {
+        StreamRequest {
+            account_id,
+            instruments,
+            snapshot: Default::default(),
+            include_home_conversions: Default::default(),
+        }
+    }
+    /// Flag that enables/disables the sending of a pricing snapshot
+    /// when initially connecting to the stream.
+    ///
+    /// The server uses `True` if this isn't set
+    pub fn snapshot(mut self, snapshot: bool) -> Self {
+        self.snapshot = Some(snapshot);
+        self
+    }
+    /// Flag that enables the inclusion of the homeConversions field
+    /// in the returned response. An entry will be returned for each
+    /// currency in the set of all base and quote currencies present
+    /// in the requested instruments list.
+    ///
+    /// The server uses `False` if this isn't set
+    pub fn include_home_conversions(mut self, include_home_conversions: bool) -> Self {
+        self.include_home_conversions = Some(include_home_conversions);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        impl futures::Stream<
+            Item = error_stack::Result<
+                responses::stream::Stream,
+                responses::stream::Error,
+            >,
+        >,
+        responses::stream::Error,
+    > {
+        let Self { account_id, instruments, snapshot, include_home_conversions } = self;
+        let url = "/v3/accounts/{accountID}/pricing/stream";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.stream_url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        query
+            .push((
+                "instruments",
+                instruments
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(","),
+            ));
+        if let Some(snapshot) = &snapshot {
+            query.push(("snapshot", snapshot.to_string()));
+        }
+        if let Some(include_home_conversions) = &include_home_conversions {
+            query.push(("includeHomeConversions", include_home_conversions.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.stream(request).await
+    }
+}
+/// The request for: Fetch candlestick data for an instrument
+pub struct CandlesRequest {
+    account_id: AccountID,
+    instrument: InstrumentName,
+    price: Option<PricingComponent>,
+    granularity: Option<CandlestickGranularity>,
+    count: Option<i64>,
+    from: Option<DateTime>,
+    to: Option<DateTime>,
+    smooth: Option<bool>,
+    include_first: Option<bool>,
+    daily_alignment: Option<i64>,
+    alignment_timezone: Option<String>,
+    weekly_alignment: Option<WeeklyAlignment>,
+    units: Option<DecimalNumber>,
+}
+impl CandlesRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `instrument` - Name of the Instrument
+    pub fn new(account_id: AccountID, instrument: InstrumentName) -> CandlesRequest {
+        CandlesRequest {
+            account_id,
+            instrument,
+            price: Default::default(),
+            granularity: Default::default(),
+            count: Default::default(),
+            from: Default::default(),
+            to: Default::default(),
+            smooth: Default::default(),
+            include_first: Default::default(),
+            daily_alignment: Default::default(),
+            alignment_timezone: Default::default(),
+            weekly_alignment: Default::default(),
+            units: Default::default(),
+        }
+    }
+    /// The Price component(s) to get candlestick data for.
+    ///
+    /// The server uses `M` if this isn't set
+    pub fn price(mut self, price: PricingComponent) -> Self {
+        self.price = Some(price);
+        self
+    }
+    /// The granularity of the candlesticks to fetch
+    ///
+    /// The server uses `S5` if this isn't set
+    pub fn granularity(mut self, granularity: CandlestickGranularity) -> Self {
+        self.granularity = Some(granularity);
+        self
+    }
+    /// The number of candlesticks to return in the response.
+    /// Count should not be specified if both the start and end
+    /// parameters are provided, as the time range combined with
+    /// the granularity will determine the number of candlesticks
+    /// to return.
+    ///
+    /// The server uses `500` if this isn't set
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+    /// The start of the time range to fetch candlesticks for.
+    pub fn from(mut self, from: DateTime) -> Self {
+        self.from = Some(from);
+        self
+    }
+    /// The end of the time range to fetch candlesticks for.
+    pub fn to(mut self, to: DateTime) -> Self {
+        self.to = Some(to);
+        self
+    }
+    /// A flag that controls whether the candlestick is “smoothed”
+    /// or not. A smoothed candlestick uses the previous candle’s
+    /// close price as its open price, while an unsmoothed
+    /// candlestick uses the first price from its time range as its
+    /// open price.
+    ///
+    /// The server uses `False` if this isn't set
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = Some(smooth);
+        self
+    }
+    /// A flag that controls whether the candlestick that is
+    /// covered by the from time should be included in the results.
+    /// This flag enables clients to use the timestamp of the
+    /// last completed candlestick received to poll for future
+    /// candlesticks but avoid receiving the previous candlestick
+    /// repeatedly.
+    ///
+    /// The server uses `True` if this isn't set
+    pub fn include_first(mut self, include_first: bool) -> Self {
+        self.include_first = Some(include_first);
+        self
+    }
+    /// The hour of the day (in the specified timezone) to use for
+    /// granularities that have daily alignments.
+    ///
+    /// The server uses `17` if this isn't set
+    pub fn daily_alignment(mut self, daily_alignment: i64) -> Self {
+        self.daily_alignment = Some(daily_alignment);
+        self
+    }
+    /// The timezone to use for the dailyAlignment parameter.
+    /// Candlesticks with daily alignment will be aligned to the
+    /// dailyAlignment hour within the alignmentTimezone. Note that
+    /// the returned times will still be represented in UTC.
+    ///
+    /// The server uses `America/New_York` if this isn't set
+    pub fn alignment_timezone(mut self, alignment_timezone: String) -> Self {
+        self.alignment_timezone = Some(alignment_timezone);
+        self
+    }
+    /// The day of the week used for granularities that have weekly
+    /// alignment.
+    ///
+    /// The server uses `Friday` if this isn't set
+    pub fn weekly_alignment(mut self, weekly_alignment: WeeklyAlignment) -> Self {
+        self.weekly_alignment = Some(weekly_alignment);
+        self
+    }
+    /// The number of units used to calculate the volume-weighted
+    /// average bid and ask prices in the returned candles.
+    ///
+    /// The server uses `1` if this isn't set
+    pub fn units(mut self, units: DecimalNumber) -> Self {
+        self.units = Some(units);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::candles::Candles,
+            responses::candles::Headers,
+        >,
+        responses::candles::Error,
+    > {
+        let Self {
+            account_id,
+            instrument,
+            price,
+            granularity,
+            count,
+            from,
+            to,
+            smooth,
+            include_first,
+            daily_alignment,
+            alignment_timezone,
+            weekly_alignment,
+            units,
+        } = self;
+        let url = "/v3/accounts/{accountID}/instruments/{instrument}/candles";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{instrument}", &instrument.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(price) = &price {
+            query.push(("price", price.to_string()));
+        }
+        if let Some(granularity) = &granularity {
+            query.push(("granularity", granularity.to_string()));
+        }
+        if let Some(count) = &count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(from) = &from {
+            query.push(("from", from.format(client.datetime_format)));
+        }
+        if let Some(to) = &to {
+            query.push(("to", to.format(client.datetime_format)));
+        }
+        if let Some(smooth) = &smooth {
+            query.push(("smooth", smooth.to_string()));
+        }
+        if let Some(include_first) = &include_first {
+            query.push(("includeFirst", include_first.to_string()));
+        }
+        if let Some(daily_alignment) = &daily_alignment {
+            query.push(("dailyAlignment", daily_alignment.to_string()));
+        }
+        if let Some(alignment_timezone) = &alignment_timezone {
+            query.push(("alignmentTimezone", alignment_timezone.to_string()));
+        }
+        if let Some(weekly_alignment) = &weekly_alignment {
+            query.push(("weeklyAlignment", weekly_alignment.to_string()));
+        }
+        if let Some(units) = &units {
+            query.push(("units", units.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+pub struct Pricing<'a> {
+    pub(crate) client: &'a Client,
 }
 impl<'a> Pricing<'a> {
     /// Get dancing bears and most recently completed candles
@@ -9,69 +531,26 @@ impl<'a> Pricing<'a> {
     /// granularity, and price component.
     pub async fn latest(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        candle_specifications: ListOf,
-        units: DecimalNumber,
-        smooth: bool,
-        daily_alignment: Integer,
-        alignment_timezone: String,
-        weekly_alignment: WeeklyAlignment,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/candles/latest";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [
-            ("candleSpecifications", candle_specifications),
-            ("units", units),
-            ("smooth", smooth),
-            ("dailyAlignment", daily_alignment),
-            ("alignmentTimezone", alignment_timezone),
-            ("weeklyAlignment", weekly_alignment),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: LatestRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::latest::Latest, responses::latest::Headers>,
+        responses::latest::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get pricing information for a specified list of Instruments
     /// within an Account.
     pub async fn pricing(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        instruments: ListOf,
-        since: DateTime<Utc>,
-        include_units_available: bool,
-        include_home_conversions: bool,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/pricing";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [
-            ("instruments", instruments),
-            ("since", since),
-            ("includeUnitsAvailable", include_units_available),
-            ("includeHomeConversions", include_home_conversions),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: PricingRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::pricing::Pricing,
+            responses::pricing::Headers,
+        >,
+        responses::pricing::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get a stream of Account Prices starting from when the
     /// request is made.
@@ -92,77 +571,29 @@ impl<'a> Pricing<'a> {
     /// alignment.
     pub async fn stream(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        instruments: ListOf,
-        snapshot: bool,
-        include_home_conversions: bool,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/pricing/stream";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [
-            ("instruments", instruments),
-            ("snapshot", snapshot),
-            ("includeHomeConversions", include_home_conversions),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: StreamRequest,
+    ) -> error_stack::Result<
+        impl futures::Stream<
+            Item = error_stack::Result<
+                responses::stream::Stream,
+                responses::stream::Error,
+            >,
+        >,
+        responses::stream::Error,
+    > {
+        request.send(self.client).await
     }
     /// Fetch candlestick data for an instrument.
     pub async fn candles(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        instrument: InstrumentName,
-        price: PricingComponent,
-        granularity: CandlestickGranularity,
-        count: Integer,
-        from: DateTime<Utc>,
-        to: DateTime<Utc>,
-        smooth: bool,
-        include_first: bool,
-        daily_alignment: Integer,
-        alignment_timezone: String,
-        weekly_alignment: WeeklyAlignment,
-        units: DecimalNumber,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/instruments/{instrument}/candles";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "instrument" + "}", "instrument");
-        let url = self.client.url(url);
-        let query = [
-            ("price", price),
-            ("granularity", granularity),
-            ("count", count),
-            ("from", from),
-            ("to", to),
-            ("smooth", smooth),
-            ("includeFirst", include_first),
-            ("dailyAlignment", daily_alignment),
-            ("alignmentTimezone", alignment_timezone),
-            ("weeklyAlignment", weekly_alignment),
-            ("units", units),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: CandlesRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::candles::Candles,
+            responses::candles::Headers,
+        >,
+        responses::candles::Error,
+    > {
+        request.send(self.client).await
     }
 }
@@ -1,4 +1,4 @@
-pub mod pricing;
 pub mod candles;
 pub mod latest;
+pub mod pricing;
 pub mod stream;
@@ -1,23 +1,88 @@
+use crate::definitions::client_price::ClientPrice;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::home_conversions::HomeConversions;
+use serde::{Serialize, Deserialize};
 /// Pricing information has been successfully provided.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Pricing {
     /// The list of Price objects requested.
-    prices: Vec<ClientPrice>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prices: Vec<ClientPrice>,
     /// The list of home currency conversion factors requested. This
     /// field will only be present if includeHomeConversions was set
     /// to true in the request.
-    home_conversions: Vec<HomeConversions>,
-    /// The DateTime<Utc> value to use for the “since” parameter in the
+    #[serde(rename = "homeConversions")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub home_conversions: Vec<HomeConversions>,
+    /// The DateTime value to use for the “since” parameter in the
     /// next poll request.
-    time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime>,
 }
-impl Default for Pricing {
-    fn default() -> Self {
-        Self {
-            prices: Default::default(),
-            home_conversions: Default::default(),
-            time: Default::default(),
+impl crate::response::ResponseBody for Pricing {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        None
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The server replied with HTTP status 400
+    #[error("HTTP status 400: {}", .0.error_message)]
+    E400(crate::error::ErrorBody),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 404
+    #[error("HTTP status 404: {}", .0.error_message)]
+    E404(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
+}
@@ -1,160 +1,506 @@
+use crate::definitions::account_id::AccountID;
+use crate::definitions::client_extensions::ClientExtensions;
+use crate::definitions::client_request_id::ClientRequestID;
+use crate::definitions::guaranteed_stop_loss_details::GuaranteedStopLossDetails;
+use crate::definitions::instrument_name::InstrumentName;
+use crate::definitions::stop_loss_details::StopLossDetails;
+use crate::definitions::take_profit_details::TakeProfitDetails;
+use crate::definitions::trade_id::TradeID;
+use crate::definitions::trade_specifier::TradeSpecifier;
+use crate::definitions::trade_state_filter::TradeStateFilter;
+use crate::definitions::trailing_stop_loss_details::TrailingStopLossDetails;
+use serde_inline_default::serde_inline_default;
 use serde::{Serialize, Deserialize};
+use crate::client::Client;
 pub mod responses;
-struct Trade<'a> {
-    client: &'a Client,
+/// The json body for: Close (partially or fully) a specific
+/// open Trade in an Account
+#[serde_inline_default]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloseBody {
+    /// Indication of how much of the Trade to close. Either the
+    /// string "ALL" (indicating that all of the Trade should be
+    /// closed), or a DecimalNumber representing the number of units
+    /// of the open Trade to Close using a TradeClose MarketOrder.
+    /// The units specified must always be positive, and the
+    /// magnitude of the value cannot exceed the magnitude of the
+    /// Trade's open units.
+    #[serde_inline_default(String::from("ALL"))]
+    pub units: String,
+}
+impl Default for CloseBody {
+    fn default() -> Self {
+        Self { units: String::from("ALL") }
+    }
+}
+/// The json body for: Update the Client Extensions for a Trade.
+/// Do not add, update, or delete the Client Extensions if your
+/// account is associated with MT4
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientExtensionsBody {
+    /// The Client Extensions to update the Trade with. Do not add,
+    /// update, or delete the Client Extensions if your account is
+    /// associated with MT4.
+    #[serde(rename = "clientExtensions")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_extensions: Option<ClientExtensions>,
+}
+/// The json body for: Create, replace and cancel a Trade’s
+/// dependent Orders (Take Profit, Stop Loss and Trailing Stop
+/// Loss) through the Trade itself
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrdersBody {
+    /// The specification of the Take Profit to create/modify/
+    /// cancel. If takeProfit is set to null, the Take Profit
+    /// Order will be cancelled if it exists. If takeProfit is
+    /// not provided, the existing Take Profit Order will not be
+    /// modified. If a sub-field of takeProfit is not specified,
+    /// that field will be set to a default value on create, and be
+    /// inherited by the replacing order on modify.
+    #[serde(rename = "takeProfit")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<TakeProfitDetails>,
+    /// The specification of the Stop Loss to create/modify/cancel.
+    /// If stopLoss is set to null, the Stop Loss Order will be
+    /// cancelled if it exists. If stopLoss is not provided, the
+    /// existing Stop Loss Order will not be modified. If a sub-
+    /// field of stopLoss is not specified, that field will be
+    /// set to a default value on create, and be inherited by the
+    /// replacing order on modify.
+    #[serde(rename = "stopLoss")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<StopLossDetails>,
+    /// The specification of the Trailing Stop Loss to create/
+    /// modify/cancel. If trailingStopLoss is set to null, the
+    /// Trailing Stop Loss Order will be cancelled if it exists.
+    /// If trailingStopLoss is not provided, the existing Trailing
+    /// Stop Loss Order will not be modified. If a sub-field of
+    /// trailingStopLoss is not specified, that field will be set to
+    /// a default value on create, and be inherited by the replacing
+    /// order on modify.
+    #[serde(rename = "trailingStopLoss")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss: Option<TrailingStopLossDetails>,
+    /// The specification of the Guaranteed Stop Loss to create/
+    /// modify/cancel. If guaranteedStopLoss is set to null,
+    /// the Guaranteed Stop Loss Order will be cancelled if it
+    /// exists. If guaranteedStopLoss is not provided, the existing
+    /// Guaranteed Stop Loss Order will not be modified. If a sub-
+    /// field of guaranteedStopLoss is not specified, that field
+    /// will be set to a default value on create, and be inherited
+    /// by the replacing order on modify.
+    #[serde(rename = "guaranteedStopLoss")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss: Option<GuaranteedStopLossDetails>,
+}
+/// The request for: Get a list of Trades for an Account
+pub struct TradesRequest {
+    account_id: AccountID,
+    ids: Vec<TradeID>,
+    state: Option<TradeStateFilter>,
+    instrument: Option<InstrumentName>,
+    count: Option<i64>,
+    before_id: Option<TradeID>,
+}
+impl TradesRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> TradesRequest {
+        TradesRequest {
+            account_id,
+            ids: Default::default(),
+            state: Default::default(),
+            instrument: Default::default(),
+            count: Default::default(),
+            before_id: Default::default(),
+        }
+    }
+    /// List of Trade IDs to retrieve.
+    pub fn ids(mut self, ids: impl IntoIterator<Item = TradeID>) -> Self {
+        self.ids = ids.into_iter().collect();
+        self
+    }
+    /// The state to filter the requested Trades by.
+    ///
+    /// The server uses `OPEN` if this isn't set
+    pub fn state(mut self, state: TradeStateFilter) -> Self {
+        self.state = Some(state);
+        self
+    }
+    /// The instrument to filter the requested Trades by.
+    pub fn instrument(mut self, instrument: InstrumentName) -> Self {
+        self.instrument = Some(instrument);
+        self
+    }
+    /// The maximum number of Trades to return.
+    ///
+    /// The server uses `50` if this isn't set
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+    /// The maximum Trade ID to return. If not provided the most
+    /// recent Trades in the Account are returned.
+    pub fn before_id(mut self, before_id: TradeID) -> Self {
+        self.before_id = Some(before_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::trades::Trades, responses::trades::Headers>,
+        responses::trades::Error,
+    > {
+        let Self { account_id, ids, state, instrument, count, before_id } = self;
+        let url = "/v3/accounts/{accountID}/trades";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if !ids.is_empty() {
+            query
+                .push((
+                    "ids",
+                    ids
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ));
+        }
+        if let Some(state) = &state {
+            query.push(("state", state.to_string()));
+        }
+        if let Some(instrument) = &instrument {
+            query.push(("instrument", instrument.to_string()));
+        }
+        if let Some(count) = &count {
+            query.push(("count", count.to_string()));
+        }
+        if let Some(before_id) = &before_id {
+            query.push(("beforeID", before_id.to_string()));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get the list of open Trades for an Account
+pub struct OpenTradesRequest {
+    account_id: AccountID,
+}
+impl OpenTradesRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> OpenTradesRequest {
+        OpenTradesRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::open_trades::OpenTrades,
+            responses::open_trades::Headers,
+        >,
+        responses::open_trades::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}/openTrades";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get the details of a specific Trade in
+/// an Account
+pub struct GetRequest {
+    account_id: AccountID,
+    trade_specifier: TradeSpecifier,
+}
+impl GetRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `trade_specifier` - Specifier for the Trade
+    pub fn new(account_id: AccountID, trade_specifier: TradeSpecifier) -> GetRequest {
+        GetRequest {
+            account_id,
+            trade_specifier,
+        }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        let Self { account_id, trade_specifier } = self;
+        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{tradeSpecifier}", &trade_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Close (partially or fully) a specific open
+/// Trade in an Account
+pub struct CloseRequest {
+    account_id: AccountID,
+    trade_specifier: TradeSpecifier,
+    client_request_id: Option<ClientRequestID>,
+    body: CloseBody,
+}
+impl CloseRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `trade_specifier` - Specifier for the Trade
+    pub fn new(
+        account_id: AccountID,
+        trade_specifier: TradeSpecifier,
+        body: CloseBody,
+    ) -> CloseRequest {
+        CloseRequest {
+            account_id,
+            trade_specifier,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::close::Close, responses::close::Headers>,
+        responses::close::Error,
+    > {
+        let Self { account_id, trade_specifier, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}/close";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{tradeSpecifier}", &trade_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .put(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+/// The request for: Update the Client Extensions for a Trade.
+/// Do not add, update, or delete the Client Extensions if your
+/// account is associated with MT4
+pub struct ClientExtensionsRequest {
+    account_id: AccountID,
+    trade_specifier: TradeSpecifier,
+    client_request_id: Option<ClientRequestID>,
+    body: ClientExtensionsBody,
+}
+impl ClientExtensionsRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `trade_specifier` - Specifier for the Trade
+    pub fn new(
+        account_id: AccountID,
+        trade_specifier: TradeSpecifier,
+        body: ClientExtensionsBody,
+    ) -> ClientExtensionsRequest {
+        ClientExtensionsRequest {
+            account_id,
+            trade_specifier,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::client_extensions::ClientExtensions,
+            responses::client_extensions::Headers,
+        >,
+        responses::client_extensions::Error,
+    > {
+        let Self { account_id, trade_specifier, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}/clientExtensions";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{tradeSpecifier}", &trade_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .put(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+/// The request for: Create, replace and cancel a Trade’s
+/// dependent Orders (Take Profit, Stop Loss and Trailing Stop
+/// Loss) through the Trade itself
+pub struct OrdersRequest {
+    account_id: AccountID,
+    trade_specifier: TradeSpecifier,
+    client_request_id: Option<ClientRequestID>,
+    body: OrdersBody,
+}
+impl OrdersRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `trade_specifier` - Specifier for the Trade
+    pub fn new(
+        account_id: AccountID,
+        trade_specifier: TradeSpecifier,
+        body: OrdersBody,
+    ) -> OrdersRequest {
+        OrdersRequest {
+            account_id,
+            trade_specifier,
+            client_request_id: Default::default(),
+            body,
+        }
+    }
+    /// Client specified RequestID to be sent with request.
+    pub fn client_request_id(mut self, client_request_id: ClientRequestID) -> Self {
+        self.client_request_id = Some(client_request_id);
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::orders::Orders, responses::orders::Headers>,
+        responses::orders::Error,
+    > {
+        let Self { account_id, trade_specifier, client_request_id, body } = self;
+        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}/orders";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{tradeSpecifier}", &trade_specifier.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client
+            .put(&url)
+            .query(&query)
+            .json(&crate::datetime_format::json_body(&body, client.datetime_format));
+        let request = match client_request_id {
+            Some(client_request_id) => {
+                request.header("ClientRequestID", &*client_request_id)
+            }
+            None => request,
+        };
+        client.send(request).await
+    }
+}
+pub struct Trade<'a> {
+    pub(crate) client: &'a Client,
 }
 impl<'a> Trade<'a> {
     /// Get a list of Trades for an Account
     pub async fn trades(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        ids: ListOf,
-        state: TradeStateFilter,
-        instrument: InstrumentName,
-        count: Integer,
-        before_id: TradeId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/trades";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [
-            ("ids", ids),
-            ("state", state),
-            ("instrument", instrument),
-            ("count", count),
-            ("beforeID", before_id),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: TradesRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::trades::Trades, responses::trades::Headers>,
+        responses::trades::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get the list of open Trades for an Account
     pub async fn open_trades(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/openTrades";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: OpenTradesRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::open_trades::OpenTrades,
+            responses::open_trades::Headers,
+        >,
+        responses::open_trades::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get the details of a specific Trade in an Account
     pub async fn get(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        trade_specifier: TradeSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "tradeSpecifier" + "}", "trade_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: GetRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        request.send(self.client).await
     }
     /// Close (partially or fully) a specific open Trade in an
     /// Account
     pub async fn close(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        trade_specifier: TradeSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}/close";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "tradeSpecifier" + "}", "trade_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: CloseRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::close::Close, responses::close::Headers>,
+        responses::close::Error,
+    > {
+        request.send(self.client).await
     }
     /// Update the Client Extensions for a Trade. Do not add,
     /// update, or delete the Client Extensions if your account is
     /// associated with MT4.
     pub async fn client_extensions(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        trade_specifier: TradeSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}/clientExtensions";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "tradeSpecifier" + "}", "trade_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: ClientExtensionsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::client_extensions::ClientExtensions,
+            responses::client_extensions::Headers,
+        >,
+        responses::client_extensions::Error,
+    > {
+        request.send(self.client).await
     }
     /// Create, replace and cancel a Trade’s dependent Orders (Take
     /// Profit, Stop Loss and Trailing Stop Loss) through the Trade
     /// itself
     pub async fn orders(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        trade_specifier: TradeSpecifier,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/trades/{tradeSpecifier}/orders";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "tradeSpecifier" + "}", "trade_specifier");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .put(url)
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: OrdersRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::orders::Orders, responses::orders::Headers>,
+        responses::orders::Error,
+    > {
+        request.send(self.client).await
     }
 }
@@ -1,6 +1,6 @@
 pub mod client_extensions;
-pub mod orders;
-pub mod trades;
-pub mod open_trades;
 pub mod close;
 pub mod get;
+pub mod open_trades;
+pub mod orders;
+pub mod trades;
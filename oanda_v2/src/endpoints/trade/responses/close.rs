@@ -1,91 +1,148 @@
+use crate::definitions::market_order_reject_transaction::MarketOrderRejectTransaction;
+use crate::definitions::market_order_transaction::MarketOrderTransaction;
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::order_fill_transaction::OrderFillTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Trade has been closed as requested
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Close {
     /// The MarketOrder Transaction created to close the Trade.
-    order_create_transaction: Option<MarketOrderTransaction>,
+    #[serde(rename = "orderCreateTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_create_transaction: Option<MarketOrderTransaction>,
     /// The OrderFill Transaction that fills the Trade-closing
     /// MarketOrder and closes the Trade.
-    order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(rename = "orderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_fill_transaction: Option<OrderFillTransaction>,
     /// The OrderCancel Transaction that immediately cancelled the
     /// Trade-closing MarketOrder.
-    order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "orderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for Close {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
 }
-impl Default for Close {
-    fn default() -> Self {
-        Self {
-            order_create_transaction: Default::default(),
-            order_fill_transaction: Default::default(),
-            order_cancel_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The Trade cannot be closed as requested.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Close400 {
     /// The MarketOrderReject Transaction that rejects the creation
     /// of the Trade- closing MarketOrder.
-    order_reject_transaction: Option<MarketOrderRejectTransaction>,
+    #[serde(rename = "orderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reject_transaction: Option<MarketOrderRejectTransaction>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
-}
-impl Default for Close400 {
-    fn default() -> Self {
-        Self {
-            order_reject_transaction: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
 /// The Account or Trade specified does not exist.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Close404 {
     /// The MarketOrderReject Transaction that rejects the creation
     /// of the Trade- closing MarketOrder. Only present if the
     /// Account exists.
-    order_reject_transaction: Option<MarketOrderRejectTransaction>,
+    #[serde(rename = "orderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_reject_transaction: Option<MarketOrderRejectTransaction>,
     /// The ID of the most recent Transaction created for the
     /// Account. Only present if the Account exists.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request. Only present if the Account exists.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-impl Default for Close404 {
-    fn default() -> Self {
-        Self {
-            order_reject_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
-}
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E400(Close400),
-    E404(Close404),
+    /// The Trade cannot be closed as requested.
+    #[error("400: The Trade cannot be closed as requested.")]
+    E400(Box<Close400>),
+    /// The Account or Trade specified does not exist.
+    #[error("404: The Account or Trade specified does not exist.")]
+    E404(Box<Close404>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
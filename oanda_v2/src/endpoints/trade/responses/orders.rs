@@ -1,145 +1,236 @@
+use crate::definitions::guaranteed_stop_loss_order_reject_transaction::GuaranteedStopLossOrderRejectTransaction;
+use crate::definitions::guaranteed_stop_loss_order_transaction::GuaranteedStopLossOrderTransaction;
+use crate::definitions::order_cancel_reject_transaction::OrderCancelRejectTransaction;
+use crate::definitions::order_cancel_transaction::OrderCancelTransaction;
+use crate::definitions::order_fill_transaction::OrderFillTransaction;
+use crate::definitions::stop_loss_order_reject_transaction::StopLossOrderRejectTransaction;
+use crate::definitions::stop_loss_order_transaction::StopLossOrderTransaction;
+use crate::definitions::take_profit_order_reject_transaction::TakeProfitOrderRejectTransaction;
+use crate::definitions::take_profit_order_transaction::TakeProfitOrderTransaction;
+use crate::definitions::trailing_stop_loss_order_reject_transaction::TrailingStopLossOrderRejectTransaction;
+use crate::definitions::trailing_stop_loss_order_transaction::TrailingStopLossOrderTransaction;
+use crate::definitions::transaction_id::TransactionID;
+use serde::{Serialize, Deserialize};
 /// The Trade’s dependent Orders have been modified as
 /// requested.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Orders {
     /// The Transaction created that cancels the Trade’s existing
     /// Take Profit Order.
-    take_profit_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "takeProfitOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction created that creates a new Take Profit Order
     /// for the Trade.
-    take_profit_order_transaction: Option<TakeProfitOrderTransaction>,
+    #[serde(rename = "takeProfitOrderTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_transaction: Option<TakeProfitOrderTransaction>,
     /// The Transaction created that immediately fills the Trade’s
     /// new Take Profit Order. Only provided if the new Take Profit
     /// Order was immediately filled.
-    take_profit_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(rename = "takeProfitOrderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_fill_transaction: Option<OrderFillTransaction>,
     /// The Transaction created that immediately cancels the Trade’s
     /// new Take Profit Order. Only provided if the new Take Profit
     /// Order was immediately cancelled.
-    take_profit_order_created_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "takeProfitOrderCreatedCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_created_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction created that cancels the Trade’s existing
     /// Stop Loss Order.
-    stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "stopLossOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction created that creates a new Stop Loss Order
     /// for the Trade.
-    stop_loss_order_transaction: Option<StopLossOrderTransaction>,
+    #[serde(rename = "stopLossOrderTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_transaction: Option<StopLossOrderTransaction>,
     /// The Transaction created that immediately fills the Trade’s
     /// new Stop Order. Only provided if the new Stop Loss Order was
     /// immediately filled.
-    stop_loss_order_fill_transaction: Option<OrderFillTransaction>,
+    #[serde(rename = "stopLossOrderFillTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_fill_transaction: Option<OrderFillTransaction>,
     /// The Transaction created that immediately cancels the Trade’s
     /// new Stop Loss Order. Only provided if the new Stop Loss
     /// Order was immediately cancelled.
-    stop_loss_order_created_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "stopLossOrderCreatedCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_created_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction created that cancels the Trade’s existing
     /// Trailing Stop Loss Order.
-    trailing_stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "trailingStopLossOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction created that creates a new Trailing Stop
     /// Loss Order for the Trade.
-    trailing_stop_loss_order_transaction: Option<TrailingStopLossOrderTransaction>,
+    #[serde(rename = "trailingStopLossOrderTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order_transaction: Option<TrailingStopLossOrderTransaction>,
     /// The Transaction created that cancels the Trade’s existing
     /// Guaranteed Stop Loss Order.
-    guaranteed_stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
+    #[serde(rename = "guaranteedStopLossOrderCancelTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_cancel_transaction: Option<OrderCancelTransaction>,
     /// The Transaction created that creates a new Guaranteed Stop
     /// Loss Order for the Trade.
-    guaranteed_stop_loss_order_transaction: Option<GuaranteedStopLossOrderTransaction>,
+    #[serde(rename = "guaranteedStopLossOrderTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_transaction: Option<
+        GuaranteedStopLossOrderTransaction,
+    >,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The ID of the most recent Transaction created for the
     /// Account
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
+}
+impl crate::response::ResponseBody for Orders {
+    fn last_transaction_id(
+        &self,
+    ) -> Option<crate::definitions::transaction_id::TransactionID> {
+        self.last_transaction_id.clone()
+    }
 }
-impl Default for Orders {
-    fn default() -> Self {
-        Self {
-            take_profit_order_cancel_transaction: Default::default(),
-            take_profit_order_transaction: Default::default(),
-            take_profit_order_fill_transaction: Default::default(),
-            take_profit_order_created_cancel_transaction: Default::default(),
-            stop_loss_order_cancel_transaction: Default::default(),
-            stop_loss_order_transaction: Default::default(),
-            stop_loss_order_fill_transaction: Default::default(),
-            stop_loss_order_created_cancel_transaction: Default::default(),
-            trailing_stop_loss_order_cancel_transaction: Default::default(),
-            trailing_stop_loss_order_transaction: Default::default(),
-            guaranteed_stop_loss_order_cancel_transaction: Default::default(),
-            guaranteed_stop_loss_order_transaction: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            last_transaction_id: Default::default(),
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    /// The unique identifier generated for the request
+    pub request_id: Option<crate::definitions::request_id::RequestID>,
+}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {
+            request_id: crate::response::header(headers, "RequestID"),
         }
     }
 }
-use serde::{Serialize, Deserialize};
 /// The Trade’s dependent Orders cannot be modified as
 /// requested.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Orders400 {
     /// An OrderCancelRejectTransaction represents the rejection of
     /// the cancellation of an Order in the client’s Account.
-    take_profit_order_cancel_reject_transaction: Option<OrderCancelRejectTransaction>,
+    #[serde(rename = "takeProfitOrderCancelRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_cancel_reject_transaction: Option<
+        OrderCancelRejectTransaction,
+    >,
     /// A TakeProfitOrderRejectTransaction represents the rejection
     /// of the creation of a TakeProfit Order.
-    take_profit_order_reject_transaction: Option<TakeProfitOrderRejectTransaction>,
+    #[serde(rename = "takeProfitOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take_profit_order_reject_transaction: Option<TakeProfitOrderRejectTransaction>,
     /// An OrderCancelRejectTransaction represents the rejection of
     /// the cancellation of an Order in the client’s Account.
-    stop_loss_order_cancel_reject_transaction: Option<OrderCancelRejectTransaction>,
+    #[serde(rename = "stopLossOrderCancelRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_cancel_reject_transaction: Option<OrderCancelRejectTransaction>,
     /// A StopLossOrderRejectTransaction represents the rejection of
     /// the creation of a StopLoss Order.
-    stop_loss_order_reject_transaction: Option<StopLossOrderRejectTransaction>,
+    #[serde(rename = "stopLossOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_order_reject_transaction: Option<StopLossOrderRejectTransaction>,
     /// An OrderCancelRejectTransaction represents the rejection of
     /// the cancellation of an Order in the client’s Account.
-    trailing_stop_loss_order_cancel_reject_transaction: Option<
+    #[serde(rename = "trailingStopLossOrderCancelRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order_cancel_reject_transaction: Option<
         OrderCancelRejectTransaction,
     >,
     /// A TrailingStopLossOrderRejectTransaction represents the
     /// rejection of the creation of a TrailingStopLoss Order.
-    trailing_stop_loss_order_reject_transaction: Option<
+    #[serde(rename = "trailingStopLossOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_loss_order_reject_transaction: Option<
         TrailingStopLossOrderRejectTransaction,
     >,
     /// An OrderCancelRejectTransaction represents the rejection of
     /// the cancellation of an Order in the client’s Account.
-    guaranteed_stop_loss_order_cancel_reject_transaction: Option<
+    #[serde(rename = "guaranteedStopLossOrderCancelRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_cancel_reject_transaction: Option<
         OrderCancelRejectTransaction,
     >,
     /// A GuaranteedStopLossOrderRejectTransaction represents the
     /// rejection of the creation of a GuaranteedStopLoss Order.
-    guaranteed_stop_loss_order_reject_transaction: Option<
+    #[serde(rename = "guaranteedStopLossOrderRejectTransaction")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_stop_loss_order_reject_transaction: Option<
         GuaranteedStopLossOrderRejectTransaction,
     >,
     /// The ID of the most recent Transaction created for the
     /// Account.
-    last_transaction_id: Option<TransactionID>,
+    #[serde(rename = "lastTransactionID")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_transaction_id: Option<TransactionID>,
     /// The IDs of all Transactions that were created while
     /// satisfying the request.
-    related_transaction_i_ds: Vec<TransactionID>,
+    #[serde(rename = "relatedTransactionIDs")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_transaction_ids: Vec<TransactionID>,
     /// The code of the error that has occurred. This field may not
     /// be returned for some errors.
-    error_code: Option<String>,
+    #[serde(rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
     /// The human-readable description of the error that has
     /// occurred.
-    error_message: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
 }
-impl Default for Orders400 {
-    fn default() -> Self {
-        Self {
-            take_profit_order_cancel_reject_transaction: Default::default(),
-            take_profit_order_reject_transaction: Default::default(),
-            stop_loss_order_cancel_reject_transaction: Default::default(),
-            stop_loss_order_reject_transaction: Default::default(),
-            trailing_stop_loss_order_cancel_reject_transaction: Default::default(),
-            trailing_stop_loss_order_reject_transaction: Default::default(),
-            guaranteed_stop_loss_order_cancel_reject_transaction: Default::default(),
-            guaranteed_stop_loss_order_reject_transaction: Default::default(),
-            last_transaction_id: Default::default(),
-            related_transaction_i_ds: Default::default(),
-            error_code: Default::default(),
-            error_message: Default::default(),
-        }
-    }
-}
-#[derive(Debug)]
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    E400(Orders400),
+    /// The Trade’s dependent Orders cannot be modified as
+    /// requested.
+    #[error("400: The Trade’s dependent Orders cannot be modified as requested.")]
+    E400(Box<Orders400>),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 404
+    #[error("HTTP status 404: {}", .0.error_message)]
+    E404(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
 }
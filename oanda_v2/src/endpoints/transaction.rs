@@ -1,135 +1,372 @@
-use serde::{Serialize, Deserialize};
+use crate::definitions::account_id::AccountID;
+use crate::definitions::date_time::DateTime;
+use crate::definitions::transaction_filter::TransactionFilter;
+use crate::definitions::transaction_id::TransactionID;
+use crate::client::Client;
 pub mod responses;
-struct Transaction<'a> {
-    client: &'a Client,
+/// The request for: Get a list of Transactions pages that
+/// satisfy a time-based Transaction query
+pub struct TransactionsRequest {
+    account_id: AccountID,
+    from: Option<DateTime>,
+    to: Option<DateTime>,
+    page_size: Option<i64>,
+    r#type: Vec<TransactionFilter>,
+}
+impl TransactionsRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> TransactionsRequest {
+        TransactionsRequest {
+            account_id,
+            from: Default::default(),
+            to: Default::default(),
+            page_size: Default::default(),
+            r#type: Default::default(),
+        }
+    }
+    /// The starting time (inclusive) of the time range for the
+    /// Transactions being queried.
+    ///
+    /// The server uses `Account Creation Time` if this isn't set
+    pub fn from(mut self, from: DateTime) -> Self {
+        self.from = Some(from);
+        self
+    }
+    /// The ending time (inclusive) of the time range for the
+    /// Transactions being queried.
+    ///
+    /// The server uses `Request Time` if this isn't set
+    pub fn to(mut self, to: DateTime) -> Self {
+        self.to = Some(to);
+        self
+    }
+    /// The number of Transactions to include in each page of the
+    /// results.
+    ///
+    /// The server uses `100` if this isn't set
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+    /// A filter for restricting the types of Transactions to
+    /// retrieve.
+    pub fn r#type(
+        mut self,
+        r#type: impl IntoIterator<Item = TransactionFilter>,
+    ) -> Self {
+        self.r#type = r#type.into_iter().collect();
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::transactions::Transactions,
+            responses::transactions::Headers,
+        >,
+        responses::transactions::Error,
+    > {
+        let Self { account_id, from, to, page_size, r#type } = self;
+        let url = "/v3/accounts/{accountID}/transactions";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(from) = &from {
+            query.push(("from", from.format(client.datetime_format)));
+        }
+        if let Some(to) = &to {
+            query.push(("to", to.format(client.datetime_format)));
+        }
+        if let Some(page_size) = &page_size {
+            query.push(("pageSize", page_size.to_string()));
+        }
+        if !r#type.is_empty() {
+            query
+                .push((
+                    "type",
+                    r#type
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get the details of a single Account
+/// Transaction
+pub struct GetRequest {
+    account_id: AccountID,
+    transaction_id: TransactionID,
+}
+impl GetRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `transaction_id` - A Transaction ID
+    pub fn new(account_id: AccountID, transaction_id: TransactionID) -> GetRequest {
+        GetRequest {
+            account_id,
+            transaction_id,
+        }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        let Self { account_id, transaction_id } = self;
+        let url = "/v3/accounts/{accountID}/transactions/{transactionID}";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = url.replace("{transactionID}", &transaction_id.to_string());
+        let url = client.url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get a range of Transactions for an Account
+/// based on the Transaction IDs
+pub struct IdrangeRequest {
+    account_id: AccountID,
+    from: TransactionID,
+    to: TransactionID,
+    r#type: Vec<TransactionFilter>,
+}
+impl IdrangeRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `from` - The starting Transaction ID (inclusive) to fetch.
+    /// * `to` - The ending Transaction ID (inclusive) to fetch.
+    pub fn new(
+        account_id: AccountID,
+        from: TransactionID,
+        to: TransactionID,
+    ) -> IdrangeRequest {
+        IdrangeRequest {
+            account_id,
+            from,
+            to,
+            r#type: Default::default(),
+        }
+    }
+    /// The filter that restricts the types of Transactions to
+    /// retrieve.
+    pub fn r#type(
+        mut self,
+        r#type: impl IntoIterator<Item = TransactionFilter>,
+    ) -> Self {
+        self.r#type = r#type.into_iter().collect();
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::idrange::Idrange,
+            responses::idrange::Headers,
+        >,
+        responses::idrange::Error,
+    > {
+        let Self { account_id, from, to, r#type } = self;
+        let url = "/v3/accounts/{accountID}/transactions/idrange";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        query.push(("from", from.to_string()));
+        query.push(("to", to.to_string()));
+        if !r#type.is_empty() {
+            query
+                .push((
+                    "type",
+                    r#type
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get a range of Transactions for an Account
+/// starting at (but not including) a provided Transaction ID
+pub struct SinceidRequest {
+    account_id: AccountID,
+    id: TransactionID,
+    r#type: Vec<TransactionFilter>,
+}
+impl SinceidRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    /// * `id` - The ID of the last Transaction fetched. This query
+    ///   will return all Transactions newer than the TransactionID.
+    pub fn new(account_id: AccountID, id: TransactionID) -> SinceidRequest {
+        SinceidRequest {
+            account_id,
+            id,
+            r#type: Default::default(),
+        }
+    }
+    /// A filter for restricting the types of Transactions to
+    /// retrieve.
+    pub fn r#type(
+        mut self,
+        r#type: impl IntoIterator<Item = TransactionFilter>,
+    ) -> Self {
+        self.r#type = r#type.into_iter().collect();
+        self
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::sinceid::Sinceid,
+            responses::sinceid::Headers,
+        >,
+        responses::sinceid::Error,
+    > {
+        let Self { account_id, id, r#type } = self;
+        let url = "/v3/accounts/{accountID}/transactions/sinceid";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.url(url);
+        let mut query: Vec<(&str, String)> = Vec::new();
+        query.push(("id", id.to_string()));
+        if !r#type.is_empty() {
+            query
+                .push((
+                    "type",
+                    r#type
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ));
+        }
+        let request = client.get(&url).query(&query);
+        client.send(request).await
+    }
+}
+/// The request for: Get a stream of Transactions for an Account
+/// starting from when the request is made
+pub struct StreamRequest {
+    account_id: AccountID,
+}
+impl StreamRequest {
+    /// Creates the request with the parameters it can't do without:
+    ///
+    /// * `account_id` - Account Identifier
+    pub fn new(account_id: AccountID) -> StreamRequest {
+        StreamRequest { account_id }
+    }
+    /// Sends the request
+    pub async fn send(
+        self,
+        client: &Client,
+    ) -> error_stack::Result<
+        impl futures::Stream<
+            Item = error_stack::Result<
+                responses::stream::Stream,
+                responses::stream::Error,
+            >,
+        >,
+        responses::stream::Error,
+    > {
+        let Self { account_id } = self;
+        let url = "/v3/accounts/{accountID}/transactions/stream";
+        let url = url.replace("{accountID}", &account_id.to_string());
+        let url = client.stream_url(url);
+        let query: Vec<(&str, String)> = Vec::new();
+        let request = client.get(&url).query(&query);
+        client.stream(request).await
+    }
+}
+pub struct Transaction<'a> {
+    pub(crate) client: &'a Client,
 }
 impl<'a> Transaction<'a> {
     /// Get a list of Transactions pages that satisfy a time-based
     /// Transaction query.
     pub async fn transactions(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        from: DateTime<Utc>,
-        to: DateTime<Utc>,
-        page_size: Integer,
-        r#type: ListOf,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/transactions";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [
-            ("from", from),
-            ("to", to),
-            ("pageSize", page_size),
-            ("type", r#type),
-        ];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: TransactionsRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::transactions::Transactions,
+            responses::transactions::Headers,
+        >,
+        responses::transactions::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get the details of a single Account Transaction.
     pub async fn get(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        transaction_id: TransactionId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/transactions/{transactionID}";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = url.replace("{" + "transactionID" + "}", "transaction_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: GetRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<responses::get::Get, responses::get::Headers>,
+        responses::get::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get a range of Transactions for an Account based on the
     /// Transaction IDs.
     pub async fn idrange(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        from: TransactionId,
-        to: TransactionId,
-        r#type: ListOf,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/transactions/idrange";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [("from", from), ("to", to), ("type", r#type)];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: IdrangeRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::idrange::Idrange,
+            responses::idrange::Headers,
+        >,
+        responses::idrange::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get a range of Transactions for an Account starting at (but
     /// not including) a provided Transaction ID.
     pub async fn sinceid(
         &self,
-        authorization: String,
-        accept_datetime_format: AcceptDatetimeFormat,
-        account_id: AccountId,
-        id: TransactionId,
-        r#type: ListOf,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/transactions/sinceid";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [("id", id), ("type", r#type)];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .header("Accept-Datetime-Format", accept_datetime_format)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: SinceidRequest,
+    ) -> error_stack::Result<
+        crate::response::Response<
+            responses::sinceid::Sinceid,
+            responses::sinceid::Headers,
+        >,
+        responses::sinceid::Error,
+    > {
+        request.send(self.client).await
     }
     /// Get a stream of Transactions for an Account starting from
     /// when the request is made.
     pub async fn stream(
         &self,
-        authorization: String,
-        account_id: AccountId,
-    ) -> Result<()> {
-        let url = "/v3/accounts/{accountID}/transactions/stream";
-        let url = url.replace("{" + "accountID" + "}", "account_id");
-        let url = self.client.url(url);
-        let query = [];
-        let response = self
-            .client
-            .get(self.client.start_get(url))
-            .await?
-            .header("Authorization", authorization)
-            .query(&query)
-            .send()
-            .await?;
-        let status_code = response.status_code();
+        request: StreamRequest,
+    ) -> error_stack::Result<
+        impl futures::Stream<
+            Item = error_stack::Result<
+                responses::stream::Stream,
+                responses::stream::Error,
+            >,
+        >,
+        responses::stream::Error,
+    > {
+        request.send(self.client).await
     }
 }
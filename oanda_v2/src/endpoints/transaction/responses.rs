@@ -1,5 +1,5 @@
-pub mod idrange;
 pub mod get;
+pub mod idrange;
 pub mod sinceid;
 pub mod stream;
 pub mod transactions;
@@ -1,2 +1,78 @@
+use crate::definitions::transaction::Transaction;
+use crate::definitions::transaction_heartbeat::TransactionHeartbeat;
+use serde::Serialize;
 /// Connecting to the Transaction Stream was successful.
-struct Stream();
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Stream {
+    Transaction(Transaction),
+    TransactionHeartbeat(TransactionHeartbeat),
+}
+impl crate::stream::StreamItem for Stream {
+    fn from_line(
+        tag: Option<&str>,
+        line: &[u8],
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        match tag {
+            Some("HEARTBEAT") => {
+                crate::error::from_json(line).map(Stream::TransactionHeartbeat)
+            }
+            _ => crate::error::from_json(line).map(Stream::Transaction),
+        }
+    }
+}
+/// The headers documented for a successful reply
+#[derive(Debug, Clone, Default)]
+pub struct Headers {}
+impl crate::response::ResponseHeaders for Headers {
+    fn from_headers(_headers: &reqwest::header::HeaderMap) -> Self {
+        Headers {}
+    }
+}
+/// All the ways this call can fail
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The server replied with HTTP status 400
+    #[error("HTTP status 400: {}", .0.error_message)]
+    E400(crate::error::ErrorBody),
+    /// The server replied with HTTP status 401
+    #[error("HTTP status 401: {}", .0.error_message)]
+    E401(crate::error::ErrorBody),
+    /// The server replied with HTTP status 404
+    #[error("HTTP status 404: {}", .0.error_message)]
+    E404(crate::error::ErrorBody),
+    /// The server replied with HTTP status 405
+    #[error("HTTP status 405: {}", .0.error_message)]
+    E405(crate::error::ErrorBody),
+    /// The server replied with a status code that isn't documented for this call
+    #[error("Unexpected HTTP status {0}")]
+    Unexpected(u16),
+    /// The request couldn't be sent, or the reply couldn't be read
+    #[error("Unable to make the request")]
+    Request,
+    /// The reply isn't what the docs say this call sends
+    #[error("Unable to parse the reply")]
+    Decode,
+}
+impl crate::client::CallError for Error {
+    fn from_response(
+        status: u16,
+        body: &str,
+    ) -> std::result::Result<Self, crate::error::JsonError> {
+        Ok(
+            match status {
+                400 => Error::E400(crate::error::from_json(body.as_bytes())?),
+                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                405 => Error::E405(crate::error::from_json(body.as_bytes())?),
+                _ => Error::Unexpected(status),
+            },
+        )
+    }
+    fn request() -> Self {
+        Error::Request
+    }
+    fn decode() -> Self {
+        Error::Decode
+    }
+}
@@ -1,28 +1,329 @@
-use error_stack::{Report, ResultExt};
-use parse_display::Display;
-use reqwest::StatusCode;
+use crate::definitions::transaction::Transaction;
+use crate::definitions::transaction_reject_reason::TransactionRejectReason;
 use serde::Deserialize;
-#[derive(Display, Debug, Default)]
-#[display(style = "snake_case")]
+#[derive(Debug, Default, thiserror::Error)]
 pub enum Error {
     /// Most errors come from this category
     #[default]
+    #[error("general")]
     General,
-    #[display("Message: {}")]
+    #[error("Message: {0}")]
     Message(String),
+    /// The request couldn't be sent, or its reply couldn't be read
+    #[error("Unable to reach the server")]
+    Transport,
+    /// The server replied with a status code other than 2xx
+    #[error("HTTP status {status}: {}", body.error_message)]
+    Http { status: u16, body: ErrorBody },
+    /// The server replied that the transaction was rejected, eg. with a
+    /// `MarketOrderRejectTransaction`
+    #[error("Transaction rejected: {reason:?}")]
+    Rejected { transaction: Box<Transaction>, reason: Option<TransactionRejectReason> },
+    /// The reply wasn't the json we expected
+    #[error(
+        "Unable to parse the json from {url} at {path}, line {line} column {column}: {message}"
+    )]
+    Json {
+        /// The url of the call that sent the json
+        url: String,
+        /// Where in the json it went wrong, eg. `orders[2].price`
+        path: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The server replied with HTTP status 429 Too Many Requests
+    #[error("Too many requests")]
+    RateLimit,
 }
 impl Error {
     pub fn new(msg: impl ToString) -> Error {
         Error::Message(msg.to_string())
     }
+    /// For when the json from `url` can't be parsed, see [`from_json`]
+    pub fn json(url: impl ToString, err: &JsonError) -> Error {
+        Error::Json {
+            url: url.to_string(),
+            path: err.path().to_string(),
+            line: err.inner().line(),
+            column: err.inner().column(),
+            message: err.inner().to_string(),
+        }
+    }
+    /// Works out what went wrong from the reply to a request, with a status code other
+    /// than 2xx
+    pub fn from_response(status: u16, body: &str) -> Error {
+        if status == 429 {
+            return Error::RateLimit;
+        }
+        let unparsed = || ErrorBody {
+            error_code: None,
+            error_message: body.to_string(),
+        };
+        let json = match serde_json::from_str::<serde_json::Value>(body) {
+            Ok(json) => json,
+            Err(_) => {
+                return Error::Http {
+                    status,
+                    body: unparsed(),
+                };
+            }
+        };
+        let rejected = json
+            .as_object()
+            .into_iter()
+            .flatten()
+            .find(|(key, _)| key.ends_with("RejectTransaction"))
+            .and_then(|(_, transaction)| {
+                let reason = transaction
+                    .get("rejectReason")
+                    .and_then(|reason| serde_json::from_value(reason.clone()).ok());
+                serde_json::from_value(transaction.clone())
+                    .ok()
+                    .map(|transaction| (transaction, reason))
+            });
+        if let Some((transaction, reason)) = rejected {
+            return Error::Rejected {
+                transaction: Box::new(transaction),
+                reason,
+            };
+        }
+        let body = serde_json::from_value(json).unwrap_or_else(|_| unparsed());
+        Error::Http { status, body }
+    }
 }
-impl std::error::Error for Error {}
 pub type Result<T> = error_stack::Result<T, Error>;
-pub trait Take {
-    fn take(self, msg: impl ToString) -> impl ResultExt;
+/// Why some json couldn't be parsed, and the path to the value it failed at
+pub type JsonError = serde_path_to_error::Error<serde_json::Error>;
+/// Parses `json`, keeping the path to the value it fails at for an [`Error::Json`]
+pub fn from_json<'a, T: Deserialize<'a>>(
+    json: &'a [u8],
+) -> std::result::Result<T, JsonError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer
+        .end()
+        .map_err(|err| JsonError::new(serde_path_to_error::Track::new().path(), err))?;
+    Ok(value)
+}
+/// Parses `value` from inside another type's `Deserialize`, eg. one variant of a tagged
+/// union. The outer path stops at `value`, so the path within it goes in the message
+pub fn from_value<T: serde::de::DeserializeOwned, E: serde::de::Error>(
+    value: serde_json::Value,
+) -> std::result::Result<T, E> {
+    serde_path_to_error::deserialize(value).map_err(E::custom)
+}
+/// The json body the server sends with most errors
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorBody {
+    pub error_code: Option<String>,
+    pub error_message: String,
+}
+/// The HTTP error statuses OANDA documents, and what usually causes them. The client
+/// attaches one to the report of a call that fails with one of these statuses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpErrorKind {
+    /// A “400 Bad Request” reponse may be returned from the v20
+    /// REST API when the client has provided invalid data to be
+    /// processed.
+    ///
+    /// ## Malformed Request
+    ///
+    /// If an incorrect request is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage" : "The request was missing required data"
+    /// ```
+    ///
+    /// **Reason:** One or more required parameters for the request
+    /// is missing or is invalid.
+    ///
+    /// ## Invalid Instrument
+    ///
+    /// If an incorrect currency is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"Invalid value specified for 'instrument'"
+    /// ```
+    ///
+    /// **Reason:** The Currency provided is invalid. Confirm the
+    /// structure of currency is correct.
+    ///
+    /// ## PRECISION_EXCEEDED
+    ///
+    /// If an incorrect value is submitted the following error will
+    /// be returned to the user.
+    ///
+    /// ```text
+    /// "rejectReason":" STOP_LOSS_ON_FILL_PRICE_PRECISION_EXCEEDED"
+    /// ```
+    ///
+    /// **Reason:** The error message
+    /// “STOP_LOSS_ON_FILL_PRICE_PRECISION_EXCEEDED” means that
+    /// The Stop Loss on fill specifies a price with more precision
+    /// than is allowed by the Order’s instrument. Confirm the value
+    /// provided for price is a string.
+    BadRequest,
+    /// A “401 Unauthorized” reponse may be returned from the v20
+    /// REST API when the endpoint being accessed requires the
+    /// client to be authenticated however the authentication token
+    /// is invalid or has not been provided.
+    ///
+    /// ## Invalid AccountID
+    ///
+    /// If an incorrect accountID is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"The provided request was forbidden."
+    /// ```
+    ///
+    /// **Reason:** The given accountID is invalid. Confirm the
+    /// accountID is correct.
+    ///
+    /// ## Insufficient Authorization
+    ///
+    /// If an incorrect request is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"Insufficient authorization to perform request."
+    /// ```
+    ///
+    /// **Reason:** The endpoint being accessed requires the client
+    /// to be authenticated. The following information needs to
+    /// be verified:
+    /// 1. The URL provided to the curl command is correct. Click
+    ///    here to learn how to configure curl examples that can be
+    ///    copied without further modification.
+    /// 2. The authentication token is valid and has been added as a
+    ///    Bearer token in the HTTP Authorization header.
+    Unauthorized,
+    /// A “403 Forbidden” response may be returned from the v20
+    /// REST API when the client has provided a token that does
+    /// not authorize them to perform the action implemented by the
+    /// API endpoint.
+    ///
+    /// ## Account Not Tradable
+    ///
+    /// If an incorrect request is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"Insufficient authorization to perform request."
+    /// ```
+    ///
+    /// **Reason:** The account specified can not be used for
+    /// trading. Please contact OANDA support for more details
+    Forbidden,
+    /// A “404 Not Found” response may be returned from the v20
+    /// REST API when the client is attempting to refer to an entity
+    /// (Account, Trade, Order, Position, etc.) that does not exist.
+    ///
+    /// ## NO_SUCH_TRADE
+    ///
+    /// If an incorrect trade ID is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"The trade ID specified does not exist"
+    /// ```
+    ///
+    /// **Reason:** The trade id specified is not valid. The /trade
+    /// returns recent trade and it is possible that the trade being
+    /// referenced is no longer in the recent trade list.
+    ///
+    /// ## NO_SUCH_TRANSACTION
+    ///
+    /// If an incorrect transaction ID is submitted the following
+    /// error will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"The transaction ID specified does not exist"
+    /// ```
+    ///
+    /// **Reason:** The transaction id specified is not valid. The /
+    /// transactions returns recent transactions and it is possible
+    /// that the transaction being referenced is no longer in the
+    /// recent transactions list.
+    ///
+    /// ## NO_SUCH_ORDER
+    ///
+    /// If an incorrect order ID is submitted the following error
+    /// will be returned to the user.
+    ///
+    /// ```text
+    /// "errorMessage":"The order ID specified does not exist"
+    /// ```
+    ///
+    /// **Reason:** The order id specified is not valid. The /
+    /// orders returns recent transactions and it is possible that
+    /// the order being referenced is no longer in the recent order
+    /// list.
+    NotFound,
+    /// The “405 Method Not Allowed” response may be returned from
+    /// the v20 REST API when the client is accessing an endpoint
+    /// using an incorrect HTTP method – for example, using GET on
+    /// an endpoint that requires POST.
+    MethodNotAllowed,
+}
+impl HttpErrorKind {
+    /// The kind for an HTTP status code, if OANDA documents it
+    pub fn from_status(status: u16) -> Option<HttpErrorKind> {
+        match status {
+            400 => Some(HttpErrorKind::BadRequest),
+            401 => Some(HttpErrorKind::Unauthorized),
+            403 => Some(HttpErrorKind::Forbidden),
+            404 => Some(HttpErrorKind::NotFound),
+            405 => Some(HttpErrorKind::MethodNotAllowed),
+            _ => None,
+        }
+    }
+    /// The HTTP status code, eg. 404
+    pub fn status(&self) -> u16 {
+        match self {
+            HttpErrorKind::BadRequest => 400,
+            HttpErrorKind::Unauthorized => 401,
+            HttpErrorKind::Forbidden => 403,
+            HttpErrorKind::NotFound => 404,
+            HttpErrorKind::MethodNotAllowed => 405,
+        }
+    }
+    /// What usually causes this error, and how to fix it
+    pub fn remedy(&self) -> &'static str {
+        match self {
+            HttpErrorKind::BadRequest => {
+                "Malformed Request: One or more required parameters for the request is missing or is invalid.\nInvalid Instrument: The Currency provided is invalid. Confirm the structure of currency is correct.\nPRECISION_EXCEEDED: The error message “STOP_LOSS_ON_FILL_PRICE_PRECISION_EXCEEDED” means that The Stop Loss on fill specifies a price with more precision than is allowed by the Order’s instrument. Confirm the value provided for price is a string."
+            }
+            HttpErrorKind::Unauthorized => {
+                "Invalid AccountID: The given accountID is invalid. Confirm the accountID is correct.\nInsufficient Authorization: The endpoint being accessed requires the client to be authenticated. The following information needs to be verified: 1. The URL provided to the curl command is correct. Click here to learn how to configure curl examples that can be copied without further modification. 2. The authentication token is valid and has been added as a Bearer token in the HTTP Authorization header."
+            }
+            HttpErrorKind::Forbidden => {
+                "Account Not Tradable: The account specified can not be used for trading. Please contact OANDA support for more details"
+            }
+            HttpErrorKind::NotFound => {
+                "NO_SUCH_TRADE: The trade id specified is not valid. The /trade returns recent trade and it is possible that the trade being referenced is no longer in the recent trade list.\nNO_SUCH_TRANSACTION: The transaction id specified is not valid. The /transactions returns recent transactions and it is possible that the transaction being referenced is no longer in the recent transactions list.\nNO_SUCH_ORDER: The order id specified is not valid. The /orders returns recent transactions and it is possible that the order being referenced is no longer in the recent order list."
+            }
+            HttpErrorKind::MethodNotAllowed => {
+                "The “405 Method Not Allowed” response may be returned from the v20 REST API when the client is accessing an endpoint using an incorrect HTTP method – for example, using GET on an endpoint that requires POST."
+            }
+        }
+    }
 }
-impl<R: ResultExt> Take for R {
-    fn take(self, msg: impl ToString) -> impl ResultExt {
-        self.change_context_lazy(|| Error::new(msg.to_string()))
+/// eg. "404 Not Found"
+impl std::fmt::Display for HttpErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            match self {
+                HttpErrorKind::BadRequest => "400 Bad Request",
+                HttpErrorKind::Unauthorized => "401 Unauthorized",
+                HttpErrorKind::Forbidden => "403 Forbidden",
+                HttpErrorKind::NotFound => "404 Not Found",
+                HttpErrorKind::MethodNotAllowed => "405 Method Not Allowed",
+            },
+        )
     }
 }
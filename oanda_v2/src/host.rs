@@ -1,30 +1,138 @@
-use parse_display::Display;
-/// Whether to use the dev or live hosts
+use crate::{Error, Result};
+use error_stack::{Report, ResultExt};
+use reqwest::Url;
+/// Whether to use the dev or live hosts, or your own
 /// See: <https://developer.oanda.com/rest-live-v20/development-guide/>
-#[derive(Debug, Clone, Copy, Display)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Host {
     Dev,
     Live,
+    /// Base URLs of your own, eg. a local mock server or a proxy. See [`Host::custom`]
+    Custom {
+        rest: Url,
+        stream: Url,
+    },
 }
 impl Host {
-    /// Returns the API endpoint for the REST API
+    /// Sends the REST calls to `rest`, and the streaming calls to `stream`. Each is a scheme,
+    /// host, optional port and optional path prefix, eg. "http://localhost:8080/oanda"
+    pub fn custom(rest: &str, stream: &str) -> Result<Host> {
+        let parse = |url: &str| {
+            let parsed = Url::parse(url)
+                .map_err(Report::from)
+                .change_context_lazy(|| Error::new(format!("Invalid base URL: {url}")))?;
+            if parsed.cannot_be_a_base() || parsed.host_str().is_none() {
+                return Err(Report::new(Error::new(format!("Invalid base URL: {url}"))));
+            }
+            Ok(parsed)
+        };
+        Ok(Host::Custom {
+            rest: parse(rest)?,
+            stream: parse(stream)?,
+        })
+    }
+    /// Returns the API endpoint for the REST API. A custom one has its port, if it isn't the
+    /// scheme's default, eg. "localhost:8080"
     /// See: <https://developer.oanda.com/rest-live-v20/development-guide/>
-    pub fn rest(&self) -> &'static str {
+    pub fn rest(&self) -> String {
         match self {
-            Host::Dev => "api-fxpractice.oanda.com",
-            Host::Live => "api-fxtrade.oanda.com",
+            Host::Dev => "api-fxpractice.oanda.com".to_string(),
+            Host::Live => "api-fxtrade.oanda.com".to_string(),
+            Host::Custom { rest, .. } => host_and_port(rest),
         }
     }
-    /// Returns the streaming API
+    /// Returns the streaming API, with the port of a custom one like [`Host::rest`]
     /// See: <https://developer.oanda.com/rest-live-v20/development-guide/>
-    pub fn streaming(&self) -> &'static str {
+    pub fn streaming(&self) -> String {
         match self {
-            Host::Dev => "stream-fxpractice.oanda.com",
-            Host::Live => "stream-fxtrade.oanda.com",
+            Host::Dev => "stream-fxpractice.oanda.com".to_string(),
+            Host::Live => "stream-fxtrade.oanda.com".to_string(),
+            Host::Custom { stream, .. } => host_and_port(stream),
         }
     }
-    /// Generates a URL using the current host, `https` and your `path`
+    /// Generates a URL using the current host, `https` and your `path`. A custom host's
+    /// base URL is used as is, so it may be `http` or have a path prefix
     pub fn rest_url(&self, path: impl std::fmt::Display) -> String {
-        format!("https://{}{path}", self.rest())
+        match self {
+            Host::Custom { rest, .. } => join(rest, path),
+            _ => format!("https://{}{path}", self.rest()),
+        }
+    }
+    /// Generates a URL using the current streaming host, `https` and your `path`, or the
+    /// custom streaming base URL
+    pub fn stream_url(&self, path: impl std::fmt::Display) -> String {
+        match self {
+            Host::Custom { stream, .. } => join(stream, path),
+            _ => format!("https://{}{path}", self.streaming()),
+        }
+    }
+}
+/// eg. "localhost:8080". `Url::port` leaves out the scheme's default port
+fn host_and_port(url: &Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+/// Puts `path` after the path of `base`, eg. "http://localhost:8080/oanda/v3/accounts". The
+/// base's query is kept, and its fragment dropped, as it's never sent
+fn join(base: &Url, path: impl std::fmt::Display) -> String {
+    let mut url = base.clone();
+    url.set_fragment(None);
+    // `Host::custom` only takes URLs that can be a base, so they have path segments
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop_if_empty().extend(
+            path.to_string()
+                .split('/')
+                .filter(|segment| !segment.is_empty()),
+        );
+    }
+    url.to_string()
+}
+impl std::fmt::Display for Host {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Host::Dev => write!(f, "Dev"),
+            Host::Live => write!(f, "Live"),
+            Host::Custom { rest, stream } => write!(f, "Custom({rest}, {stream})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Host;
+
+    #[test]
+    fn test_urls() {
+        assert_eq!(
+            "https://api-fxpractice.oanda.com/v3/accounts",
+            Host::Dev.rest_url("/v3/accounts")
+        );
+        let host = Host::custom("http://localhost:8080", "http://127.0.0.1:8081/oanda/").unwrap();
+        assert_eq!(
+            "http://localhost:8080/v3/accounts",
+            host.rest_url("/v3/accounts")
+        );
+        assert_eq!(
+            "http://127.0.0.1:8081/oanda/v3/accounts/1/pricing/stream",
+            host.stream_url("/v3/accounts/1/pricing/stream")
+        );
+        assert_eq!("localhost:8080", host.rest());
+        assert_eq!("127.0.0.1:8081", host.streaming());
+        assert_eq!("api-fxtrade.oanda.com", Host::Live.rest());
+        let host = Host::custom(
+            "https://proxy.example/oanda?key=1#top",
+            "https://proxy.example:443",
+        )
+        .unwrap();
+        assert_eq!(
+            "https://proxy.example/oanda/v3/accounts?key=1",
+            host.rest_url("/v3/accounts")
+        );
+        assert_eq!("proxy.example", host.streaming());
+        assert!(Host::custom("localhost:8080", "http://localhost").is_err());
+        assert!(Host::custom("not a url", "http://localhost").is_err());
     }
 }
@@ -1,6 +1,15 @@
 pub mod host;
 pub mod error;
 pub mod client;
+pub mod datetime_format;
+pub mod decimal;
 pub mod definitions;
 pub mod endpoints;
+pub mod price_stream;
+pub mod rate_limit;
+pub mod response;
+pub mod retry;
+pub mod stream;
+pub mod transaction_stream;
+pub mod transport;
 pub use error::{Error, Result};
@@ -0,0 +1,266 @@
+//! A price stream that stays connected. OANDA sends a `PricingHeartbeat` every 5 seconds or so;
+//! when they stop coming, or the connection drops, it reconnects and asks for a fresh snapshot
+//! of the prices, so a long running strategy never quietly goes stale. eg:
+//!
+//! ```ignore
+//! let mut events = PriceStream::new(client, account_id, vec![eur_usd]).start();
+//! while let Some(event) = events.next().await {
+//!     match event {
+//!         PriceEvent::Price(price) => ...,
+//!         PriceEvent::Disconnected { reason, .. } => warn!("Prices are stale: {reason}"),
+//!         _ => {}
+//!     }
+//! }
+//! ```
+use crate::{
+    client::Client,
+    definitions::{
+        account_id::AccountID, client_price::ClientPrice, instrument_name::InstrumentName,
+        pricing_heartbeat::PricingHeartbeat,
+    },
+    endpoints::pricing::{responses::stream, StreamRequest},
+    retry::RetryPolicy,
+};
+use futures::{channel::mpsc, SinkExt, Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{task::JoinHandle, time::Instant};
+
+/// What the price stream sends: the prices, and how the connection is doing
+#[derive(Debug)]
+pub enum PriceEvent {
+    /// Connected, or reconnected, to the stream. A snapshot of the current prices follows
+    Connected,
+    Price(ClientPrice),
+    Heartbeat(PricingHeartbeat),
+    /// The prices have stopped coming. It'll reconnect after `retry_in`
+    Disconnected {
+        reason: Disconnect,
+        /// The number of times in a row it's failed to stay connected
+        attempt: u32,
+        retry_in: Duration,
+    },
+}
+
+/// Why the price stream stopped
+#[derive(Debug, thiserror::Error)]
+pub enum Disconnect {
+    /// No heartbeat arrived in time
+    #[error("No heartbeat for {0:?}")]
+    Stale(Duration),
+    /// The server ended the stream
+    #[error("The server closed the stream")]
+    Closed,
+    /// Connecting failed, or the connection broke
+    #[error("{0:?}")]
+    Failed(error_stack::Report<stream::Error>),
+}
+
+/// Sets up a price stream that reconnects itself, see [`PriceStream::start`]
+#[derive(Debug, Clone)]
+pub struct PriceStream {
+    client: Client,
+    account_id: AccountID,
+    instruments: Vec<InstrumentName>,
+    heartbeat_timeout: Duration,
+    reconnect: RetryPolicy,
+}
+
+impl PriceStream {
+    pub fn new(client: Client, account_id: AccountID, instruments: Vec<InstrumentName>) -> Self {
+        PriceStream {
+            client,
+            account_id,
+            instruments,
+            heartbeat_timeout: Duration::from_secs(10),
+            reconnect: RetryPolicy::default()
+                .with_backoff(Duration::from_secs(1), Duration::from_secs(30)),
+        }
+    }
+
+    /// How long to go without a heartbeat before reconnecting. Defaults to 10 seconds
+    pub fn heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    /// How long to wait before reconnecting. It never gives up, so `max_attempts` isn't used.
+    /// Defaults to 1 second, doubling up to 30 seconds
+    pub fn reconnect(mut self, reconnect: RetryPolicy) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// Connects, and keeps reconnecting, in the background until the [`PriceEvents`] are
+    /// dropped. Must be called from within a tokio runtime
+    pub fn start(self) -> PriceEvents {
+        let (sender, receiver) = mpsc::channel(256);
+        let task = tokio::spawn(self.run(sender));
+        PriceEvents { receiver, task }
+    }
+
+    async fn run(self, mut events: mpsc::Sender<PriceEvent>) {
+        let mut attempt = 0;
+        loop {
+            let reason = match self.connect(&mut events, &mut attempt).await {
+                Ok(reason) => reason,
+                // Nobody is listening any more
+                Err(_) => return,
+            };
+            attempt += 1;
+            let retry_in = self.reconnect.backoff(attempt);
+            let disconnected = PriceEvent::Disconnected {
+                reason,
+                attempt,
+                retry_in,
+            };
+            if events.send(disconnected).await.is_err() {
+                return;
+            }
+            tokio::time::sleep(retry_in).await;
+        }
+    }
+
+    /// Streams the prices until the connection fails or goes quiet, and says why it stopped.
+    /// Once something arrives, the `attempt`s start again from 0. Only connecting isn't enough,
+    /// or a server that accepts the connection and then says nothing would be retried quickly
+    /// forever
+    async fn connect(
+        &self,
+        events: &mut mpsc::Sender<PriceEvent>,
+        attempt: &mut u32,
+    ) -> Result<Disconnect, mpsc::SendError> {
+        // Ask for the current prices every time, so nothing missed while disconnected is stale
+        let request =
+            StreamRequest::new(self.account_id.clone(), self.instruments.clone()).snapshot(true);
+        let prices = match self.client.pricing().stream(request).await {
+            Ok(prices) => prices,
+            Err(report) => return Ok(Disconnect::Failed(report)),
+        };
+        let mut prices = std::pin::pin!(prices);
+        events.send(PriceEvent::Connected).await?;
+        let mut deadline = Instant::now() + self.heartbeat_timeout;
+        loop {
+            let item = match tokio::time::timeout_at(deadline, prices.next()).await {
+                Err(_) => return Ok(Disconnect::Stale(self.heartbeat_timeout)),
+                Ok(None) => return Ok(Disconnect::Closed),
+                Ok(Some(Err(report))) => return Ok(Disconnect::Failed(report)),
+                Ok(Some(Ok(item))) => item,
+            };
+            *attempt = 0;
+            let event = match item {
+                stream::Stream::ClientPrice(price) => PriceEvent::Price(price),
+                stream::Stream::PricingHeartbeat(heartbeat) => {
+                    deadline = Instant::now() + self.heartbeat_timeout;
+                    PriceEvent::Heartbeat(heartbeat)
+                }
+            };
+            events.send(event).await?;
+        }
+    }
+}
+
+/// The events from a running [`PriceStream`]. Dropping it disconnects
+#[derive(Debug)]
+pub struct PriceEvents {
+    receiver: mpsc::Receiver<PriceEvent>,
+    task: JoinHandle<()>,
+}
+
+impl Stream for PriceEvents {
+    type Item = PriceEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for PriceEvents {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Disconnect, PriceEvent, PriceStream};
+    use crate::{
+        client::Client,
+        host::Host,
+        rate_limit::RateLimit,
+        retry::RetryPolicy,
+        transport::{CannedResponse, MemoryTransport},
+    };
+    use futures::StreamExt;
+    use reqwest::Method;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_reconnects_when_closed() {
+        let heartbeat = r#"{"type":"HEARTBEAT","time":"2023-11-14T22:13:20.000000000Z"}"#;
+        let path = "/v3/accounts/001-001-1234567-001/pricing/stream";
+        let transport = MemoryTransport::new();
+        transport
+            .respond(
+                Method::GET,
+                path,
+                CannedResponse::chunked(200, vec![format!("{heartbeat}\n").into_bytes()]),
+            )
+            .respond(Method::GET, path, CannedResponse::new(200, ""));
+        let client = Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport.clone())
+            .with_stream_rate_limit(RateLimit::per_second(1000));
+        let account_id = serde_json::from_str(r#""001-001-1234567-001""#).unwrap();
+        let instrument = serde_json::from_str(r#""EUR_USD""#).unwrap();
+        let fast = RetryPolicy::default().with_backoff(Duration::ZERO, Duration::ZERO);
+        let mut events = PriceStream::new(client, account_id, vec![instrument])
+            .reconnect(fast)
+            .start();
+        assert!(matches!(events.next().await, Some(PriceEvent::Connected)));
+        assert!(matches!(
+            events.next().await,
+            Some(PriceEvent::Heartbeat(_))
+        ));
+        assert!(matches!(
+            events.next().await,
+            Some(PriceEvent::Disconnected {
+                reason: Disconnect::Closed,
+                attempt: 1,
+                ..
+            })
+        ));
+        assert!(matches!(events.next().await, Some(PriceEvent::Connected)));
+        // Every connection asks for the current prices
+        let sent = transport.requests();
+        assert!(sent[1].url.query().unwrap().contains("snapshot=true"));
+    }
+
+    #[tokio::test]
+    async fn test_backs_off_when_nothing_arrives() {
+        let path = "/v3/accounts/001-001-1234567-001/pricing/stream";
+        let transport = MemoryTransport::new();
+        // Accepts the connection, and closes it without sending anything
+        transport
+            .respond(Method::GET, path, CannedResponse::new(200, ""))
+            .respond(Method::GET, path, CannedResponse::new(200, ""));
+        let client = Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport.clone())
+            .with_stream_rate_limit(RateLimit::per_second(1000));
+        let account_id = serde_json::from_str(r#""001-001-1234567-001""#).unwrap();
+        let instrument = serde_json::from_str(r#""EUR_USD""#).unwrap();
+        let fast = RetryPolicy::default().with_backoff(Duration::ZERO, Duration::ZERO);
+        let mut events = PriceStream::new(client, account_id, vec![instrument])
+            .reconnect(fast)
+            .start();
+        for expected in [1, 2] {
+            assert!(matches!(events.next().await, Some(PriceEvent::Connected)));
+            let Some(PriceEvent::Disconnected { attempt, .. }) = events.next().await else {
+                panic!("Expected to be disconnected")
+            };
+            assert_eq!(expected, attempt);
+        }
+    }
+}
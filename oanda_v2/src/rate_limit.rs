@@ -0,0 +1,183 @@
+//! Keeps the client under OANDA's request limits, by making calls wait their turn rather than
+//! fail with 429 Too Many Requests.
+//! See: <https://developer.oanda.com/rest-live-v20/best-practices/>
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// How many requests may be sent per second, and how many may go at once after a quiet spell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub per_second: u32,
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// OANDA allows 120 REST requests per second
+    pub const REST: RateLimit = RateLimit::per_second(120);
+    /// OANDA allows 2 new connections per second
+    pub const STREAM: RateLimit = RateLimit::per_second(2);
+
+    /// `per_second` requests a second, that may all go at once
+    pub const fn per_second(per_second: u32) -> RateLimit {
+        RateLimit {
+            per_second,
+            burst: per_second,
+        }
+    }
+
+    pub fn with_burst(mut self, burst: u32) -> RateLimit {
+        self.burst = burst;
+        self
+    }
+}
+
+/// A token bucket. Each request takes a token, and they refill at `per_second`. When the bucket
+/// is empty, callers wait in the order they arrived
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+    /// The number of callers waiting for a token
+    queued: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Below zero when tokens have been promised to waiting callers
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> RateLimiter {
+        RateLimiter {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(limit.burst),
+                updated: Instant::now(),
+            }),
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Waits until a request may be sent
+    pub async fn acquire(&self) {
+        let wait = self.reserve();
+        if wait.is_zero() {
+            return;
+        }
+        /// Takes the caller off the queue, even if it gives up waiting
+        struct Dequeue<'a>(&'a AtomicUsize);
+        impl Drop for Dequeue<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+        self.queued.fetch_add(1, Ordering::SeqCst);
+        let _dequeue = Dequeue(&self.queued);
+        tokio::time::sleep(wait).await;
+    }
+
+    /// The number of callers waiting for their turn
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Takes a token, and says how long until it's ours
+    fn reserve(&self) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+        let now = Instant::now();
+        let per_second = f64::from(self.limit.per_second);
+        let refilled = now.duration_since(bucket.updated).as_secs_f64() * per_second;
+        bucket.tokens = (bucket.tokens + refilled).min(f64::from(self.limit.burst));
+        bucket.updated = now;
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 || per_second == 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / per_second)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RateLimit, RateLimiter};
+    use crate::{
+        client::Client,
+        endpoints::account::AccountsRequest,
+        host::Host,
+        transport::{CannedResponse, MemoryTransport},
+    };
+    use reqwest::Method;
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    #[tokio::test]
+    async fn test_burst_then_wait() {
+        let limiter = RateLimiter::new(RateLimit::per_second(50).with_burst(2));
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(10));
+        // The bucket is empty, so the next two wait 20ms each
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(38));
+    }
+
+    #[tokio::test]
+    async fn test_queued() {
+        let limiter = Arc::new(RateLimiter::new(RateLimit::per_second(20).with_burst(1)));
+        limiter.acquire().await;
+        let waiting: Vec<_> = (0..3)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(3, limiter.queued());
+        for waiting in waiting {
+            waiting.await.unwrap();
+        }
+        assert_eq!(0, limiter.queued());
+    }
+
+    #[tokio::test]
+    async fn test_requests_wait_for_the_rate_limit() {
+        let transport = MemoryTransport::new();
+        for _ in 0..3 {
+            transport.respond(
+                Method::GET,
+                "/v3/accounts",
+                CannedResponse::new(200, r#"{"accounts": []}"#),
+            );
+        }
+        let client = Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport)
+            .with_rate_limit(RateLimit::per_second(20).with_burst(1));
+        let start = Instant::now();
+        for _ in 0..3 {
+            client
+                .account()
+                .accounts(AccountsRequest::new())
+                .await
+                .unwrap();
+        }
+        // The first goes straight away, then one every 50ms
+        assert!(start.elapsed() >= Duration::from_millis(95));
+        assert_eq!(0, client.queue_depth());
+    }
+}
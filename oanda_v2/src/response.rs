@@ -0,0 +1,49 @@
+//! What a successful call gives back: the parsed body, and the headers documented for the call
+use crate::definitions::transaction_id::TransactionID;
+use reqwest::header::HeaderMap;
+
+/// The reply to a call. It derefs to the `body`, eg. `response.orders`
+#[derive(Debug, Clone)]
+pub struct Response<T, H> {
+    pub body: T,
+    /// The headers documented for the call, eg. the `RequestID` to quote to OANDA support
+    pub headers: H,
+    /// The ID of the most recent Transaction created for the Account, where the reply says.
+    /// Pass it to the account `changes` call to get what's happened since
+    pub last_transaction_id: Option<TransactionID>,
+}
+
+impl<T, H> Response<T, H> {
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+impl<T, H> std::ops::Deref for Response<T, H> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.body
+    }
+}
+
+/// The body of a call's successful reply. Each call has its own, generated in its `responses`
+/// module
+pub trait ResponseBody {
+    /// The `lastTransactionID` most replies about an Account have
+    fn last_transaction_id(&self) -> Option<TransactionID>;
+}
+
+/// The headers documented for a call's reply. Each call has its own, generated in its
+/// `responses` module
+pub trait ResponseHeaders: Sized {
+    fn from_headers(headers: &HeaderMap) -> Self;
+}
+
+/// The value of a header, if it was sent and is text. eg. a `RequestID`
+pub fn header<T: From<String>>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string().into())
+}
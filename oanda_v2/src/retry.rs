@@ -0,0 +1,302 @@
+//! When and how long to wait before sending a request again, after it failed in a way that
+//! might not happen next time. eg. a dropped connection, a 503 or a 429 Too Many Requests
+use crate::{transport::HttpResponse, Error};
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// How many times to send a request, and how long to wait in between. The client only sends a
+/// request again when it's safe to: it's a GET, or it has a `ClientRequestID` header so the
+/// server can tell it's a resend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The most times a request is sent, including the first. 1 never retries
+    pub max_attempts: u32,
+    /// The wait before the first retry. It doubles with each retry after that
+    pub initial_backoff: Duration,
+    /// The longest wait between retries, unless the server asks for longer with `Retry-After`
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sends each request once
+    pub const NEVER: RetryPolicy = RetryPolicy {
+        max_attempts: 1,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> RetryPolicy {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// How long to wait before sending the request again, after `attempt` (starting at 1) gave
+    /// `result`. `None` if it shouldn't be sent again
+    pub fn wait_before_retry(
+        &self,
+        attempt: u32,
+        result: &error_stack::Result<HttpResponse, Error>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match result {
+            Ok(response) if !is_retryable_status(response.status) => None,
+            Ok(response) => {
+                Some(retry_after(&response.headers).unwrap_or_else(|| self.backoff(attempt)))
+            }
+            // The request couldn't be sent, or the connection dropped
+            Err(_) => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// Exponential backoff, with half of it random, so clients that failed together don't all
+    /// retry together
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+        let half = backoff / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// True for the statuses that may not happen next time
+pub fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 429 | 500 | 502 | 503 | 504)
+}
+
+/// The wait the server asked for. Either in seconds, eg. `Retry-After: 2`, or until a date, eg.
+/// `Retry-After: Wed, 21 Oct 2015 07:28:00 GMT`
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    retry_after_at(headers, Utc::now())
+}
+
+/// [`retry_after`], as of `now`. A date that's already passed means we don't need to wait
+fn retry_after_at(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<f64>() {
+        return Duration::try_from_secs_f64(seconds).ok();
+    }
+    // An HTTP-date is the RFC 2822 format, always in GMT
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// A number from 0 to 1. Each `RandomState` is seeded differently, which is random enough for
+/// spreading out retries
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    random as f64 / u64::MAX as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::{retry_after_at, RetryPolicy};
+    use crate::{
+        client::Client,
+        endpoints::{
+            account::AccountsRequest,
+            order::{CancelRequest, PostOrdersBody, PostOrdersRequest},
+        },
+        host::Host,
+        transport::{CannedResponse, MemoryTransport},
+        Error,
+    };
+    use chrono::{TimeZone, Utc};
+    use error_stack::Report;
+    use reqwest::Method;
+    use std::time::Duration;
+
+    /// A client that retries straight away, so the tests don't wait
+    fn client(transport: &MemoryTransport) -> Client {
+        Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport.clone())
+            .with_retry_policy(RetryPolicy::default().with_backoff(Duration::ZERO, Duration::ZERO))
+    }
+
+    fn response(status: u16) -> error_stack::Result<crate::transport::HttpResponse, Error> {
+        Ok(CannedResponse::new(status, "").into_response())
+    }
+
+    #[test]
+    fn test_wait_before_retry() {
+        let policy =
+            RetryPolicy::default().with_backoff(Duration::from_secs(1), Duration::from_secs(3));
+        assert_eq!(None, policy.wait_before_retry(1, &response(200)));
+        assert_eq!(None, policy.wait_before_retry(1, &response(400)));
+        let wait = policy.wait_before_retry(1, &response(503)).unwrap();
+        assert!(wait >= Duration::from_millis(500) && wait <= Duration::from_secs(1));
+        let wait = policy
+            .wait_before_retry(2, &Err(Report::new(Error::Transport)))
+            .unwrap();
+        assert!(wait >= Duration::from_secs(1) && wait <= Duration::from_secs(2));
+        // Out of attempts
+        assert_eq!(None, policy.wait_before_retry(3, &response(503)));
+        assert_eq!(
+            None,
+            RetryPolicy::NEVER.wait_before_retry(1, &response(503))
+        );
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let policy = RetryPolicy::default();
+        let limited = Ok(CannedResponse::new(429, "")
+            .with_header("retry-after", "5")
+            .into_response());
+        assert_eq!(
+            Some(Duration::from_secs(5)),
+            policy.wait_before_retry(1, &limited)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_date() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        let headers = |value: &str| {
+            CannedResponse::new(503, "")
+                .with_header("retry-after", value)
+                .into_response()
+                .headers
+        };
+        assert_eq!(
+            Some(Duration::from_secs(90)),
+            retry_after_at(&headers("Wed, 21 Oct 2015 07:29:30 GMT"), now)
+        );
+        assert_eq!(
+            Some(Duration::ZERO),
+            retry_after_at(&headers("Wed, 21 Oct 2015 07:00:00 GMT"), now)
+        );
+        assert_eq!(None, retry_after_at(&headers("soon"), now));
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::default()
+            .with_max_attempts(100)
+            .with_backoff(Duration::from_secs(1), Duration::from_secs(4));
+        assert!(policy.backoff(50) <= Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn test_get_is_resent_after_5xx() {
+        let transport = MemoryTransport::new();
+        transport
+            .respond(Method::GET, "/v3/accounts", CannedResponse::new(503, ""))
+            .respond(
+                Method::GET,
+                "/v3/accounts",
+                CannedResponse::new(200, r#"{"accounts": []}"#),
+            );
+        let client = client(&transport);
+        client
+            .account()
+            .accounts(AccountsRequest::new())
+            .await
+            .unwrap();
+        assert_eq!(2, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_get_is_resent_after_connection_error() {
+        let transport = MemoryTransport::new();
+        let client = client(&transport);
+        // No canned responses, so every attempt fails to connect
+        let report = client
+            .account()
+            .accounts(AccountsRequest::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            report.downcast_ref::<Error>(),
+            Some(Error::Transport)
+        ));
+        assert_eq!(3, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_post_without_client_request_id_is_not_resent() {
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::POST,
+            "/v3/accounts/1/orders",
+            CannedResponse::new(503, ""),
+        );
+        let client = client(&transport);
+        let sent = PostOrdersRequest::new("1".into(), PostOrdersBody::default())
+            .send(&client)
+            .await;
+        assert!(sent.is_err());
+        assert_eq!(1, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_post_with_client_request_id_is_resent() {
+        let transport = MemoryTransport::new();
+        let path = "/v3/accounts/1/orders";
+        transport
+            .respond(Method::POST, path, CannedResponse::new(503, ""))
+            .respond(Method::POST, path, CannedResponse::new(201, "{}"));
+        let client = client(&transport);
+        PostOrdersRequest::new("1".into(), PostOrdersBody::default())
+            .client_request_id("order-1".into())
+            .send(&client)
+            .await
+            .unwrap();
+        let sent = transport.requests();
+        assert_eq!(2, sent.len());
+        assert_eq!("order-1", sent[1].headers["ClientRequestID"]);
+    }
+
+    #[tokio::test]
+    async fn test_put_with_client_request_id_is_resent() {
+        let transport = MemoryTransport::new();
+        let path = "/v3/accounts/1/orders/2/cancel";
+        transport.respond(Method::PUT, path, CannedResponse::new(502, ""));
+        let client = client(&transport);
+        // Without a ClientRequestID the server can't tell a resend from a new request
+        let cancelled = CancelRequest::new("1".into(), "2".into())
+            .send(&client)
+            .await;
+        assert!(cancelled.is_err());
+        assert_eq!(1, transport.requests().len());
+        transport
+            .respond(Method::PUT, path, CannedResponse::new(502, ""))
+            .respond(Method::PUT, path, CannedResponse::new(200, "{}"));
+        CancelRequest::new("1".into(), "2".into())
+            .client_request_id("cancel-2".into())
+            .send(&client)
+            .await
+            .unwrap();
+        let sent = transport.requests();
+        assert_eq!(3, sent.len());
+        assert_eq!("cancel-2", sent[2].headers["ClientRequestID"]);
+    }
+}
@@ -0,0 +1,148 @@
+//! Decodes the replies of the streaming calls: newline delimited json
+use crate::{client::CallError, error::JsonError, transport::Body, Error};
+use error_stack::{Report, ResultExt};
+use futures::{stream, Stream, StreamExt};
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// One of the objects a streaming call sends, eg. a price or a heartbeat. Each call's is
+/// generated in its `responses` module
+pub trait StreamItem: Sized {
+    /// Parses a `line` as the object its `type` field, `tag`, says it is
+    fn from_line(tag: Option<&str>, line: &[u8]) -> Result<Self, JsonError>;
+}
+
+/// The `type` field of a line, that says which object it is
+#[derive(Deserialize)]
+struct TypeTag<'a> {
+    #[serde(rename = "type", borrow)]
+    tag: Option<Cow<'a, str>>,
+}
+
+/// Parses a line straight into the object it is, so an error has the path to where it failed
+fn parse_line<T: StreamItem>(line: &[u8]) -> Result<T, JsonError> {
+    let TypeTag { tag } = crate::error::from_json(line)?;
+    T::from_line(tag.as_deref(), line)
+}
+
+/// The error for a line whose `type` isn't one of the `expected` ones the call sends
+pub fn unknown_type(tag: Option<&str>, expected: &'static [&'static str]) -> JsonError {
+    let error = match tag {
+        Some(tag) => serde::de::Error::unknown_variant(tag, expected),
+        None => serde::de::Error::missing_field("type"),
+    };
+    JsonError::new(serde_path_to_error::Track::new().path(), error)
+}
+
+/// Turns the body of a streaming http response from `url` into a stream of the json objects it
+/// holds, one per line.
+///
+/// A chunk from the server may hold several lines, or only part of one, so we buffer the bytes
+/// until we have a whole line before parsing it. Blank lines are skipped
+pub fn json_lines<T: StreamItem, E: CallError>(
+    url: String,
+    body: Body,
+) -> impl Stream<Item = error_stack::Result<T, E>> {
+    stream::try_unfold(
+        (body, Vec::new(), url),
+        |(mut body, mut buffer, url)| async move {
+            loop {
+                if let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+                    let line: Vec<u8> = buffer.drain(..=end).collect();
+                    if line.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    let item = parse_line(&line)
+                        .map_err(|err| {
+                            let error = Error::json(&url, &err);
+                            Report::from(err).change_context(error)
+                        })
+                        .change_context_lazy(E::decode)
+                        .attach_printable_lazy(|| {
+                            format!("Line: {}", String::from_utf8_lossy(&line))
+                        })?;
+                    return Ok(Some((item, (body, buffer, url))));
+                }
+                let chunk = body
+                    .next()
+                    .await
+                    .transpose()
+                    .attach_printable("Reading from stream")
+                    .change_context_lazy(E::request)?;
+                match chunk {
+                    Some(chunk) => buffer.extend_from_slice(&chunk),
+                    // The connection closed. Parse whatever was left without a newline on the end
+                    None if buffer.iter().any(|byte| !byte.is_ascii_whitespace()) => {
+                        buffer.push(b'\n')
+                    }
+                    None => return Ok(None),
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        client::Client,
+        endpoints::pricing::{responses::stream, StreamRequest},
+        host::Host,
+        retry::RetryPolicy,
+        transport::{CannedResponse, MemoryTransport},
+        Error,
+    };
+    use futures::StreamExt;
+    use reqwest::Method;
+
+    /// The stream of prices, from a server that sends `lines`
+    async fn prices(lines: &str) -> Vec<error_stack::Result<stream::Stream, stream::Error>> {
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::GET,
+            "/v3/accounts/1/pricing/stream",
+            CannedResponse::chunked(200, vec![lines.as_bytes().to_vec()]),
+        );
+        let client = Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport)
+            .with_retry_policy(RetryPolicy::NEVER);
+        let request = StreamRequest::new("1".into(), vec!["EUR_USD".into()]);
+        let prices = client.pricing().stream(request).await.unwrap();
+        prices.collect().await
+    }
+
+    #[tokio::test]
+    async fn test_lines_by_type() {
+        let prices = prices(concat!(
+            r#"{"type":"HEARTBEAT","time":"2023-11-14T22:13:20.000000000Z"}"#,
+            "\n",
+            r#"{"type":"PRICE","instrument":"EUR_USD","bids":[{"price":"1.1","liquidity":"10"}]}"#,
+            "\n",
+        ))
+        .await;
+        assert!(matches!(prices[0], Ok(stream::Stream::PricingHeartbeat(_))));
+        assert!(matches!(prices[1], Ok(stream::Stream::ClientPrice(_))));
+    }
+
+    /// The path and message of an `Error::Json`
+    fn json_error(result: &error_stack::Result<stream::Stream, stream::Error>) -> (String, String) {
+        match result.as_ref().unwrap_err().downcast_ref::<Error>() {
+            Some(Error::Json { path, message, .. }) => (path.clone(), message.clone()),
+            other => panic!("Expected an Error::Json: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_line_error_has_json_path() {
+        // The stream ends at the first line it can't parse
+        let malformed = prices(concat!(
+            r#"{"type":"PRICE","instrument":"EUR_USD","bids":[{"price":"1.1","liquidity":[]}]}"#,
+            "\n",
+        ))
+        .await;
+        assert_eq!("bids[0].liquidity", json_error(&malformed[0]).0);
+        let unknown = prices("{\"type\":\"SOMETHING_NEW\"}\n").await;
+        let (_, message) = json_error(&unknown[0]);
+        assert!(message.contains("SOMETHING_NEW"), "{message}");
+    }
+}
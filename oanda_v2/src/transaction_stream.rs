@@ -0,0 +1,490 @@
+//! A transaction stream that picks up where it left off. It remembers the ID of the last
+//! Transaction it gave you; when the connection drops, or goes quiet, it reconnects and gets the
+//! Transactions created in the meantime with `sinceid`. If the stream skips any IDs it fills them
+//! in with `idrange`, so you see every Transaction once, in order. eg:
+//!
+//! ```ignore
+//! let mut events = TransactionStream::new(client, account_id)
+//!     .since(last_seen)
+//!     .start();
+//! while let Some(event) = events.next().await {
+//!     if let TransactionEvent::Transaction(transaction) = event {
+//!         book.apply(transaction);
+//!     }
+//! }
+//! ```
+use crate::{
+    client::Client,
+    definitions::{
+        account_id::AccountID, transaction::Transaction,
+        transaction_heartbeat::TransactionHeartbeat, transaction_id::TransactionID,
+    },
+    endpoints::transaction::{responses::stream, IdrangeRequest, SinceidRequest, StreamRequest},
+    retry::RetryPolicy,
+    Error,
+};
+use error_stack::ResultExt;
+use futures::{channel::mpsc, SinkExt, Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{task::JoinHandle, time::Instant};
+
+/// What the transaction stream sends: the Transactions, and how the connection is doing
+#[derive(Debug)]
+pub enum TransactionEvent {
+    /// Connected, or reconnected, to the stream. Any Transactions missed while disconnected follow
+    Connected,
+    Transaction(Transaction),
+    Heartbeat(TransactionHeartbeat),
+    /// The Transactions have stopped coming. It'll reconnect after `retry_in`
+    Disconnected {
+        reason: Disconnect,
+        /// The number of times in a row it's failed to stay connected
+        attempt: u32,
+        retry_in: Duration,
+    },
+}
+
+/// Why the transaction stream stopped
+#[derive(Debug, thiserror::Error)]
+pub enum Disconnect {
+    /// No heartbeat arrived in time
+    #[error("No heartbeat for {0:?}")]
+    Stale(Duration),
+    /// The server ended the stream
+    #[error("The server closed the stream")]
+    Closed,
+    /// Connecting failed, or the connection broke
+    #[error("{0:?}")]
+    Failed(error_stack::Report<stream::Error>),
+    /// Getting the missed Transactions failed
+    #[error("{0:?}")]
+    Backfill(error_stack::Report<Error>),
+}
+
+/// Sets up a transaction stream that reconnects itself without losing any Transactions, see
+/// [`TransactionStream::start`]
+#[derive(Debug, Clone)]
+pub struct TransactionStream {
+    client: Client,
+    account_id: AccountID,
+    since: Option<TransactionID>,
+    heartbeat_timeout: Duration,
+    reconnect: RetryPolicy,
+}
+
+impl TransactionStream {
+    pub fn new(client: Client, account_id: AccountID) -> Self {
+        TransactionStream {
+            client,
+            account_id,
+            since: None,
+            heartbeat_timeout: Duration::from_secs(10),
+            reconnect: RetryPolicy::default()
+                .with_backoff(Duration::from_secs(1), Duration::from_secs(30)),
+        }
+    }
+
+    /// Starts after the Transaction with this ID, eg. the last one you saved. Without it, it
+    /// starts with the Transactions created after it connects
+    pub fn since(mut self, id: TransactionID) -> Self {
+        self.since = Some(id);
+        self
+    }
+
+    /// How long to go without a heartbeat before reconnecting. Defaults to 10 seconds
+    pub fn heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    /// How long to wait before reconnecting. It never gives up, so `max_attempts` isn't used.
+    /// Defaults to 1 second, doubling up to 30 seconds
+    pub fn reconnect(mut self, reconnect: RetryPolicy) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// Connects, and keeps reconnecting, in the background until the [`TransactionEvents`] are
+    /// dropped. Must be called from within a tokio runtime
+    pub fn start(self) -> TransactionEvents {
+        let (sender, receiver) = mpsc::channel(256);
+        let task = tokio::spawn(self.run(sender));
+        TransactionEvents { receiver, task }
+    }
+
+    async fn run(self, mut events: mpsc::Sender<TransactionEvent>) {
+        let mut delivered = Delivered {
+            last: self.since.as_deref().and_then(|id| id.parse().ok()),
+        };
+        let mut attempt = 0;
+        loop {
+            let reason = match self
+                .connect(&mut events, &mut attempt, &mut delivered)
+                .await
+            {
+                Ok(reason) => reason,
+                // Nobody is listening any more
+                Err(_) => return,
+            };
+            attempt += 1;
+            let retry_in = self.reconnect.backoff(attempt);
+            let disconnected = TransactionEvent::Disconnected {
+                reason,
+                attempt,
+                retry_in,
+            };
+            if events.send(disconnected).await.is_err() {
+                return;
+            }
+            tokio::time::sleep(retry_in).await;
+        }
+    }
+
+    /// Gets any Transactions missed since the last connection, then streams them until the
+    /// connection fails or goes quiet, and says why it stopped. Once something arrives down the
+    /// stream, the `attempt`s start again from 0, like in `PriceStream`
+    async fn connect(
+        &self,
+        events: &mut mpsc::Sender<TransactionEvent>,
+        attempt: &mut u32,
+        delivered: &mut Delivered,
+    ) -> Result<Disconnect, mpsc::SendError> {
+        // Connect before backfilling, so anything created during the backfill comes down the
+        // stream, rather than falling in between the two
+        let request = StreamRequest::new(self.account_id.clone());
+        let transactions = match self.client.transaction().stream(request).await {
+            Ok(transactions) => transactions,
+            Err(report) => return Ok(Disconnect::Failed(report)),
+        };
+        let mut transactions = std::pin::pin!(transactions);
+        events.send(TransactionEvent::Connected).await?;
+        if let Some(last) = delivered.last {
+            match self.since_id(last).await {
+                Ok(missed) => delivered.send_all(events, missed).await?,
+                Err(report) => return Ok(Disconnect::Backfill(report)),
+            }
+        }
+        let mut deadline = Instant::now() + self.heartbeat_timeout;
+        loop {
+            let item = match tokio::time::timeout_at(deadline, transactions.next()).await {
+                Err(_) => return Ok(Disconnect::Stale(self.heartbeat_timeout)),
+                Ok(None) => return Ok(Disconnect::Closed),
+                Ok(Some(Err(report))) => return Ok(Disconnect::Failed(report)),
+                Ok(Some(Ok(item))) => item,
+            };
+            *attempt = 0;
+            match item {
+                stream::Stream::Transaction(transaction) => {
+                    if let Some(id) = id_number(&transaction) {
+                        if let Err(report) = self
+                            .fill_gap(events, delivered, id.saturating_sub(1))
+                            .await?
+                        {
+                            return Ok(Disconnect::Backfill(report));
+                        }
+                    }
+                    delivered.send(events, transaction).await?;
+                }
+                stream::Stream::TransactionHeartbeat(heartbeat) => {
+                    deadline = Instant::now() + self.heartbeat_timeout;
+                    let latest = heartbeat
+                        .last_transaction_id
+                        .as_deref()
+                        .and_then(|id| id.parse().ok());
+                    match (delivered.last, latest) {
+                        // The heartbeat says where the Account is up to, so start from there
+                        (None, Some(latest)) => delivered.last = Some(latest),
+                        (Some(_), Some(latest)) => {
+                            if let Err(report) = self.fill_gap(events, delivered, latest).await? {
+                                return Ok(Disconnect::Backfill(report));
+                            }
+                        }
+                        _ => {}
+                    }
+                    events.send(TransactionEvent::Heartbeat(heartbeat)).await?;
+                }
+            }
+        }
+    }
+
+    /// Gets and sends the Transactions after the last one delivered, up to and including `to`.
+    /// The outer error is nobody listening; the inner one is the call failing
+    async fn fill_gap(
+        &self,
+        events: &mut mpsc::Sender<TransactionEvent>,
+        delivered: &mut Delivered,
+        to: u64,
+    ) -> Result<error_stack::Result<(), Error>, mpsc::SendError> {
+        let from = match delivered.last {
+            Some(last) if last < to => last + 1,
+            _ => return Ok(Ok(())),
+        };
+        match self.id_range(from, to).await {
+            Ok(missed) => delivered.send_all(events, missed).await.map(Ok),
+            Err(report) => Ok(Err(report)),
+        }
+    }
+
+    /// The Transactions after `after`
+    async fn since_id(&self, after: u64) -> error_stack::Result<Vec<Transaction>, Error> {
+        let request = SinceidRequest::new(
+            self.account_id.clone(),
+            TransactionID::from(after.to_string()),
+        );
+        let response = self
+            .client
+            .transaction()
+            .sinceid(request)
+            .await
+            .change_context_lazy(|| {
+                Error::new(format!("Unable to get the Transactions since {after}"))
+            })?;
+        Ok(response.into_body().transactions)
+    }
+
+    /// The Transactions from `from` to `to`, inclusive
+    async fn id_range(&self, from: u64, to: u64) -> error_stack::Result<Vec<Transaction>, Error> {
+        let request = IdrangeRequest::new(
+            self.account_id.clone(),
+            TransactionID::from(from.to_string()),
+            TransactionID::from(to.to_string()),
+        );
+        let response = self
+            .client
+            .transaction()
+            .idrange(request)
+            .await
+            .change_context_lazy(|| {
+                Error::new(format!(
+                    "Unable to get the Transactions from {from} to {to}"
+                ))
+            })?;
+        Ok(response.into_body().transactions)
+    }
+}
+
+/// Remembers the last Transaction sent, so each is only sent once, and in order
+#[derive(Debug)]
+struct Delivered {
+    last: Option<u64>,
+}
+
+impl Delivered {
+    /// Sends the `transaction`, unless it's been sent already
+    async fn send(
+        &mut self,
+        events: &mut mpsc::Sender<TransactionEvent>,
+        transaction: Transaction,
+    ) -> Result<(), mpsc::SendError> {
+        if let Some(id) = id_number(&transaction) {
+            if matches!(self.last, Some(last) if id <= last) {
+                return Ok(());
+            }
+            self.last = Some(id);
+        }
+        events
+            .send(TransactionEvent::Transaction(transaction))
+            .await
+    }
+
+    async fn send_all(
+        &mut self,
+        events: &mut mpsc::Sender<TransactionEvent>,
+        mut transactions: Vec<Transaction>,
+    ) -> Result<(), mpsc::SendError> {
+        transactions.sort_by_key(id_number);
+        for transaction in transactions {
+            self.send(events, transaction).await?;
+        }
+        Ok(())
+    }
+}
+
+/// The Transaction's ID as a number, so they can be put in order. `None` if it hasn't got one,
+/// or it isn't a number
+fn id_number(transaction: &Transaction) -> Option<u64> {
+    transaction.id()?.parse().ok()
+}
+
+/// The events from a running [`TransactionStream`]. Dropping it disconnects
+#[derive(Debug)]
+pub struct TransactionEvents {
+    receiver: mpsc::Receiver<TransactionEvent>,
+    task: JoinHandle<()>,
+}
+
+impl Stream for TransactionEvents {
+    type Item = TransactionEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for TransactionEvents {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{id_number, Disconnect, TransactionEvent, TransactionStream};
+    use crate::{
+        client::Client,
+        definitions::transaction::Transaction,
+        host::Host,
+        rate_limit::RateLimit,
+        retry::RetryPolicy,
+        transport::{CannedResponse, MemoryTransport},
+    };
+    use futures::StreamExt;
+    use reqwest::Method;
+    use std::time::Duration;
+
+    fn transaction(id: u64) -> String {
+        format!(r#"{{"id":"{id}","type":"CREATE","time":"2023-11-14T22:13:20.000000000Z"}}"#)
+    }
+
+    async fn next_id(events: &mut super::TransactionEvents) -> u64 {
+        match events.next().await {
+            Some(TransactionEvent::Transaction(transaction)) => id_number(&transaction).unwrap(),
+            other => panic!("Expected a Transaction, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resumes_without_gaps() {
+        let accounts = "/v3/accounts/001-001-1234567-001/transactions";
+        let transport = MemoryTransport::new();
+        transport
+            .respond(
+                Method::GET,
+                &format!("{accounts}/stream"),
+                CannedResponse::chunked(200, vec![format!("{}\n", transaction(5)).into_bytes()]),
+            )
+            // 6 and 7 were created while disconnected
+            .respond(
+                Method::GET,
+                &format!("{accounts}/stream"),
+                CannedResponse::chunked(
+                    200,
+                    vec![
+                        format!("{}\n", transaction(7)).into_bytes(),
+                        format!("{}\n", transaction(9)).into_bytes(),
+                    ],
+                ),
+            )
+            .respond(
+                Method::GET,
+                &format!("{accounts}/sinceid"),
+                CannedResponse::new(
+                    200,
+                    format!(
+                        r#"{{"transactions":[{},{}],"lastTransactionID":"7"}}"#,
+                        transaction(7),
+                        transaction(6)
+                    ),
+                ),
+            )
+            // The stream skipped 8
+            .respond(
+                Method::GET,
+                &format!("{accounts}/idrange"),
+                CannedResponse::new(
+                    200,
+                    format!(
+                        r#"{{"transactions":[{}],"lastTransactionID":"9"}}"#,
+                        transaction(8)
+                    ),
+                ),
+            );
+        let client = Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport.clone())
+            .with_stream_rate_limit(RateLimit::per_second(1000));
+        let account_id = serde_json::from_str(r#""001-001-1234567-001""#).unwrap();
+        let fast = RetryPolicy::default().with_backoff(Duration::ZERO, Duration::ZERO);
+        let mut events = TransactionStream::new(client, account_id)
+            .reconnect(fast)
+            .start();
+        assert!(matches!(
+            events.next().await,
+            Some(TransactionEvent::Connected)
+        ));
+        assert_eq!(5, next_id(&mut events).await);
+        assert!(matches!(
+            events.next().await,
+            Some(TransactionEvent::Disconnected {
+                reason: Disconnect::Closed,
+                ..
+            })
+        ));
+        assert!(matches!(
+            events.next().await,
+            Some(TransactionEvent::Connected)
+        ));
+        // In order, and 7 only once, though both the backfill and the stream had it
+        for id in 6..=9 {
+            assert_eq!(id, next_id(&mut events).await);
+        }
+        let sent = transport.requests();
+        assert!(sent[2].url.query().unwrap().contains("id=5"));
+        assert!(sent[3].url.query().unwrap().contains("from=8&to=8"));
+    }
+
+    #[tokio::test]
+    async fn test_backs_off_when_nothing_arrives() {
+        let path = "/v3/accounts/001-001-1234567-001/transactions/stream";
+        let transport = MemoryTransport::new();
+        // Accepts the connection, and closes it without sending anything
+        transport
+            .respond(Method::GET, path, CannedResponse::new(200, ""))
+            .respond(Method::GET, path, CannedResponse::new(200, ""));
+        let client = Client::new("token".to_string(), Host::Dev)
+            .with_transport(transport.clone())
+            .with_stream_rate_limit(RateLimit::per_second(1000));
+        let account_id = serde_json::from_str(r#""001-001-1234567-001""#).unwrap();
+        let fast = RetryPolicy::default().with_backoff(Duration::ZERO, Duration::ZERO);
+        let mut events = TransactionStream::new(client, account_id)
+            .reconnect(fast)
+            .start();
+        for expected in [1, 2] {
+            assert!(matches!(
+                events.next().await,
+                Some(TransactionEvent::Connected)
+            ));
+            let Some(TransactionEvent::Disconnected { attempt, .. }) = events.next().await else {
+                panic!("Expected to be disconnected")
+            };
+            assert_eq!(expected, attempt);
+        }
+    }
+
+    #[test]
+    fn test_id_number() {
+        let known: Transaction = serde_json::from_str(&transaction(5)).unwrap();
+        assert!(matches!(known, Transaction::Create(_)), "{known:?}");
+        assert_eq!(Some(5), id_number(&known));
+        // A type OANDA added after these docs were written still has its id read
+        let unknown: Transaction =
+            serde_json::from_str(r#"{"id":"12","type":"SOMETHING_NEW"}"#).unwrap();
+        assert!(matches!(unknown, Transaction::Unknown(_)));
+        assert_eq!(Some(12), id_number(&unknown));
+        let no_id: Transaction = serde_json::from_str(r#"{"type":"SOMETHING_NEW"}"#).unwrap();
+        assert_eq!(None, id_number(&no_id));
+    }
+
+    #[test]
+    fn test_malformed_known_type() {
+        // A type we know about that doesn't parse is an error, rather than Unknown
+        let err = crate::error::from_json::<Transaction>(
+            br#"{"type":"ORDER_FILL","id":"5","units":{"oops":1}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("units"), "{err}");
+    }
+}
@@ -0,0 +1,287 @@
+//! Sends the requests the client builds.
+//!
+//! [`ReqwestTransport`] sends them over the network, and is what [`crate::client::Client::new`]
+//! uses. [`MemoryTransport`] answers them with canned responses instead, so code that uses the
+//! client can be tested without reaching OANDA. eg:
+//!
+//! ```ignore
+//! let transport = MemoryTransport::new();
+//! transport.respond(
+//!     Method::GET,
+//!     "/v3/accounts",
+//!     CannedResponse::new(200, r#"{"accounts": []}"#),
+//! );
+//! let client = Client::new(token, Host::Dev).with_transport(transport.clone());
+//! ```
+use crate::Error;
+use error_stack::{Report, ResultExt};
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, Request,
+};
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+/// The body of a response, as it arrives. The streaming calls never finish sending it
+pub type Body = BoxStream<'static, error_stack::Result<Vec<u8>, Error>>;
+
+/// Sends a request and gives back the response, as soon as its status and headers arrive
+pub trait Transport: Debug + Send + Sync {
+    fn execute(&self, request: Request) -> BoxFuture<'_, error_stack::Result<HttpResponse, Error>>;
+}
+
+/// What a [`Transport`] gives back for a request
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: Body,
+}
+
+impl HttpResponse {
+    /// True for a 2xx status code
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Reads the whole body as text
+    pub async fn text(self) -> error_stack::Result<String, Error> {
+        let body: Vec<Vec<u8>> = self.body.try_collect().await?;
+        String::from_utf8(body.concat())
+            .map_err(Report::from)
+            .change_context(Error::Transport)
+            .attach_printable("The body isn't UTF-8")
+    }
+}
+
+/// Sends requests over the network with `reqwest`
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, error_stack::Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let url = request.url().to_owned();
+            let response = self
+                .client
+                .execute(request)
+                .await
+                .map_err(Report::from)
+                .change_context(Error::Transport)
+                .attach_printable_lazy(|| format!("URL: {url}"))?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = stream::try_unfold(response, |mut response| async move {
+                let chunk = response
+                    .chunk()
+                    .await
+                    .map_err(Report::from)
+                    .change_context(Error::Transport)
+                    .attach_printable("Reading the body")?;
+                Ok(chunk.map(|chunk| (chunk.to_vec(), response)))
+            });
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: body.boxed(),
+            })
+        })
+    }
+}
+
+/// A response for [`MemoryTransport`] to give back
+#[derive(Debug, Clone)]
+pub struct CannedResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    /// The body, in the chunks it's sent in
+    pub chunks: Vec<Vec<u8>>,
+}
+
+impl CannedResponse {
+    /// A response with the whole `body` in one chunk
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> CannedResponse {
+        CannedResponse::chunked(status, vec![body.into()])
+    }
+
+    /// A response with the body sent in `chunks`, like the streaming calls send it. A chunk may
+    /// hold several lines, or only part of one
+    pub fn chunked(status: u16, chunks: Vec<Vec<u8>>) -> CannedResponse {
+        CannedResponse {
+            status,
+            headers: HeaderMap::new(),
+            chunks,
+        }
+    }
+
+    /// Adds a header. `name` must be lower case, eg. "requestid"
+    pub fn with_header(mut self, name: &'static str, value: &str) -> CannedResponse {
+        if let Ok(value) = HeaderValue::from_str(value) {
+            self.headers.insert(HeaderName::from_static(name), value);
+        }
+        self
+    }
+
+    pub(crate) fn into_response(self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: stream::iter(self.chunks.into_iter().map(Ok)).boxed(),
+        }
+    }
+}
+
+/// A request [`MemoryTransport`] was given
+#[derive(Debug, Clone)]
+pub struct SentRequest {
+    pub method: Method,
+    pub url: reqwest::Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+/// Answers requests with the responses it's been given, without touching the network. Each
+/// response is given once, to the first request with the same method and URL path, in the order
+/// they were added. A request with no response left fails, as if the server couldn't be reached.
+///
+/// Clones share their responses, so a test can keep one to look at what was sent
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTransport {
+    state: Arc<Mutex<MemoryState>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    /// The method, URL path and response for each request still to come
+    responses: VecDeque<(Method, String, CannedResponse)>,
+    requests: Vec<SentRequest>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
+        MemoryTransport::default()
+    }
+
+    /// Gives `response` to the next `method` request to `path`, eg. "/v3/accounts"
+    pub fn respond(&self, method: Method, path: &str, response: CannedResponse) -> &Self {
+        self.lock()
+            .responses
+            .push_back((method, path.to_string(), response));
+        self
+    }
+
+    /// The requests sent so far, oldest first
+    pub fn requests(&self) -> Vec<SentRequest> {
+        self.lock().requests.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MemoryState> {
+        // A test that panicked while holding the lock leaves nothing half changed
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'_, error_stack::Result<HttpResponse, Error>> {
+        let mut state = self.lock();
+        state.requests.push(SentRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(<[u8]>::to_vec),
+        });
+        let path = request.url().path();
+        let found = state
+            .responses
+            .iter()
+            .position(|(method, expected, _)| method == request.method() && expected == path)
+            .and_then(|index| state.responses.remove(index));
+        let result = match found {
+            Some((_, _, response)) => Ok(response.into_response()),
+            None => Err(Report::new(Error::Transport).attach_printable(format!(
+                "No canned response for {} {}",
+                request.method(),
+                request.url()
+            ))),
+        };
+        Box::pin(async move { result })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CannedResponse, MemoryTransport, Transport};
+    use futures::TryStreamExt;
+    use reqwest::Method;
+
+    fn request(method: Method, url: &str) -> reqwest::Request {
+        reqwest::Request::new(method, url.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport() {
+        let transport = MemoryTransport::new();
+        transport
+            .respond(
+                Method::GET,
+                "/v3/accounts",
+                CannedResponse::new(200, "first").with_header("requestid", "1"),
+            )
+            .respond(
+                Method::GET,
+                "/v3/accounts",
+                CannedResponse::new(429, "second"),
+            );
+        let url = "https://api-fxpractice.oanda.com/v3/accounts?count=1";
+        let first = transport.execute(request(Method::GET, url)).await.unwrap();
+        assert!(first.is_success());
+        assert_eq!("1", first.headers["requestid"]);
+        assert_eq!("first", first.text().await.unwrap());
+        let second = transport.execute(request(Method::GET, url)).await.unwrap();
+        assert_eq!(429, second.status);
+        // Each response is only given once
+        assert!(transport.execute(request(Method::GET, url)).await.is_err());
+        assert!(transport.execute(request(Method::POST, url)).await.is_err());
+        let sent = transport.requests();
+        assert_eq!(4, sent.len());
+        assert_eq!(Some("count=1"), sent[0].url.query());
+        assert_eq!(Method::POST, sent[3].method);
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_chunked() {
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::GET,
+            "/v3/accounts/1/pricing/stream",
+            CannedResponse::chunked(200, vec![b"{\"a\":".to_vec(), b"1}\n".to_vec()]),
+        );
+        let response = transport
+            .execute(request(
+                Method::GET,
+                "https://stream-fxpractice.oanda.com/v3/accounts/1/pricing/stream",
+            ))
+            .await
+            .unwrap();
+        let chunks: Vec<Vec<u8>> = response.body.try_collect().await.unwrap();
+        assert_eq!(2, chunks.len());
+    }
+}
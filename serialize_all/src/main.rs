@@ -1,13 +1,31 @@
+use std::sync::Arc;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
 use tracing_subscriber::EnvFilter;
+use web_scraper::{DirectorySource, HttpSource, MirroringSource, PageSource};
+
+const USAGE: &str = "Usage: serialize_all [--mirror <dir> | --offline <dir>]
+
+  (no args)         Scrape the oanda website
+  --mirror <dir>    Scrape the oanda website, saving a copy of every page into <dir>
+  --offline <dir>   Scrape the pages previously mirrored into <dir>";
 
 #[tokio::main]
 async fn main() {
     tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env())
         .init();
-    let content = web_scraper::get_all_content().await.unwrap();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let source: Arc<dyn PageSource> = match args.as_slice() {
+        [] => Arc::new(HttpSource),
+        [flag, dir] if flag == "--mirror" => Arc::new(MirroringSource::new(HttpSource, dir)),
+        [flag, dir] if flag == "--offline" => Arc::new(DirectorySource::new(dir)),
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(1);
+        }
+    };
+    let content = web_scraper::get_all_content(source).await.unwrap();
     let yaml = serde_yaml::to_string(&content).unwrap();
     let mut file = File::create("content.yaml").await.unwrap();
     file.write_all(yaml.as_bytes()).await.unwrap();
@@ -1,6 +1,6 @@
 mod error;
-pub use crate::error::Error;
-use crate::error::{EasyError, Result};
+pub use crate::error::{EasyError, Error, Tracer};
+use crate::error::Result;
 use error_stack::ResultExt;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
@@ -14,14 +14,28 @@ pub fn stream_to_string(stream: &TokenStream) -> Result<String> {
         .annotate_lazy(|| format!("Converting code to string {stream:#?}"))
 }
 
-/// Make a comment pretty. Wraps to 60 chars width
+/// Make a comment pretty. Wraps to 60 chars width. The lines of a markdown list item are
+/// indented under it, and code blocks are left as they are
 ///
 /// ## Arguments
 ///
 /// * `input`: The comment text
 /// * `prefix`: The prefix, eg. `//` or `///`
 pub fn pretty_comment_basic(input: &str, prefix: &str) -> Result<Vec<TokenStream>> {
-    let lines = textwrap::wrap(input, 60);
+    let mut in_code = false;
+    let mut lines = Vec::new();
+    for line in input.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            lines.push(line.into());
+        } else if in_code {
+            lines.push(line.into());
+        } else {
+            let indent = " ".repeat(list_marker_width(line));
+            let options = textwrap::Options::new(60).subsequent_indent(&indent);
+            lines.extend(textwrap::wrap(line, options));
+        }
+    }
     lines
         .iter()
         .map(|line| {
@@ -34,6 +48,19 @@ pub fn pretty_comment_basic(input: &str, prefix: &str) -> Result<Vec<TokenStream
         .attach_printable_lazy(|| format!("Trying to prettyize: {input}"))
 }
 
+/// The width of the marker that starts a markdown list item, eg. 3 for "1. " or 2 for "* ". Zero
+/// if the line isn't one
+fn list_marker_width(line: &str) -> usize {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let rest = &line[digits..];
+    let marker = if digits > 0 { ". " } else { "* " };
+    if rest.starts_with(marker) || (digits == 0 && rest.starts_with("- ")) {
+        digits + 2
+    } else {
+        0
+    }
+}
+
 /// Takes a raw doc string and returns a pretty token_stream
 /// Example usage: `#(#doc_string)*`
 pub fn pretty_doc_string(input: &str) -> Result<Vec<TokenStream>> {
@@ -24,13 +24,13 @@ struct Header {
 /// # Arguments
 ///
 /// * `document` - A reference to an `Html` object containing the HTML
-/// content of the OANDA REST API definitions page.
+///   content of the OANDA REST API definitions page.
 ///
 /// # Returns
 ///
 /// * `Result<Vec<Definition>>` - A result containing a vector of
-/// `Definition` structs if successful, or an error if an issue occurred
-/// during the scraping process.
+///   `Definition` structs if successful, or an error if an issue occurred
+///   during the scraping process.
 ///
 /// # Errors
 ///
@@ -78,7 +78,7 @@ pub fn get_definitions(document: &Html) -> Result<Vec<Definition>> {
 /// # Returns
 ///
 /// * `Result<Header>` - A `Result` containing a `Header` struct with the `type_name` and
-/// `description` if successful, otherwise an `Error` if there are any issues with the CSS selectors
+///   `description` if successful, otherwise an `Error` if there are any issues with the CSS selectors
 ///
 /// # Errors
 ///
@@ -132,7 +132,7 @@ fn read_body(div: ElementRef) -> Result<Value> {
 /// # Arguments
 ///
 /// * `fragment: ElementRef` - An ElementRef object representing the
-/// HTML block to be parsed.
+///   HTML block to be parsed.
 ///
 /// # Result
 ///
@@ -327,25 +327,19 @@ pub(crate) fn read_struct(fragment: ElementRef) -> Result<Struct> {
 
 #[cfg(test)]
 mod test {
-    use crate::{bail, Error, Result};
+    use crate::{bail, DirectorySource, Error, PageSource, Result};
     use error_stack::{IntoReport, ResultExt};
     use model::definition_docs::{Row, Value};
     use scraper::Html;
     use pretty_assertions::assert_eq;
 
     #[tokio::test]
+    #[ignore = "reads the mirrored oanda pages: cargo run -p serialize_all -- --mirror web_scraper/test_content"]
     async fn read_definitions() -> Result<()> {
         let url = reqwest::Url::parse("https://developer.oanda.com/rest-live-v20/instrument-df/")
             .into_report()
             .change_context(Error::default())?;
-        let html = reqwest::get(url.clone())
-            .await
-            .into_report()
-            .change_context(Error::default())?
-            .text()
-            .await
-            .into_report()
-            .change_context(Error::default())?;
+        let html = DirectorySource::new("test_content").fetch(&url).await?;
         let document = Html::parse_document(&html);
         let definitions =
             super::get_definitions(&document).attach_printable_lazy(|| format!("URL: {url}"))?;
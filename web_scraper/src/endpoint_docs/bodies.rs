@@ -1,11 +1,13 @@
 //! Parses the documentation for Rest call bodies, including:
 //! * Header
 //! * Parameters
+//! * Request body
 //! * Responses
-use crate::{bail, Error, Result};
+use crate::{bail, definitions::read_struct, Error, Result};
 use error_stack::{IntoReport, ResultExt};
+use model::definition_docs::Schema;
 use model::endpoint_docs::Response;
-use model::endpoint_docs::RestCallParameter;
+use model::endpoint_docs::{CollectionFormat, RestCallParameter};
 use scraper::{ElementRef, Html, Selector};
 
 use responses::parse_responses_docs_group;
@@ -17,6 +19,7 @@ mod responses;
 /// Just the body part of the RestCall docs
 pub(crate) struct RestCallBody {
     pub parameters: Vec<RestCallParameter>,
+    pub request_body: Option<Schema>,
     pub responses: Vec<Response>,
     pub other_responses: Vec<u16>,
 }
@@ -32,6 +35,11 @@ pub(crate) fn get_all_rest_call_bodies(document: &Html) -> Result<Vec<RestCallBo
         .iter()
         .map(get_rest_call_parameters)
         .collect::<Result<Vec<Vec<RestCallParameter>>>>()?;
+    // Get the json body that POST/PUT calls send
+    let request_bodies = bodies
+        .iter()
+        .map(get_request_body)
+        .collect::<Result<Vec<Option<Schema>>>>()?;
     // Get all the responses for all the API calls in this endpoint
     let responses = bodies
         .iter()
@@ -43,16 +51,43 @@ pub(crate) fn get_all_rest_call_bodies(document: &Html) -> Result<Vec<RestCallBo
         .collect::<Result<Vec<Vec<u16>>>>()?;
     Ok(all_parameters
         .into_iter()
+        .zip(request_bodies)
         .zip(responses)
         .zip(all_other_responses)
-        .map(|((parameters, responses), other_responses)| RestCallBody {
-            parameters,
-            responses,
-            other_responses,
-        })
+        .map(
+            |(((parameters, request_body), responses), other_responses)| RestCallBody {
+                parameters,
+                request_body,
+                responses,
+                other_responses,
+            },
+        )
         .collect())
 }
 
+/// Given a .endpoint_body div, reads the "Request Body Schema" if there is one.
+///
+/// The response bodies use the same `pre.json_schema` blocks, but they're always
+/// inside a `.panel`, so the request body is the one that isn't
+pub(crate) fn get_request_body(body: &ElementRef) -> Result<Option<Schema>> {
+    let schema_selector = Selector::parse("pre.json_schema").map_err(Error::from)?;
+    let in_panel = |pre: &ElementRef| {
+        pre.ancestors()
+            .take_while(|node| node.id() != body.id())
+            .flat_map(ElementRef::wrap)
+            .any(|e| e.value().classes().any(|class| class == "panel"))
+    };
+    let mut request_bodies = body.select(&schema_selector).filter(|pre| !in_panel(pre));
+    let Some(pre) = request_bodies.next() else {
+        return Ok(None);
+    };
+    if request_bodies.next().is_some() {
+        bail!("Found more than one request body schema in: {}", body.html())
+    }
+    let schema = read_struct(pre).attach_printable("While reading the request body schema")?;
+    Ok(Some(Schema::Struct(schema)))
+}
+
 /// Given a .endpoint_body div, extracts the table headers and rows, which are the
 /// Rest API call parameters
 pub(crate) fn get_rest_call_parameters(body: &ElementRef) -> Result<Vec<RestCallParameter>> {
@@ -73,9 +108,15 @@ pub(crate) fn get_rest_call_parameters(body: &ElementRef) -> Result<Vec<RestCall
 
     let td_selector = Selector::parse("td").map_err(Error::from)?;
     let tr_selector = Selector::parse("tr").map_err(Error::from)?;
+    // The type column can hold links, eg. "List of (<a>InstrumentName</a>, csv)", so we need all of the text
     let get_tds = |tr: ElementRef| -> Vec<String> {
         tr.select(&td_selector)
-            .flat_map(|td| Some(td.text().next()?.trim().to_string()))
+            .map(|td| {
+                td.text()
+                    .flat_map(str::split_whitespace)
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
             .collect()
     };
     tbody
@@ -89,6 +130,8 @@ pub(crate) fn get_rest_call_parameters(body: &ElementRef) -> Result<Vec<RestCall
                     ))
                 })
                 .into_report()?;
+            let (type_name, collection) = parse_parameter_type(&type_name)?;
+            let (default, required) = parse_description_tags(&description);
             Ok(RestCallParameter {
                 name,
                 located_in: located_in
@@ -97,7 +140,248 @@ pub(crate) fn get_rest_call_parameters(body: &ElementRef) -> Result<Vec<RestCall
                     .change_context(Error::default())?,
                 type_name,
                 description,
+                default,
+                required,
+                collection,
             })
         })
         .collect()
 }
+
+/// Splits a parameter type like "List of (InstrumentName, csv)" into the item type and how the list is encoded.
+/// Plain types like "integer" come back as they are
+fn parse_parameter_type(type_name: &str) -> Result<(String, Option<CollectionFormat>)> {
+    let Some(list) = type_name.strip_prefix("List of") else {
+        return Ok((type_name.to_string(), None));
+    };
+    let list = list.trim().trim_start_matches('(').trim_end_matches(')');
+    let Some((item_type, format)) = list.split_once(',') else {
+        bail!("Expected a list type like \"List of (InstrumentName, csv)\" but got: {type_name}")
+    };
+    let format = format
+        .trim()
+        .parse()
+        .into_report()
+        .change_context(Error::default())
+        .attach_printable_lazy(|| format!("Unknown list format in parameter type: {type_name}"))?;
+    Ok((item_type.trim().to_string(), Some(format)))
+}
+
+/// Reads the tags at the end of a parameter description, like "[required]" or "[default=50, maximum=500]".
+///
+/// Returns the default value (if any) and whether the parameter is required
+fn parse_description_tags(description: &str) -> (Option<String>, bool) {
+    let tags = description
+        .trim_end()
+        .strip_suffix(']')
+        .and_then(|start| start.rsplit_once('['))
+        .map(|(_, tags)| tags)
+        .unwrap_or_default();
+    let mut default = None;
+    let mut required = false;
+    for tag in tags.split(',').map(str::trim) {
+        match tag.split_once('=') {
+            Some(("default", value)) => default = Some(value.trim().to_string()),
+            None if tag == "required" => required = true,
+            _ => (),
+        }
+    }
+    (default, required)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::{bail, Error, IntoReport, Result};
+    use error_stack::ResultExt;
+    use model::{definition_docs::Schema, endpoint_docs::CollectionFormat};
+    use pretty_assertions::assert_eq;
+    use scraper::Html;
+
+    #[test]
+    fn test_get_request_body() -> Result<()> {
+        let input = r##"
+<div class="endpoint_body">
+<b>Request Body Schema (application/json)</b>
+<p>
+</p><pre class="json_schema">{
+    # 
+    # Specification of the Order to create
+    # 
+    order : (<a href="../order-df/#OrderRequest">OrderRequest</a>)
+}
+</pre>
+<div class="panel-group" id="accordion" role="tablist" aria-multiselectable="true">
+<div class="panel panel-default">
+<div class="panel-body">
+<b>Response Body Schema (application/json)</b>
+<p>
+</p><pre class="json_schema">{
+    # 
+    # The ID of the most recent Transaction created for the Account
+    # 
+    lastTransactionID : (<a href="../transaction-df/#TransactionID">TransactionID</a>)
+}
+</pre>
+</div>
+</div>
+</div>
+</div>"##;
+        let html = Html::parse_fragment(input);
+        let Some(Schema::Struct(body)) = super::get_request_body(&html.root_element())? else {
+            bail!("Expected a request body struct")
+        };
+        assert_eq!(1, body.fields.len());
+        let order = &body.fields[0];
+        assert_eq!("order", order.name);
+        assert_eq!("OrderRequest", order.type_name);
+        assert_eq!("Specification of the Order to create", order.doc_string);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_request_body_with_defaults() -> Result<()> {
+        // The position close body, as the docs lay it out
+        let input = r##"
+<div class="endpoint_body">
+<b>Request Body Schema (application/json)</b>
+<p>
+</p><pre class="json_schema">{
+    # 
+    # Indication of how much of the long Position to closeout. Either the
+    # string "ALL", the string "NONE", or a DecimalNumber representing how many
+    # units of the long position to close using a PositionCloseout MarketOrder.
+    # The units specified must always be positive.
+    # 
+    longUnits : (string, default=ALL),
+
+    # 
+    # The client extensions to add to the MarketOrder used to close the long
+    # position.
+    # 
+    longClientExtensions : (<a href="../transaction-df/#ClientExtensions">ClientExtensions</a>)
+}
+</pre>
+</div>"##;
+        let html = Html::parse_fragment(input);
+        let Some(Schema::Struct(body)) = super::get_request_body(&html.root_element())? else {
+            bail!("Expected a request body struct")
+        };
+        assert_eq!(2, body.fields.len());
+        let units = &body.fields[0];
+        assert_eq!("longUnits", units.name);
+        assert_eq!("string", units.type_name);
+        assert_eq!(Some("ALL"), units.default.as_deref());
+        let extensions = &body.fields[1];
+        assert_eq!("longClientExtensions", extensions.name);
+        assert_eq!("ClientExtensions", extensions.type_name);
+        assert_eq!(None, extensions.default);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_request_body_none() -> Result<()> {
+        let input = r##"
+<div class="endpoint_body">
+<div class="panel-group" id="accordion" role="tablist" aria-multiselectable="true">
+<div class="panel panel-default">
+<div class="panel-body">
+<pre class="json_schema">{
+    # 
+    # The ID of the most recent Transaction created for the Account
+    # 
+    lastTransactionID : (<a href="../transaction-df/#TransactionID">TransactionID</a>)
+}
+</pre>
+</div>
+</div>
+</div>
+</div>"##;
+        let html = Html::parse_fragment(input);
+        assert_eq!(None, super::get_request_body(&html.root_element())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_parameter_type() -> Result<()> {
+        assert_eq!(
+            ("InstrumentName".to_string(), Some(CollectionFormat::Csv)),
+            super::parse_parameter_type("List of (InstrumentName, csv)")?
+        );
+        assert_eq!(
+            ("integer".to_string(), None),
+            super::parse_parameter_type("integer")?
+        );
+        assert!(super::parse_parameter_type("List of InstrumentName").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_description_tags() {
+        assert_eq!(
+            (Some("50".to_string()), false),
+            super::parse_description_tags(
+                "The maximum number of Orders to return [default=50, maximum=500]"
+            )
+        );
+        assert_eq!(
+            (None, true),
+            super::parse_description_tags("Account Identifier [required]")
+        );
+        assert_eq!(
+            (Some("Account Creation Time".to_string()), false),
+            super::parse_description_tags(
+                "The starting time (inclusive) of the time range for the Transactions being queried. [default=Account Creation Time]"
+            )
+        );
+        assert_eq!(
+            (None, false),
+            super::parse_description_tags("Client specified RequestID to be sent with request.")
+        );
+    }
+
+    #[test]
+    fn test_get_rest_call_parameters() -> Result<()> {
+        let input = r##"
+<div class="endpoint_body">
+<table class="parameter_table">
+<thead><tr><th>Name</th><th>Located In</th><th>Type</th><th>Description</th></tr></thead>
+<tbody>
+<tr>
+<td>accountID</td>
+<td>path</td>
+<td><a href="../account-df/#AccountID">AccountID</a></td>
+<td>Account Identifier [required]</td>
+</tr>
+<tr>
+<td>instruments</td>
+<td>query</td>
+<td>List of (<a href="../primitives-df/#InstrumentName">InstrumentName</a>, csv)</td>
+<td>List of Instruments to get pricing for. [required]</td>
+</tr>
+<tr>
+<td>count</td>
+<td>query</td>
+<td>integer</td>
+<td>The maximum number of Orders to return [default=50, maximum=500]</td>
+</tr>
+</tbody>
+</table>
+</div>"##;
+        let html = Html::parse_fragment(input);
+        let parameters = super::get_rest_call_parameters(&html.root_element())?;
+        assert_eq!(3, parameters.len());
+        let account_id = &parameters[0];
+        assert_eq!("AccountID", account_id.type_name);
+        assert!(account_id.required);
+        assert_eq!(None, account_id.collection);
+        let instruments = &parameters[1];
+        assert_eq!("InstrumentName", instruments.type_name);
+        assert_eq!(Some(CollectionFormat::Csv), instruments.collection);
+        assert!(instruments.required);
+        let count = &parameters[2];
+        assert_eq!("integer", count.type_name);
+        assert_eq!(Some("50"), count.default.as_deref());
+        assert!(!count.required);
+        Ok(())
+    }
+}
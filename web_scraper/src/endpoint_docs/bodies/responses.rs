@@ -50,36 +50,41 @@ fn parse_single_response_doc(panel: ElementRef) -> Result<Response> {
         .select(&title_selector)
         .next() else { bail!("Couldn't find response title in {}", panel.html())};
     let (code, description) = parse_response_title(a)?;
-    // Get the response http headers
-    let headers_selector = Selector::parse(".panel ul li").map_err(Error::from)?;
-    let headers = panel
-        .select(&headers_selector)
-        .map(parse_response_http_header)
-        .collect::<Result<Vec<ResponseHeader>>>()
-        .attach_printable_lazy(|| format!("In {code} {description}"))?;
+    // Both the response http headers and the objects a stream can send are in lists.
+    // The stream objects are links to their definitions, the headers are plain text
+    let li_selector = Selector::parse(".panel ul li").map_err(Error::from)?;
+    let link_selector = Selector::parse("a").map_err(Error::from)?;
+    let lis: Vec<ElementRef> = panel.select(&li_selector).collect();
     // Get the body
     let body_selector = Selector::parse(".panel-body pre.json_schema").map_err(Error::from)?;
-    // TODO: in https://developer.oanda.com/rest-live-v20/transaction-ep/ for /v3/accounts/{accountID}/transactions/stream
-    // It has a stream. We need to parse this differently
-    let schema = if let Some(body) = panel.select(&body_selector).next() {
-        Schema::Struct(read_struct(body)?)
+    let (schema, header_lis) = if let Some(body) = panel.select(&body_selector).next() {
+        (Schema::Struct(read_struct(body)?), lis)
     } else {
-        // If we can't find a straight schema, it must be a stream
-        let stream_selector =
-            Selector::parse(".panel-body > div > ul > li > a").map_err(Error::from)?;
-        let objects = panel
-            .select(&stream_selector)
-            .map(|a| {
-                a.text()
-                    .next()
+        // If we can't find a straight schema, it must be a stream, eg. for
+        // /v3/accounts/{accountID}/transactions/stream
+        let (links, plain): (Vec<ElementRef>, Vec<ElementRef>) = lis
+            .into_iter()
+            .partition(|li| li.select(&link_selector).next().is_some());
+        let objects = links
+            .iter()
+            .map(|li| {
+                li.text()
+                    .map(str::trim)
+                    .find(|text| !text.is_empty())
                     .map(str::to_string)
                     .ok_or(Error::default())
                     .into_report()
-                    .attach_printable_lazy(|| format!("Found empty object_name in {}", a.html()))
+                    .attach_printable_lazy(|| format!("Found empty object_name in {}", li.html()))
             })
             .collect::<Result<HashSet<String>>>()?;
-        Schema::Stream(Stream { objects })
+        (Schema::Stream(Stream { objects }), plain)
     };
+    // Get the response http headers
+    let headers = header_lis
+        .into_iter()
+        .map(parse_response_http_header)
+        .collect::<Result<Vec<ResponseHeader>>>()
+        .attach_printable_lazy(|| format!("In {code} {description}"))?;
     Ok(Response {
         code,
         description,
@@ -276,6 +281,51 @@ mod unit_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_stream_response_doc() -> Result<()> {
+        let input = r##"
+            <div class="panel panel-default">
+<div class="panel-heading" role="tab" id="heading_4_200">
+<span class="panel-title">
+<a class="" role="button" data-toggle="collapse" data-parent="#accordion" href="#collapse_4_200" aria-expanded="false" aria-controls="collapse_4_200">
+<b>HTTP 200</b> – Connecting to the Price Stream was successful.
+</a>
+</span>
+</div>
+<div id="collapse_4_200" class="panel-collapse collapse in" role="tabpanel" aria-labelledby="heading_4_200">
+<div class="panel-body">
+<b>Response Headers</b>
+<p>
+</p><ul>
+<li>RequestID - The unique identifier generated for the request</li>
+</ul>
+<b>Response Body Schema (application/octet-stream)</b>
+<div>
+The response body for the Pricing Stream uses chunked transfer encoding. Each chunk contains Price and/or PricingHeartbeat objects encoded as JSON. Each JSON object is serialized into a single line of text, and multiple objects found in the same chunk are separated by newlines.
+<ul>
+<li><a href="../pricing-df/#ClientPrice">ClientPrice</a></li>
+<li><a href="../pricing-df/#PricingHeartbeat">PricingHeartbeat</a></li>
+</ul>
+</div>
+</div> </div></div>
+        "##;
+        let html = Html::parse_fragment(input);
+        let response = super::parse_single_response_doc(html.root_element())?;
+        assert_eq!(200, response.code);
+        assert_eq!(
+            vec![ResponseHeader {
+                name: "RequestID".to_string(),
+                description: "The unique identifier generated for the request".to_string(),
+            }],
+            response.headers
+        );
+        let Schema::Stream(stream) = response.schema else { bail!("Expected a stream, but got: {:#?}", response.schema) };
+        let mut objects: Vec<&str> = stream.objects.iter().map(String::as_str).collect();
+        objects.sort();
+        assert_eq!(vec!["ClientPrice", "PricingHeartbeat"], objects);
+        Ok(())
+    }
+
     #[test]
     fn test_read_struct() -> Result<()> {
         let input = r#"{
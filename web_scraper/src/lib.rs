@@ -1,5 +1,6 @@
 pub mod definitions;
 pub mod endpoint_docs;
+pub mod page_source;
 
 use definitions::get_definitions;
 use error_stack::Report;
@@ -10,7 +11,9 @@ pub mod error;
 use endpoint_docs::endpoint_docs;
 pub use error::{Error, IntoReport, Result};
 use error_stack::ResultExt;
+pub use page_source::{DirectorySource, HttpSource, MirroringSource, PageSource};
 use scraper::{ElementRef, Html, Node, Selector};
+use std::sync::Arc;
 
 #[macro_export]
 macro_rules! bail {
@@ -26,15 +29,13 @@ macro_rules! report {
     };
 }
 
-pub async fn get_content(url: Url) -> Result<Content> {
-    let html = reqwest::get(url.clone())
-        .await
-        .into_report()
-        .change_context(Error::default())?
-        .text()
-        .await
-        .into_report()
-        .change_context(Error::default())?;
+/// The page we start scraping from. Its menu links to every other page
+pub const START_URL: &str = "https://developer.oanda.com/rest-live-v20/instrument-ep/";
+/// The page that documents the http errors
+pub const ERRORS_URL: &str = "https://developer.oanda.com/rest-live-v20/troubleshooting-errors/";
+
+pub async fn get_content(source: &dyn PageSource, url: Url) -> Result<Content> {
+    let html = source.fetch(&url).await?;
     let document = Html::parse_document(&html);
     let urls = endpoint_links(&document, &url)?;
     // Get all the endpoint documentation
@@ -68,8 +69,7 @@ pub async fn get_content(url: Url) -> Result<Content> {
 fn url_to_name(url: &Url, suffix: &str) -> Option<String> {
     Some(
         url.path_segments()?
-            .filter(|segment| !segment.is_empty())
-            .last()?
+            .rfind(|segment| !segment.is_empty())?
             .strip_suffix(suffix)?
             .to_string(),
     )
@@ -103,6 +103,9 @@ fn endpoint_links(document: &Html, base_url: &Url) -> Result<Vec<Url>> {
 
 /// Gets all content on the oanda site from all URLs
 ///
+/// `source` is where the html for each page comes from, eg. [`HttpSource`]
+/// for the live site, or [`DirectorySource`] for a mirror of it
+///
 /// # Panics
 ///
 /// Panics if .
@@ -110,31 +113,33 @@ fn endpoint_links(document: &Html, base_url: &Url) -> Result<Vec<Url>> {
 /// # Errors
 ///
 /// This function will return an error if .
-pub async fn get_all_content() -> Result<Everything> {
-    let instrument_url =
-        reqwest::Url::parse("https://developer.oanda.com/rest-live-v20/instrument-ep/").unwrap();
+pub async fn get_all_content(source: Arc<dyn PageSource>) -> Result<Everything> {
+    let instrument_url = reqwest::Url::parse(START_URL).unwrap();
 
-    let content = get_content(instrument_url.clone())
+    let instrument_content = get_content(source.as_ref(), instrument_url.clone())
         .await
         .attach_printable_lazy(|| format!("At url: {instrument_url}"))?;
 
     let mut tasks = Vec::new();
 
-    for url in content
+    for url in instrument_content
         .urls
-        .into_iter()
-        .filter(|url| url != &instrument_url)
+        .iter()
+        .filter(|url| *url != &instrument_url)
         // Forex labs just says "coming soon" at the time of writing -  Sat 27 May 2023 19:02:27 AEST
         .filter(|url| url.path() != "/rest-live-v20/forexlabs-ep/")
     {
+        let source = source.clone();
+        let url = url.clone();
         tasks.push(tokio::spawn(async move {
-            get_content(url.clone())
+            get_content(source.as_ref(), url.clone())
                 .await
                 .attach_printable_lazy(|| format!("At url: {url}"))
         }));
     }
 
-    let mut content = Vec::new();
+    // The start page is the instrument endpoint docs, so it's part of the content too
+    let mut content = vec![instrument_content];
 
     for result in futures::future::join_all(tasks).await {
         match result {
@@ -145,20 +150,14 @@ pub async fn get_all_content() -> Result<Everything> {
     }
 
     // Scrape the errors
-    let errors = scrape_error_page().await?;
+    let errors = scrape_error_page(source.as_ref()).await?;
 
     Ok(Everything { content, errors })
 }
 
-async fn scrape_error_page() -> Result<Vec<ErrorDefinition>> {
-    let html = reqwest::get("https://developer.oanda.com/rest-live-v20/troubleshooting-errors/")
-        .await
-        .into_report()
-        .change_context(Error::default())?
-        .text()
-        .await
-        .into_report()
-        .change_context(Error::default())?;
+async fn scrape_error_page(source: &dyn PageSource) -> Result<Vec<ErrorDefinition>> {
+    let url = Url::parse(ERRORS_URL).into_report()?;
+    let html = source.fetch(&url).await?;
     parse_error_page(&html)
 }
 
@@ -166,25 +165,18 @@ async fn scrape_error_page() -> Result<Vec<ErrorDefinition>> {
 #[display(style = "lowercase")]
 enum TextType {
     H5,
-    B,
-    Code,
+    Pre,
     #[default]
     PlainText,
 }
 
-/// Gets the child (not descendent) text nodes
+/// Gets all the text in an element, including that of its inline `<a>`, `<em>`, `<code>`, etc.
+/// children, with each run of whitespace turned into a single space
 fn get_text(e: &ElementRef) -> String {
-    e.children()
-        .flat_map(|n| {
-            if let scraper::node::Node::Text(text) = n.value() {
-                Some(String::from_utf8_lossy(text.text.as_bytes()))
-            } else {
-                None
-            }
-        })
-        .collect::<String>()
-        .trim()
-        .to_string()
+    e.text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 /// Returns true if this is a `<p><b>Reason:</b> something</p>`
@@ -207,9 +199,20 @@ fn is_reason(e: &ElementRef) -> bool {
     false
 }
 
+/// A paragraph that's an item of a numbered list, as markdown. The page writes them with no space
+/// after the number, eg. "1.The URL ..." becomes "1. The URL ..."
+fn list_item(text: &str) -> Option<String> {
+    let (number, item) = text.split_once('.')?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{number}. {}", item.trim_start()))
+}
+
 fn parse_error_page(html: &str) -> Result<Vec<ErrorDefinition>> {
     let document = Html::parse_document(html);
-    let selector = Selector::parse("#single-column *").map_err(Error::from)?;
+    // Only the blocks, eg. the <p>s, and not the inline elements in them, so each is read once
+    let selector = Selector::parse("#single-column > *").map_err(Error::from)?;
     let is_h4 = |e: &ElementRef| e.value().name() == "h4";
     let is_not_h4 = |e: &ElementRef| !is_h4(e);
     // First skip until the second <h4> tag
@@ -219,7 +222,6 @@ fn parse_error_page(html: &str) -> Result<Vec<ErrorDefinition>> {
         // Skip over the actual <h4>table of contents</h4>
         .skip(1)
         .skip_while(is_not_h4)
-        // .inspect(|e| println!("e: {} {}", e.value().name(), e.text().collect::<String>()))
         .collect();
     // Each ErrorDefinition starts with an h4 element, followed by a bunch of other elements
     nodes
@@ -242,28 +244,35 @@ fn parse_error_page(html: &str) -> Result<Vec<ErrorDefinition>> {
                 .to_string();
             let code: u16 = code.parse().into_report()?;
             // Gather the non-h4 nodes by creating a slice of the original nodes vec from i+1 to the end...
-            let documentation: String = nodes[(i + 1)..]
-                .iter()
-                // ..but stop if we hit another h4
-                .take_while(|e| is_not_h4(e))
-                .map(|e| {
-                    // Gather the html nodes' text and turn it into markdown
-                    let text = get_text(e);
-                    let tag_name = e.value().name();
-                    // Sometimes we hit a <p><b>Reason:</b> something</p> - because we're just walking all descendents and not recursing
-                    // That would put them out of order, but because it's the only case, we're just treating it differently
-                    if tag_name == "p" && !text.is_empty() && is_reason(e) {
-                        format!("\n**Reason:** {text}\n")
-                    } else {
-                        match tag_name.parse::<TextType>().unwrap_or_default() {
-                            TextType::H5 => format!("\n\n## {text}\n\n"),
-                            TextType::B => Default::default(),
-                            TextType::Code => format!("\n```\n{text}\n```\n"),
-                            TextType::PlainText => text,
+            let mut documentation = String::new();
+            for e in nodes[(i + 1)..].iter().take_while(|e| is_not_h4(e)) {
+                // ..but stop if we hit another h4, and turn each block into markdown
+                let text = get_text(e);
+                if text.is_empty() {
+                    continue;
+                }
+                let (separator, block) = match e.value().name().parse().unwrap_or_default() {
+                    TextType::H5 => ("\n\n", format!("## {text}")),
+                    // Kept as it is, rather than with its whitespace squashed
+                    TextType::Pre => (
+                        "\n\n",
+                        format!("```\n{}\n```", e.text().collect::<String>().trim()),
+                    ),
+                    // A list carries on from the paragraph before it, eg. a reason
+                    TextType::PlainText => match list_item(&text) {
+                        Some(item) => ("\n", item),
+                        None if is_reason(e) => {
+                            let reason = text.trim_start_matches("Reason:");
+                            ("\n\n", format!("**Reason:**{reason}"))
                         }
-                    }
-                })
-                .collect();
+                        None => ("\n\n", text),
+                    },
+                };
+                if !documentation.is_empty() {
+                    documentation.push_str(separator);
+                }
+                documentation.push_str(&block);
+            }
             Ok(ErrorDefinition {
                 code,
                 documentation,
@@ -276,7 +285,7 @@ fn parse_error_page(html: &str) -> Result<Vec<ErrorDefinition>> {
 mod tests {
     use crate::Documentation;
 
-    use super::get_content;
+    use super::{get_content, DirectorySource, PageSource};
     use error_stack::ResultExt;
     use model::{
         definition_docs::Schema,
@@ -284,12 +293,19 @@ mod tests {
         Endpoint, ErrorDefinition,
     };
     use reqwest::Url;
+    use std::sync::Arc;
+
+    /// The mirrored pages that the tests read, instead of hitting the oanda website.
+    /// Refresh them with `cargo run -p serialize_all -- --mirror web_scraper/test_content`
+    fn test_source() -> Arc<dyn PageSource> {
+        Arc::new(DirectorySource::new("test_content"))
+    }
 
     #[tokio::test]
+    #[ignore = "reads the mirrored oanda pages: cargo run -p serialize_all -- --mirror web_scraper/test_content"]
     async fn instrument_page() {
-        let url = reqwest::Url::parse("https://developer.oanda.com/rest-live-v20/instrument-ep/")
-            .unwrap();
-        let content = get_content(url.clone())
+        let url = reqwest::Url::parse(super::START_URL).unwrap();
+        let content = get_content(test_source().as_ref(), url.clone())
             .await
             .attach_printable_lazy(|| format!("At url: {url}"))
             .unwrap();
@@ -359,27 +375,52 @@ mod tests {
     }
 
     #[tokio::test]
+    #[ignore = "reads the mirrored oanda pages: cargo run -p serialize_all -- --mirror web_scraper/test_content"]
     async fn test_get_all_content() -> super::Result<()> {
         tracing_subscriber::fmt()
             .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
             .try_init()
             .ok();
-        let all_content = super::get_all_content().await?;
+        let all_content = super::get_all_content(test_source()).await?;
         println!("{all_content:#?}");
+        // The start page's own endpoint docs are kept
+        assert!(all_content.content.iter().any(|content| matches!(
+            &content.documentation,
+            Documentation::Endpoint(Endpoint { name, .. }) if name == "instrument"
+        )));
         Ok(())
     }
 
     #[test]
     fn test_parse_error_page() {
-        let input = include_str!("../test_content/errors.html");
+        let input = include_str!("../test_content/troubleshooting-errors.html");
         let out = super::parse_error_page(input).unwrap();
         for ErrorDefinition {
             code,
             documentation,
-        } in out
+        } in &out
         {
             println!("\n# {code}\n");
             println!("{documentation}");
         }
+        // The links and emphasis are kept in their place, and the list is numbered as markdown
+        let unauthorized = out.iter().find(|error| error.code == 401).unwrap();
+        assert!(
+            unauthorized.documentation.ends_with(concat!(
+                "**Reason:** The endpoint being accessed requires the client to be authenticated. ",
+                "The following information needs to be verified:\n",
+                "1. The URL provided to the curl command is correct. Click here to learn how to ",
+                "configure curl examples that can be copied without further modification.\n",
+                "2. The authentication token is valid and has been added as a Bearer token in the ",
+                "HTTP Authorization header.",
+            )),
+            "{}",
+            unauthorized.documentation
+        );
+        // Lines broken in the html are joined
+        let method_not_allowed = out.iter().find(|error| error.code == 405).unwrap();
+        assert!(method_not_allowed
+            .documentation
+            .contains("using an incorrect HTTP method"));
     }
 }
@@ -0,0 +1,147 @@
+//! Where we get the html for each page of the oanda docs from.
+//!
+//! Normally that's the oanda website, but we can also read pages from a
+//! directory of mirrored pages, so the whole pipeline can be run offline
+//! and give the same results every time.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+use url::Url;
+
+use crate::{bail, Error, IntoReport, Result};
+
+/// Something that can give us the html for a page of the oanda docs
+#[async_trait]
+pub trait PageSource: Send + Sync {
+    /// Returns the html of the page at `url`
+    async fn fetch(&self, url: &Url) -> Result<String>;
+}
+
+/// Gets the pages straight from the oanda website
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HttpSource;
+
+#[async_trait]
+impl PageSource for HttpSource {
+    async fn fetch(&self, url: &Url) -> Result<String> {
+        reqwest::get(url.clone())
+            .await
+            .into_report()
+            .attach_printable_lazy(|| format!("Downloading {url}"))?
+            .text()
+            .await
+            .into_report()
+            .attach_printable_lazy(|| format!("Reading the body of {url}"))
+    }
+}
+
+/// Reads pages from a directory of mirrored pages. See [`page_file_name`]
+/// for how a url maps to a file in the directory
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    dir: PathBuf,
+}
+
+impl DirectorySource {
+    pub fn new(dir: impl Into<PathBuf>) -> DirectorySource {
+        DirectorySource { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl PageSource for DirectorySource {
+    async fn fetch(&self, url: &Url) -> Result<String> {
+        let path = self.dir.join(page_file_name(url)?);
+        tokio::fs::read_to_string(&path)
+            .await
+            .into_report()
+            .attach_printable_lazy(|| format!("Reading mirrored page {path:?} for {url}"))
+            .attach_printable("You can mirror the site with `serialize_all --mirror <dir>`")
+    }
+}
+
+/// Fetches pages from another source, and saves a copy of each one into a
+/// directory, so that it can be read later by a [`DirectorySource`]
+#[derive(Debug, Clone)]
+pub struct MirroringSource<S> {
+    inner: S,
+    dir: PathBuf,
+}
+
+impl<S: PageSource> MirroringSource<S> {
+    pub fn new(inner: S, dir: impl Into<PathBuf>) -> MirroringSource<S> {
+        MirroringSource {
+            inner,
+            dir: dir.into(),
+        }
+    }
+
+    /// The directory the pages are being saved to
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+#[async_trait]
+impl<S: PageSource> PageSource for MirroringSource<S> {
+    async fn fetch(&self, url: &Url) -> Result<String> {
+        let html = self.inner.fetch(url).await?;
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .into_report()
+            .attach_printable_lazy(|| format!("Creating mirror directory {:?}", self.dir))?;
+        let path = self.dir.join(page_file_name(url)?);
+        tokio::fs::write(&path, &html)
+            .await
+            .into_report()
+            .attach_printable_lazy(|| format!("Saving {url} to {path:?}"))?;
+        Ok(html)
+    }
+}
+
+/// The name of the file a page is mirrored to. It's the last segment of the url path, plus `.html`
+///
+/// eg. `https://developer.oanda.com/rest-live-v20/instrument-ep/` is saved as `instrument-ep.html`
+pub fn page_file_name(url: &Url) -> Result<String> {
+    let Some(segment) = url
+        .path_segments()
+        .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+    else {
+        bail!("Unable to make a mirror file name out of url: {url}")
+    };
+    Ok(format!("{segment}.html"))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::{page_file_name, DirectorySource, PageSource};
+    use crate::Result;
+    use url::Url;
+
+    #[test]
+    fn test_page_file_name() -> Result<()> {
+        let url = Url::parse("https://developer.oanda.com/rest-live-v20/instrument-ep/").unwrap();
+        assert_eq!("instrument-ep.html", page_file_name(&url)?);
+        let url =
+            Url::parse("https://developer.oanda.com/rest-live-v20/troubleshooting-errors/")
+                .unwrap();
+        assert_eq!("troubleshooting-errors.html", page_file_name(&url)?);
+        let url = Url::parse("https://developer.oanda.com/").unwrap();
+        assert!(page_file_name(&url).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_directory_source() -> Result<()> {
+        let source = DirectorySource::new("test_content");
+        let url = Url::parse(crate::ERRORS_URL).unwrap();
+        let html = source.fetch(&url).await?;
+        assert!(html.contains("single-column"));
+        // Pages we haven't mirrored are an error
+        let url = Url::parse("https://developer.oanda.com/rest-live-v20/not-a-page/").unwrap();
+        assert!(source.fetch(&url).await.is_err());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+//! Generates `client.rs`: the `Client` that holds the http connection and hands out the endpoints
 use crate::{Error, Result};
 use change_case::pascal_case;
 use error_stack::ResultExt;
@@ -5,16 +6,25 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use utils::pretty_doc_string;
 
-pub fn gen_client(mods: &[&str]) -> Result<TokenStream> {
-    let mods: Vec<TokenStream> = mods
+/// Generates the `Client` struct, with an accessor for each endpoint. eg. `client.order()`
+///
+/// `endpoints` are the endpoint module names, eg. `["account", "order"]`
+/// `datetime_format_use` is the uses clause for the `AcceptDatetimeFormat` definition
+pub fn gen_client(endpoints: &[&str], datetime_format_use: TokenStream) -> Result<TokenStream> {
+    let uses: Vec<TokenStream> = endpoints
         .iter()
         .map(|name| {
-            let m = Ident::new(name, proc_macro2::Span::call_site());
-            let s = Ident::new(&pascal_case(name), proc_macro2::Span::call_site());
+            let m = Ident::new(name, Span::call_site());
+            let s = Ident::new(&pascal_case(name), Span::call_site());
             quote!(use crate::endpoints::#m::#s;)
         })
         .collect();
 
+    let accessors = endpoints
+        .iter()
+        .map(|name| gen_accessor(name))
+        .collect::<Result<Vec<TokenStream>>>()?;
+
     let builders = ["get", "put", "patch", "post", "delete"].into_iter().map(|http_method| {
         let ident = Ident::new(http_method, Span::call_site());
         let doc_string = pretty_doc_string(&format!("Given a URL path, creates a {http_method} request builder with the correct host and authentication token")).
@@ -27,22 +37,57 @@ pub fn gen_client(mods: &[&str]) -> Result<TokenStream> {
                     .#ident(url)
                     .header(AUTHORIZATION, format!("Bearer {}", &self.token))
                     .header(ACCEPT, "application/json")
+                    .header("Accept-Datetime-Format", self.datetime_format.to_string())
             }
         })
     }).collect::<Result<Vec<TokenStream>>>()?;
 
+    // The client's tests are written by hand, in `client/test.rs`
     Ok(quote!(
-        use std::borrow::ToOwned;
-        use error_stack::{report, ResultExt, Report};
+        use crate::{
+            error::HttpErrorKind,
+            host::Host,
+            rate_limit::{RateLimit, RateLimiter},
+            response::{Response, ResponseBody, ResponseHeaders},
+            retry::RetryPolicy,
+            stream::{json_lines, StreamItem},
+            transport::{HttpResponse, ReqwestTransport, Transport},
+            Error,
+        };
+        use error_stack::{Report, ResultExt};
+        use futures::Stream;
         use reqwest::RequestBuilder;
         use serde::de::DeserializeOwned;
-        #(#mods)*
+        use std::sync::Arc;
+        #datetime_format_use
+        #(#uses)*
+
+        /// The error type of a single call. Each call has its own, generated in its `responses` module
+        pub trait CallError: error_stack::Context + Sized {
+            /// Parses the body of a reply that didn't have a 2xx status code
+            fn from_response(status: u16, body: &str) -> std::result::Result<Self, crate::error::JsonError>;
+            /// For when the request couldn't be sent, or its reply couldn't be read
+            fn request() -> Self;
+            /// For when the reply was read, but isn't what the docs say the call sends
+            fn decode() -> Self;
+        }
 
         #[derive(Debug, Clone)]
         pub struct Client {
             token: String,
             pub host: Host,
+            /// The format the server sends and expects dates and times in. Defaults to RFC3339
+            pub datetime_format: AcceptDatetimeFormat,
+            /// Makes the requests, see the builders below
             rest_client: reqwest::Client,
+            /// Sends the requests
+            transport: Arc<dyn Transport>,
+            /// Spaces out the REST calls. Shared by clones of the client
+            rest_limiter: Arc<RateLimiter>,
+            /// Spaces out connecting to the streaming calls. Shared by clones of the client
+            stream_limiter: Arc<RateLimiter>,
+            /// When to send a failed request again
+            pub retry_policy: RetryPolicy,
         }
 
         impl Client {
@@ -61,130 +106,285 @@ pub fn gen_client(mods: &[&str]) -> Result<TokenStream> {
                 Client {
                     token,
                     host,
+                    datetime_format: AcceptDatetimeFormat::Rfc3339,
+                    transport: Arc::new(ReqwestTransport::new(rest_client.clone())),
                     rest_client,
+                    rest_limiter: Arc::new(RateLimiter::new(RateLimit::REST)),
+                    stream_limiter: Arc::new(RateLimiter::new(RateLimit::STREAM)),
+                    retry_policy: RetryPolicy::default(),
                 }
             }
 
+            /// Sets when to send a request again, after a dropped connection or a 429 or 5xx
+            /// reply. GETs are sent again, as are other calls with a `ClientRequestID` header,
+            /// so the server can tell it's a resend rather than, eg. a second order
+            pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Client {
+                self.retry_policy = retry_policy;
+                self
+            }
+
+            /// Sets how fast the REST calls may be sent. Calls over the limit wait their turn.
+            /// Defaults to [`RateLimit::REST`]
+            pub fn with_rate_limit(mut self, limit: RateLimit) -> Client {
+                self.rest_limiter = Arc::new(RateLimiter::new(limit));
+                self
+            }
+
+            /// Sets how fast the streaming calls may connect. Defaults to [`RateLimit::STREAM`]
+            pub fn with_stream_rate_limit(mut self, limit: RateLimit) -> Client {
+                self.stream_limiter = Arc::new(RateLimiter::new(limit));
+                self
+            }
+
+            /// The number of calls, by this client and its clones, waiting for their turn to be
+            /// sent
+            pub fn queue_depth(&self) -> usize {
+                self.rest_limiter.queued() + self.stream_limiter.queued()
+            }
+
+            /// Sends the requests with `transport`, rather than over the network. eg. a
+            /// `crate::transport::MemoryTransport`, to test against canned responses
+            pub fn with_transport(mut self, transport: impl Transport + 'static) -> Client {
+                self.transport = Arc::new(transport);
+                self
+            }
+
+            /// Sets the format the server sends and expects dates and times in
+            pub fn with_datetime_format(mut self, datetime_format: AcceptDatetimeFormat) -> Client {
+                self.datetime_format = datetime_format;
+                self
+            }
+
             /// Given a URL path, inserts the part before it
-            pub fn url(&self, path: &str) -> String {
+            pub fn url(&self, path: impl std::fmt::Display) -> String {
                 self.host.rest_url(path)
             }
 
+            /// Given a URL path for one of the streaming calls, inserts the part before it
+            pub fn stream_url(&self, path: impl std::fmt::Display) -> String {
+                self.host.stream_url(path)
+            }
+
             #(#builders)*
 
-            /// Makes an authenticated get request to a path in the rest api
-            pub async fn send<T: DeserializeOwned>(
+            /// Sends a request made by one of the builders above.
+            ///
+            /// A 2xx reply is parsed into `T`, with the call's documented headers `H`. Any other
+            /// reply is parsed into the call's own error type `E`, so callers can match on the
+            /// documented failures of each call
+            pub async fn send<T: DeserializeOwned + ResponseBody, H: ResponseHeaders, E: CallError>(
                 &self,
                 request: RequestBuilder,
-            ) -> error_stack::Result<T, Error> {
-                let request = request.build().map_err(Error::from).map_err(Report::from)?;
-                let url = request.url().to_owned();
-
-                let response = self
-                    .rest_client
-                    .execute(request)
+            ) -> error_stack::Result<Response<T, H>, E> {
+                let (url, response) = self
+                    .execute(request, &self.rest_limiter)
                     .await
-                    .map_err(Error::from)
-                    .map_err(Report::from)
+                    .change_context_lazy(E::request)?;
+                let status = response.status;
+                let headers = H::from_headers(&response.headers);
+                let body = response
+                    .text()
+                    .await
+                    .attach_printable("Retrieving HTTP body")
+                    .change_context_lazy(E::request)
+                    .attach_printable_lazy(|| format!("HTTP status code: {status}"))
                     .attach_printable_lazy(|| format!("URL: {url}"))?;
+                if (200..300).contains(&status) {
+                    crate::error::from_json(body.as_bytes())
+                        .map(|parsed: T| Response {
+                            last_transaction_id: parsed.last_transaction_id(),
+                            body: parsed,
+                            headers,
+                        })
+                        .map_err(|err| {
+                            let error = Error::json(&url, &err);
+                            Report::from(err).change_context(error)
+                        })
+                        .change_context_lazy(E::decode)
+                } else {
+                    Err(Self::error_reply(&url, status, &body))
+                }
+                .attach_printable_lazy(|| format!("Body: {body}"))
+                .attach_printable_lazy(|| format!("HTTP status code: {status}"))
+                .attach_printable_lazy(|| format!("URL: {url}"))
+            }
 
-                let status = response.status();
-
-                // Try and get the body, regardless if there was a bad status code
-                let body: Result<String> = response
+            /// Sends a request to one of the streaming calls.
+            ///
+            /// Once connected, the server sends one json object per line, for as long as the
+            /// connection lasts. Each is parsed into a `T`. If the connection fails, the reply is
+            /// parsed into the call's own error type `E`, like in [`Client::send`]
+            pub async fn stream<T: StreamItem, E: CallError>(
+                &self,
+                request: RequestBuilder,
+            ) -> error_stack::Result<impl Stream<Item = error_stack::Result<T, E>>, E> {
+                let (url, response) = self
+                    .execute(request, &self.stream_limiter)
+                    .await
+                    .change_context_lazy(E::request)?;
+                if response.is_success() {
+                    return Ok(json_lines(url, response.body));
+                }
+                let status = response.status;
+                let body = response
                     .text()
                     .await
-                    .map_err(Error::from)
-                    .map_err(Report::from)
+                    .attach_printable("Retrieving HTTP body")
+                    .change_context_lazy(E::request)
+                    .attach_printable_lazy(|| format!("HTTP status code: {status}"))
+                    .attach_printable_lazy(|| format!("URL: {url}"))?;
+                Err(Self::error_reply(&url, status, &body))
+                    .attach_printable_lazy(|| format!("Body: {body}"))
+                    .attach_printable_lazy(|| format!("HTTP status code: {status}"))
                     .attach_printable_lazy(|| format!("URL: {url}"))
-                    .attach_printable_lazy(|| format!("HTTP status code: {status}"));
-
-                match response.error_for_status_ref() {
-                    Ok(response) => {
-                        let body = body?;
-                        // Try to parse the json separetely from reqwest
-                        serde_json::from_str(&body)
-                            .map_err(|err| Error::JsonParse {
-                                err,
-                                input: body.to_owned(),
-                            })
-                            .map_err(Report::from)
-                            .attach_printable_lazy(|| format!("HTTP status code: {status}"))
-                            .attach_printable_lazy(|| format!("url: {url}"))
+            }
+
+            /// Builds the request and hands it to the transport, once `limiter` says it's its
+            /// turn. Sends it again if the `retry_policy` says to, and it's safe to. Gives back
+            /// the URL it was sent to, for the error reports, with the response
+            async fn execute(
+                &self,
+                request: RequestBuilder,
+                limiter: &RateLimiter,
+            ) -> error_stack::Result<(String, HttpResponse), Error> {
+                let request = request
+                    .build()
+                    .map_err(Report::from)
+                    .change_context(Error::Transport)
+                    .attach_printable("Building request")?;
+                let url = request.url().to_string();
+                // Sending anything but a GET twice could, eg. place two orders, unless the
+                // server can tell it's a resend
+                let can_resend = request.method() == reqwest::Method::GET
+                    || request.headers().contains_key("ClientRequestID");
+                let mut request = request;
+                let mut attempt = 1;
+                loop {
+                    let resend = if can_resend { request.try_clone() } else { None };
+                    limiter.acquire().await;
+                    let result = self.transport.execute(request).await;
+                    match (resend, self.retry_policy.wait_before_retry(attempt, &result)) {
+                        (Some(resend), Some(wait)) => {
+                            tokio::time::sleep(wait).await;
+                            request = resend;
+                            attempt += 1;
+                        }
+                        _ => {
+                            return result
+                                .attach_printable_lazy(|| format!("URL: {url}"))
+                                .attach_printable(format!("Attempts: {attempt}"))
+                                .map(|response| (url, response));
+                        }
                     }
+                }
+            }
+
+            /// The report for a reply from `url` with a status code other than 2xx. The call's own
+            /// error type `E` is caused by our [`Error`], which says what went wrong the same way
+            /// for every call. Where OANDA documents the status, its [`HttpErrorKind`] is
+            /// attached, so the report says what usually causes it.
+            ///
+            /// If the body isn't what the docs say the call replies with, it's `E::decode`,
+            /// caused by an [`Error::Json`]
+            fn error_reply<E: CallError>(url: &str, status: u16, body: &str) -> Report<E> {
+                let mut report = match E::from_response(status, body) {
+                    Ok(error) => Report::new(Error::from_response(status, body)).change_context(error),
                     Err(err) => {
-                        let err = Err(Error::from(err))
-                            .map_err(Report::from)
-                            .attach_printable_lazy(|| format!("HTTP status code: {status}"))
-                            .attach_printable_lazy(|| format!("url: {url}"));
-                        Err(match body {
-                            Ok(body) => {
-                                err.attach_printable(format!("Body: {body}"));
-                                err
-                            }
-                            Err(body_err) => {
-                                err.change_context(body_err);
-                                err
-                            }
-                        })
+                        let error = Error::json(url, &err);
+                        Report::from(err)
+                            .change_context(error)
+                            .change_context(E::decode())
                     }
+                };
+                if let Some(kind) = HttpErrorKind::from_status(status) {
+                    report = report
+                        .attach(kind)
+                        .attach_printable(format!("{kind}: {}", kind.remedy()));
                 }
+                report
             }
 
-            // /// Rest API for anything account related
-            // pub fn accounts(&self) -> Accounts {
-            //     Accounts { client: self }
-            // }
-
-            // /// Rest API for anything instrument related
-            // pub fn instrument(&self, instrument: impl ToString) -> Instrument {
-            //     Instrument {
-            //         client: self,
-            //         instrument: instrument.to_string(),
-            //     }
-            // }
-
-            // /// Rest API for anything trade related including closing an existing Trade
-            // pub fn trade(&self, account_id: impl ToString) -> Trade {
-            //     Trade::new(self, account_id.to_string())
-            // }
-
-            // // Rest API for anything order related including openning a new position
-            // pub fn order(&self, account_id: impl ToString) -> Order {
-            //     Order::new(self, account_id.to_string())
-            // }
+            #(#accessors)*
         }
 
-
         #[cfg(test)]
-        mod test_utils {
-            use error_stack::{IntoReport, Result, ResultExt};
-            use lazy_static::lazy_static;
-            use std::sync::Mutex;
-
+        mod test;
+    ))
+}
 
-            lazy_static! {
-                static ref ACCOUNT_ID: Mutex<Option<String>> = Mutex::new(None);
-            }
+/// Generates the method that hands out an endpoint, eg:
+///
+/// ```ignore
+/// /// Rest API calls for the order endpoint
+/// pub fn order(&self) -> Order<'_> {
+///     Order { client: self }
+/// }
+/// ```
+fn gen_accessor(endpoint_name: &str) -> Result<TokenStream> {
+    let method = Ident::new(endpoint_name, Span::call_site());
+    let struct_name = Ident::new(&pascal_case(endpoint_name), Span::call_site());
+    let doc_string = pretty_doc_string(&format!("Rest API calls for the {endpoint_name} endpoint"))
+        .change_context_lazy(|| {
+            Error::new(format!("Creating doc string for {endpoint_name} accessor"))
+        })?;
+    Ok(quote! {
+        #(#doc_string)*
+        pub fn #method(&self) -> #struct_name<'_> {
+            #struct_name { client: self }
+        }
+    })
+}
 
+#[cfg(test)]
+mod unit_tests {
+    use crate::{Error, Result};
+    use error_stack::ResultExt;
+    use pretty_assertions::assert_eq;
+    use utils::stream_to_string;
 
-            pub async fn get_account_id(client: &Client) -> Result<String, Error> {
-                let mut account_id = ACCOUNT_ID.lock().unwrap();
-                if let Some(account_id) = account_id.as_ref() {
-                    Ok(account_id.clone())
-                } else {
-                    let accounts = client.accounts().list().await?;
-                    let out = accounts
-                        .into_iter()
-                        .next()
-                        .ok_or_else(|| Error::Other)
-                        .map_err(Report::from)
-                        .attach_printable_lazy(|| "No oanda accounts found")?
-                        .id;
-                    *account_id = Some(out.clone());
-                    Ok(out)
+    #[test]
+    fn test_gen_accessor() -> Result<()> {
+        let tokens = super::gen_accessor("order")?;
+        let code = stream_to_string(&quote::quote! { impl Client { #tokens } })
+            .change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc::indoc! {r#"
+                impl Client {
+                    /// Rest API calls for the order endpoint
+                    pub fn order(&self) -> Order<'_> {
+                        Order { client: self }
+                    }
                 }
-            }
-        }
-    ))
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_client() -> Result<()> {
+        let datetime_format_use = quote::quote! {
+            use crate::definitions::accept_datetime_format::AcceptDatetimeFormat;
+        };
+        let code = super::gen_client(&["order", "instrument"], datetime_format_use)?;
+        // What the client does is tested against canned responses in the generated crate, so
+        // this only checks each endpoint gets its accessor
+        let file: syn::File = syn::parse2(code).change_context_lazy(Error::default)?;
+        let methods: Vec<String> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item_impl) => Some(&item_impl.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert!(methods.contains(&"order".to_string()), "{methods:?}");
+        assert!(methods.contains(&"instrument".to_string()), "{methods:?}");
+        Ok(())
+    }
 }
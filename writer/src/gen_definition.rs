@@ -1,31 +1,47 @@
 //! Generates error.rs for oanda_v2
 use self::{
-    gen_row::{gen_rows, gen_single_row},
-    gen_struct::gen_typed_string,
+    gen_row::{gen_rows, gen_single_row, gen_typed_string},
+    gen_tagged_union::{gen_tagged_union, tagged_union_variants},
 };
-use crate::error::Result;
+use crate::{error::Result, Error};
+use error_stack::ResultExt;
 pub use gen_struct::gen_struct;
 use model::{definition_docs::Value, Definition};
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
+use utils::pretty_doc_string;
 
 mod gen_row;
 mod gen_struct;
+mod gen_tagged_union;
 
-pub fn gen_definition(
-    Definition {
+/// Generates the code for a single definition.
+///
+/// `definitions` are all the definitions, used to find the types a tagged union can hold
+pub fn gen_definition(definition: &Definition, definitions: &[&Definition]) -> Result<TokenStream> {
+    let Definition {
         name,
         doc_string,
         value,
-    }: &Definition,
-) -> Result<TokenStream> {
-    let definition = match value {
-        Value::Table(rows) => match rows.as_slice() {
-            [row] => gen_single_row(row, name, doc_string),
-            rows => gen_rows(rows, name, doc_string),
+    } = definition;
+    let variants = tagged_union_variants(definition, definitions)?;
+    let definition = match (value, variants) {
+        (_, Some(variants)) => gen_tagged_union(name, doc_string, &variants),
+        (value, None) => match value {
+            Value::Table(rows) => match rows.as_slice() {
+                [row] => gen_single_row(row, name, doc_string),
+                rows => gen_rows(rows, name, doc_string),
+            },
+            Value::Struct(s) => gen_struct(s, name),
+            Value::Empty => {
+                let doc_string =
+                    pretty_doc_string(doc_string).change_context_lazy(Error::default)?;
+                Ok(gen_typed_string(
+                    &Ident::new(name, Span::call_site()),
+                    &doc_string,
+                ))
+            }
         },
-        Value::Struct(s) => gen_struct(s, name),
-        Value::Empty => gen_typed_string(name),
     }?;
 
     Ok(quote! {
@@ -51,7 +67,7 @@ mod test {
                 .to_string(),
             value: Value::Empty,
         };
-        let tokens = super::gen_definition(&input)?;
+        let tokens = super::gen_definition(&input, &[])?;
         let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
         println!("{code}");
         Ok(())
@@ -45,9 +45,25 @@ pub fn gen_single_row(row: &Row, name: &str, struct_doc_string: &str) -> Result<
     let doc_string = format!("{struct_doc_string}\n\n{field_doc_string}");
     let doc_string =
         pretty_doc_string(&doc_string).change_context_lazy(|| Error::new("Creating doc string"))?;
-    Ok(quote! {
+    if is_decimal(row) {
+        return Ok(gen_decimal(&struct_name, &doc_string));
+    }
+    if is_date_time(row) {
+        return Ok(gen_date_time(&struct_name, &doc_string));
+    }
+    if type_name != "String" {
+        bail!("Expected {name} to be a string, but it's a {type_name}: {row:#?}")
+    }
+    Ok(gen_typed_string(&struct_name, &doc_string))
+}
+
+/// Generates a type wrapping a `String`, eg. an `InstrumentName`. It's written out as the
+/// string, in json and in the url of a call, and made from one with `into()`
+pub fn gen_typed_string(struct_name: &Ident, doc_string: &[TokenStream]) -> TokenStream {
+    quote! {
         #(#doc_string)*
-        pub struct #struct_name(#type_name);
+        #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        pub struct #struct_name(String);
 
         impl std::ops::Deref for #struct_name {
             type Target = str;
@@ -56,7 +72,200 @@ pub fn gen_single_row(row: &Row, name: &str, struct_doc_string: &str) -> Result<
                 self.0.as_str()
             }
         }
-    })
+
+        impl std::fmt::Display for #struct_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<String> for #struct_name {
+            fn from(value: String) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<&str> for #struct_name {
+            fn from(value: &str) -> Self {
+                Self(value.to_string())
+            }
+        }
+    }
+}
+
+/// True if the row describes a decimal number sent as a string. eg. `PriceValue`
+fn is_decimal(row: &Row) -> bool {
+    matches!(row, Row::Format { format, .. } if format.starts_with("A decimal number"))
+}
+
+/// Generates a type wrapping an exact `crate::decimal::Decimal`, that still reads and writes
+/// OANDA's string form. It does the same arithmetic as a `Decimal`, giving back its own type
+fn gen_decimal(struct_name: &Ident, doc_string: &[TokenStream]) -> TokenStream {
+    let ops = [
+        (
+            quote! { Add },
+            quote! { add },
+            quote! { Self },
+            quote! { rhs.0 },
+        ),
+        (
+            quote! { Sub },
+            quote! { sub },
+            quote! { Self },
+            quote! { rhs.0 },
+        ),
+        // Scaling, eg. a price by a number of units
+        (
+            quote! { Mul },
+            quote! { mul },
+            quote! { crate::decimal::Decimal },
+            quote! { rhs },
+        ),
+        (
+            quote! { Div },
+            quote! { div },
+            quote! { crate::decimal::Decimal },
+            quote! { rhs },
+        ),
+    ]
+    .into_iter()
+    .map(|(op, method, rhs, rhs_decimal)| {
+        quote! {
+            impl std::ops::#op<#rhs> for #struct_name {
+                type Output = Self;
+
+                fn #method(self, rhs: #rhs) -> Self::Output {
+                    Self(std::ops::#op::#method(self.0, #rhs_decimal))
+                }
+            }
+        }
+    });
+    quote! {
+        #(#doc_string)*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct #struct_name(pub crate::decimal::Decimal);
+
+        impl std::ops::Deref for #struct_name {
+            type Target = crate::decimal::Decimal;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<crate::decimal::Decimal> for #struct_name {
+            fn from(value: crate::decimal::Decimal) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<#struct_name> for crate::decimal::Decimal {
+            fn from(value: #struct_name) -> Self {
+                value.0
+            }
+        }
+
+        impl std::str::FromStr for #struct_name {
+            type Err = crate::decimal::ParseDecimalError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+
+        /// Give a precision to round to it, eg. `{:.5}`
+        impl std::fmt::Display for #struct_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl std::ops::Neg for #struct_name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        #(#ops)*
+    }
+}
+
+/// True if the row describes the `DateTime` definition, that can be RFC3339 or UNIX time
+fn is_date_time(row: &Row) -> bool {
+    matches!(row, Row::Format { format, .. } if format.starts_with("The RFC 3339 representation"))
+}
+
+/// Generates a type wrapping a `chrono::DateTime<Utc>`. It reads either of OANDA's formats, and
+/// displays and serializes as RFC3339. The requests write it in the one the client asked for,
+/// with `format` in the query and `crate::datetime_format::json_body` in the body
+fn gen_date_time(struct_name: &Ident, doc_string: &[TokenStream]) -> TokenStream {
+    quote! {
+        #(#doc_string)*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct #struct_name(pub chrono::DateTime<chrono::Utc>);
+
+        impl std::ops::Deref for #struct_name {
+            type Target = chrono::DateTime<chrono::Utc>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<chrono::DateTime<chrono::Utc>> for #struct_name {
+            fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<#struct_name> for chrono::DateTime<chrono::Utc> {
+            fn from(value: #struct_name) -> Self {
+                value.0
+            }
+        }
+
+        impl std::str::FromStr for #struct_name {
+            type Err = crate::datetime_format::ParseDateTimeError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                crate::datetime_format::parse(s).map(Self)
+            }
+        }
+
+        impl #struct_name {
+            /// Writes the time in `format`, to the nanosecond
+            pub fn format(
+                &self,
+                format: crate::definitions::accept_datetime_format::AcceptDatetimeFormat,
+            ) -> String {
+                crate::datetime_format::format(&self.0, format)
+            }
+        }
+
+        impl std::fmt::Display for #struct_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.format(
+                    crate::definitions::accept_datetime_format::AcceptDatetimeFormat::Rfc3339,
+                ))
+            }
+        }
+
+        impl Serialize for #struct_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                crate::datetime_format::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for #struct_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    }
 }
 
 /// Generates an enum from an HTML table that has more than one row
@@ -81,7 +290,8 @@ pub fn gen_rows(rows: &[Row], enum_name: &str, enum_doc_string: &str) -> Result<
         .collect::<Result<Vec<TokenStream>>>()?;
     Ok(quote! {
         #(#doc_string)*
-        #[derive(Deserialize, Serialize)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, parse_display::Display, Deserialize, Serialize)]
+        #[display(style = "SNAKE_CASE")]
         #[serde(rename_all="SCREAMING_SNAKE_CASE")]
         pub enum #enum_name {
             #(#enum_variants)*
@@ -111,6 +321,58 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_gen_single_row_decimal() -> crate::error::Result<()> {
+        let input = Row::Format {
+            r#type: "string".to_string(),
+            format: "A decimal number encodes as a string. The amount of precision provided depends on the Instrument.".to_string(),
+        };
+        let tokens = super::gen_single_row(
+            &input,
+            "PriceValue",
+            "The string representation of a Price for a Bucket.",
+        )?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert!(
+            code.contains("pub struct PriceValue(pub crate::decimal::Decimal);"),
+            "{code}"
+        );
+        assert!(code.contains("#[serde(transparent)]"), "{code}");
+        assert!(
+            code.contains("impl std::ops::Mul<crate::decimal::Decimal> for PriceValue {"),
+            "{code}"
+        );
+        assert!(
+            code.contains("impl std::ops::Add<Self> for PriceValue {"),
+            "{code}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_single_row_date_time() -> crate::error::Result<()> {
+        let input = Row::Format {
+            r#type: "string".to_string(),
+            format: "The RFC 3339 representation is a string conforming to ".to_string(),
+        };
+        let tokens = super::gen_single_row(
+            &input,
+            "DateTime",
+            "A date and time value using either RFC3339 or UNIX time representation.",
+        )?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert!(
+            code.contains("pub struct DateTime(pub chrono::DateTime<chrono::Utc>);"),
+            "{code}"
+        );
+        assert!(
+            code.contains("crate::datetime_format::parse(s).map(Self)"),
+            "{code}"
+        );
+        assert!(code.contains("impl Serialize for DateTime {"), "{code}");
+        Ok(())
+    }
+
     #[test]
     fn test_gen_rows() -> crate::error::Result<()> {
         let input = vec![
@@ -147,6 +409,8 @@ mod test {
         let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
         println!("{code}");
         assert!(code.contains("SuperEnum"));
+        // Display gives the same text as the json, so they can be sent as http headers and query params
+        assert!(code.contains(r#"#[display(style = "SNAKE_CASE")]"#));
         Ok(())
     }
 }
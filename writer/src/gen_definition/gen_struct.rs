@@ -1,7 +1,7 @@
 //! Generates error.rs for oanda_v2
 use crate::{
-    error::{Result, Tracer},
-    util::field_name,
+    error::{EasyError, Result, Tracer},
+    util::{field_name, snake_case_name},
     Error,
 };
 use error_stack::ResultExt;
@@ -16,10 +16,21 @@ pub fn gen_struct(s: &Struct, name: &str) -> Result<TokenStream> {
     let fields = gen_fields(&s.fields)
         .attach_printable_lazy(|| format!("While generating fields for struct {name}"))?;
     let name = Ident::new(name, proc_macro2::Span::call_site());
-    let defaults = gen_defaults(&s.fields, &name);
+    // Without any field defaults, every field's own default will do
+    if s.fields.iter().all(|field| field.default.is_none()) {
+        return Ok(quote! {
+            #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+            pub struct #name {
+                #(#fields)*
+            }
+        });
+    }
+    let defaults = gen_defaults(&s.fields, &name)
+        .attach_printable_lazy(|| format!("While generating the defaults for struct {name}"))?;
+    // serde_inline_default has to come first, so the derive sees the defaults it adds
     Ok(quote! {
-        #[derive(Serialize, Deserialize)]
         #[serde_inline_default]
+        #[derive(Debug, Clone, Serialize, Deserialize)]
         pub struct #name {
             #(#fields)*
         }
@@ -28,29 +39,6 @@ pub fn gen_struct(s: &Struct, name: &str) -> Result<TokenStream> {
     })
 }
 
-/// Generates a type with a single string inside
-pub fn gen_typed_string(name: &str) -> Result<TokenStream> {
-    let name = Ident::new(name, proc_macro2::Span::call_site());
-    Ok(quote! {
-        #[derive(Serialize, Deserialize)]
-        pub struct #name (String);
-
-        impl ToString for #name {
-            fn to_string(&self) -> String {
-                self.0
-            }
-        }
-
-        impl std::ops::Deref for X {
-            type Target = str;
-
-            fn deref(&self) -> &Self::Target {
-                self.0.as_str()
-            }
-        }
-    })
-}
-
 fn gen_fields(fields: &[Field]) -> Result<Vec<TokenStream>> {
     fields.iter().map(gen_field).collect()
 }
@@ -65,12 +53,11 @@ fn gen_field(
         required,
     }: &Field,
 ) -> Result<TokenStream> {
+    // Keep the json key when the rust name differs from it. `type` becomes `r#type`, which serde
+    // already reads as `type`
+    let rename = (name != "type" && snake_case_name(name) != *name)
+        .then(|| quote! { #[serde(rename = #name)] });
     let name = field_name(name);
-    let type_name = if type_name.as_str() == "string" {
-        "String"
-    } else {
-        type_name.as_str()
-    };
     let type_name = Ident::new(type_name, proc_macro2::Span::call_site());
     let doc_string = pretty_doc_string(doc_string)
         .change_context_lazy(Error::default)
@@ -84,33 +71,62 @@ fn gen_field(
         quote! {#type_name}
     };
     Ok(if let Some(default) = default {
+        let default = default_expression(default)?;
         quote! {
             #(#doc_string)*
+            #rename
             #[serde_inline_default(#default)]
-            #name: #type_name,
+            pub #name: #type_name,
+        }
+    } else if *is_array || !*required {
+        // The server leaves out empty lists and fields it has no value for, eg. an error reply
+        // with just an `errorMessage`. We leave them out too: a `null` can mean something, eg.
+        // a `stopLoss` of `null` cancels the Trade's Stop Loss
+        let is_empty = if *is_array {
+            "Vec::is_empty"
+        } else {
+            "Option::is_none"
+        };
+        quote! {
+            #(#doc_string)*
+            #rename
+            #[serde(default, skip_serializing_if = #is_empty)]
+            pub #name: #type_name,
         }
     } else {
         quote! {
             #(#doc_string)*
-            #name: #type_name,
+            #rename
+            pub #name: #type_name,
         }
     })
 }
 
+/// Parses a field default, that `resolve_type_names` has made into a rust expression
+fn default_expression(default: &str) -> Result<syn::Expr> {
+    syn::parse_str(default).annotate_lazy(|| format!("Parsing the default: {default}"))
+}
+
 /// Generates the Default and serde default functions
-fn gen_defaults(fields: &[Field], name: &Ident) -> TokenStream {
-    let fields = fields.iter().map(|Field { name, default, .. }| {
-        let name = field_name(name);
-        let default = default
-            .as_deref()
-            .map(|v| quote! { #v })
-            .unwrap_or_else(|| quote! { Default::default() });
-        quote! {
-            #name: #default
-        }
-    });
+fn gen_defaults(fields: &[Field], name: &Ident) -> Result<TokenStream> {
+    let fields = fields
+        .iter()
+        .map(|Field { name, default, .. }| {
+            let name = field_name(name);
+            let default = match default {
+                Some(default) => {
+                    let default = default_expression(default)?;
+                    quote! { #default }
+                }
+                None => quote! { Default::default() },
+            };
+            Ok(quote! {
+                #name: #default
+            })
+        })
+        .collect::<Result<Vec<TokenStream>>>()?;
 
-    quote! {
+    Ok(quote! {
         impl Default for #name {
             fn default() -> Self {
                 Self{
@@ -118,7 +134,7 @@ fn gen_defaults(fields: &[Field], name: &Ident) -> TokenStream {
                 }
             }
         }
-    }
+    })
 }
 
 #[cfg(test)]
@@ -153,7 +169,7 @@ mod test {
                 type_name: "String".to_string(),
                 doc_string: "Don't get too close".to_string(),
                 is_array: false,
-                default: Some("Mister Fartsy".to_string()),
+                default: Some(r#"String::from("Mister Fartsy")"#.to_string()),
                 required: false,
             },
             Field {
@@ -174,25 +190,27 @@ mod test {
         assert_eq!(
             code.to_string(),
             indoc! {r#"
-                #[derive(Serialize, Deserialize)]
                 #[serde_inline_default]
+                #[derive(Debug, Clone, Serialize, Deserialize)]
                 pub struct TestStruct {
                     /// Field 1
-                    field1: String,
+                    pub field1: String,
                     /// Field 2
-                    field2: Vec<u32>,
+                    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                    pub field2: Vec<u32>,
                     /// Don't get too close
-                    #[serde_inline_default("Mister Fartsy")]
-                    name: String,
+                    #[serde_inline_default(String::from("Mister Fartsy"))]
+                    pub name: String,
                     /// Optional
-                    age: Option<u32>,
+                    #[serde(default, skip_serializing_if = "Option::is_none")]
+                    pub age: Option<u32>,
                 }
                 impl Default for TestStruct {
                     fn default() -> Self {
                         Self {
                             field1: Default::default(),
                             field2: Default::default(),
-                            name: "Mister Fartsy",
+                            name: String::from("Mister Fartsy"),
                             age: Default::default(),
                         }
                     }
@@ -233,7 +251,7 @@ mod test {
             indoc! {"
             struct Tmp {
                 /// A very nice field
-                name: String,
+                pub name: String,
             }
             "}
             .to_string()
@@ -241,6 +259,31 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_gen_field_rename() -> Result<()> {
+        let field = Field {
+            name: "relatedTransactionIDs".to_string(),
+            type_name: "TransactionID".to_string(),
+            doc_string: "The IDs of all Transactions".to_string(),
+            is_array: true,
+            default: None,
+            required: false,
+        };
+        assert_eq!(
+            make_field_code(field).trace()?,
+            indoc! {r#"
+            struct Tmp {
+                /// The IDs of all Transactions
+                #[serde(rename = "relatedTransactionIDs")]
+                #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                pub related_transaction_ids: Vec<TransactionID>,
+            }
+            "#}
+            .to_string()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_gen_field_array() -> Result<()> {
         let field = Field {
@@ -254,12 +297,13 @@ mod test {
         let code = make_field_code(field).trace()?;
         assert_eq!(
             code,
-            indoc! {"
+            indoc! {r#"
                 struct Tmp {
                     /// A vec of names
-                    names: Vec<String>,
+                    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                    pub names: Vec<String>,
                 }
-            "}
+            "#}
         );
         Ok(())
     }
@@ -271,7 +315,7 @@ mod test {
             type_name: "String".to_string(),
             doc_string: "A vec of names".to_string(),
             is_array: false,
-            default: Some("Mister Joe".to_string()),
+            default: Some(r#"String::from("Mister Joe")"#.to_string()),
             required: true,
         };
         let code = make_field_code(field).trace()?;
@@ -280,8 +324,8 @@ mod test {
             indoc! {r#"
                 struct Tmp {
                     /// A vec of names
-                    #[serde_inline_default("Mister Joe")]
-                    names: String,
+                    #[serde_inline_default(String::from("Mister Joe"))]
+                    pub names: String,
                 }
             "#}
         );
@@ -304,7 +348,8 @@ mod test {
             indoc! {r#"
                 struct Tmp {
                     /// You don't really need a name
-                    optional_name: Option<String>,
+                    #[serde(default, skip_serializing_if = "Option::is_none")]
+                    pub optional_name: Option<String>,
                 }
             "#}
         );
@@ -319,7 +364,7 @@ mod test {
             type_name: "String".to_string(),
             doc_string: "You don't really need a name".to_string(),
             is_array: false,
-            default: Some("Master Blaster".to_owned()),
+            default: Some(r#"String::from("Master Blaster")"#.to_owned()),
             required: false,
         };
         let code = make_field_code(field).trace()?;
@@ -328,8 +373,8 @@ mod test {
             indoc! {r#"
                 struct Tmp {
                     /// You don't really need a name
-                    #[serde_inline_default("Master Blaster")]
-                    optional_name: String,
+                    #[serde_inline_default(String::from("Master Blaster"))]
+                    pub optional_name: String,
                 }
             "#}
         );
@@ -353,7 +398,8 @@ mod test {
             indoc! {r#"
                 struct Tmp {
                     /// Should be an empty array probably 🤷
-                    optional_array: Vec<String>,
+                    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                    pub optional_array: Vec<String>,
                 }
             "#}
         );
@@ -0,0 +1,438 @@
+//! Generates the enums for the definitions that are really one of many types, picked by their
+//! `type` field. eg. a `Transaction` may be an `OrderFillTransaction` or a `DailyFinancingTransaction`
+use crate::{bail, error::Result, resolve::enum_variant, Error};
+use error_stack::ResultExt;
+use model::{
+    definition_docs::{Row, Value},
+    Definition,
+};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::Ident;
+use utils::pretty_doc_string;
+
+/// The definitions that we generate as tagged unions, and the enum definition holding their tags
+const TAGGED_UNIONS: [(&str, &str); 3] = [
+    ("Transaction", "TransactionType"),
+    ("Order", "OrderType"),
+    ("OrderRequest", "OrderType"),
+];
+
+/// One of the types a tagged union can hold
+#[derive(Debug, PartialEq, Eq)]
+pub struct Variant<'a> {
+    /// The value of the `type` field for this variant. eg. "ORDER_FILL"
+    pub tag: &'a str,
+    /// The doc string from the tag enum's table
+    pub doc_string: &'a str,
+    /// The name of the struct holding the variant. eg. "OrderFillTransaction"
+    pub struct_name: &'a str,
+    /// The type of the struct's optional `id` field, if it has one. eg. "TransactionID"
+    pub id_type: Option<&'a str>,
+}
+
+/// If `definition` is one of the tagged unions, finds all the definitions it can hold.
+///
+/// Those are the structs whose name ends with the definition's, and that have a `type` field of
+/// the tag enum defaulting to one of its variants. `resolve_type_names` leaves the default as
+/// the variant, eg. `TransactionType::OrderFill`. Matching the name tells apart structs with the
+/// same tag, eg. `MarketOrder` for `Order` and `MarketOrderRequest` for `OrderRequest` are both
+/// tagged "MARKET". They're returned in the order the tag enum lists them
+pub fn tagged_union_variants<'a>(
+    definition: &Definition,
+    definitions: &[&'a Definition],
+) -> Result<Option<Vec<Variant<'a>>>> {
+    let Some((_, tag_type)) = TAGGED_UNIONS
+        .iter()
+        .find(|(name, _)| *name == definition.name)
+    else {
+        return Ok(None);
+    };
+    let Some(Value::Table(rows)) = definitions
+        .iter()
+        .find(|d| d.name == *tag_type)
+        .map(|d| &d.value)
+    else {
+        let name = &definition.name;
+        bail!("Couldn't find the {tag_type} table to make the {name} enum")
+    };
+    let variants = rows
+        .iter()
+        .filter_map(|row| match row {
+            Row::ValueDescription { value, description } => Some((value, description)),
+            _ => None,
+        })
+        .filter_map(|(tag, doc_string)| {
+            let default = enum_variant(tag_type, tag);
+            definitions
+                .iter()
+                .filter(|d| d.name.ends_with(&definition.name))
+                .find(|d| type_default(d, tag_type) == Some(&default))
+                .map(|d| Variant {
+                    tag,
+                    doc_string,
+                    struct_name: &d.name,
+                    id_type: optional_field_type(d, "id"),
+                })
+        })
+        .collect::<Vec<Variant>>();
+    if variants.is_empty() {
+        bail!("Couldn't find any structs tagged by {tag_type}")
+    }
+    Ok(Some(variants))
+}
+
+/// If the definition is a struct with a `type` field of `tag_type`, returns its default
+fn type_default<'a>(definition: &'a Definition, tag_type: &str) -> Option<&'a str> {
+    let Value::Struct(s) = &definition.value else {
+        return None;
+    };
+    s.fields
+        .iter()
+        .find(|field| field.name == "type" && field.type_name == tag_type)
+        .and_then(|field| field.default.as_deref())
+}
+
+/// The type of the struct's field called `name`, if it's generated as an `Option`
+fn optional_field_type<'a>(definition: &'a Definition, name: &str) -> Option<&'a str> {
+    let Value::Struct(s) = &definition.value else {
+        return None;
+    };
+    s.fields
+        .iter()
+        .find(|field| {
+            field.name == name && !field.is_array && !field.required && field.default.is_none()
+        })
+        .map(|field| field.type_name.as_str())
+}
+
+/// Generates an enum with a variant for each struct it can hold, eg:
+///
+/// ```ignore
+/// pub enum Transaction {
+///     /// Order Fill Transaction
+///     OrderFill(Box<OrderFillTransaction>),
+///     /// Daily Financing Transaction
+///     DailyFinancing(Box<DailyFinancingTransaction>),
+///     /// A type that isn't documented yet, as the json the server sent
+///     Unknown(serde_json::Value),
+/// }
+/// ```
+///
+/// Deserializing reads the `type` field, then the whole object as the struct for that tag, with
+/// its `type` field and all. OANDA adds new types from time to time, so a tag we don't know
+/// about is kept as `Unknown`, rather than failing the whole reply or stream. A tag we do know
+/// must parse as its struct though, or it's an error, so a change to a type we know about isn't
+/// hidden. The structs are boxed, as some are many times the size of others.
+///
+/// Each struct serializes as itself, `type` field included.
+///
+/// When every struct has an `id` of the same type, eg. each kind of `Transaction` has a
+/// `TransactionID`, the enum gets an `id()` that reads it from whichever one it holds
+pub fn gen_tagged_union(name: &str, doc_string: &str, variants: &[Variant]) -> Result<TokenStream> {
+    let doc_string = pretty_doc_string(doc_string).change_context_lazy(Error::default)?;
+    let enum_name = Ident::new(name, Span::call_site());
+    let variant_names = variants
+        .iter()
+        .map(|variant| {
+            let variant_name = variant
+                .struct_name
+                .strip_suffix(name)
+                .filter(|prefix| !prefix.is_empty())
+                .unwrap_or(variant.struct_name);
+            Ident::new(variant_name, Span::call_site())
+        })
+        .collect::<Vec<Ident>>();
+    let struct_names = variants
+        .iter()
+        .map(|variant| Ident::new(variant.struct_name, Span::call_site()));
+    let variant_docs = variants
+        .iter()
+        .map(|variant| {
+            pretty_doc_string(variant.doc_string).change_context_lazy(|| {
+                Error::new(format!("Creating doc string for {}", variant.struct_name))
+            })
+        })
+        .collect::<Result<Vec<Vec<TokenStream>>>>()?
+        .into_iter()
+        .map(|doc_string| quote! { #(#doc_string)* });
+    let tags = variants.iter().map(|variant| variant.tag);
+    let id = gen_id(&enum_name, &variant_names, variants);
+    Ok(quote! {
+        #(#doc_string)*
+        #[derive(Debug, Clone)]
+        pub enum #enum_name {
+            #(
+                #variant_docs
+                #variant_names(Box<#struct_names>),
+            )*
+            /// A type that isn't documented yet, as the json the server sent
+            Unknown(serde_json::Value),
+        }
+
+        impl<'de> Deserialize<'de> for #enum_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let value = serde_json::Value::deserialize(deserializer)?;
+                match value.get("type").and_then(serde_json::Value::as_str) {
+                    #(Some(#tags) => crate::error::from_value(value).map(#enum_name::#variant_names),)*
+                    _ => Ok(#enum_name::Unknown(value)),
+                }
+            }
+        }
+
+        impl Serialize for #enum_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                match self {
+                    #(#enum_name::#variant_names(inner) => inner.serialize(serializer),)*
+                    #enum_name::Unknown(value) => value.serialize(serializer),
+                }
+            }
+        }
+
+        #id
+    })
+}
+
+/// The `id()` accessor, if every variant has an `id` of the same type
+fn gen_id(enum_name: &Ident, variant_names: &[Ident], variants: &[Variant]) -> TokenStream {
+    let Some(id_type) = variants.first().and_then(|variant| variant.id_type) else {
+        return TokenStream::new();
+    };
+    if variants.iter().any(|variant| variant.id_type != Some(id_type)) {
+        return TokenStream::new();
+    }
+    let id_type = Ident::new(id_type, Span::call_site());
+    quote! {
+        impl #enum_name {
+            /// The `id` of the type it holds. For an `Unknown` type, it's read from the json, if
+            /// it's there
+            pub fn id(&self) -> Option<#id_type> {
+                match self {
+                    #(#enum_name::#variant_names(inner) => inner.id.clone(),)*
+                    #enum_name::Unknown(value) => value
+                        .get("id")
+                        .and_then(|id| serde_json::from_value(id.clone()).ok()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Variant;
+    use crate::resolve::enum_variant;
+    use crate::{Error, Result};
+    use error_stack::ResultExt;
+    use indoc::indoc;
+    use model::{
+        definition_docs::{Field, Row, Struct, Value},
+        Definition,
+    };
+    use pretty_assertions::assert_eq;
+    use utils::stream_to_string;
+
+    fn tagged_struct(name: &str, tag_type: &str, tag: &str) -> Definition {
+        Definition {
+            name: name.to_string(),
+            doc_string: String::new(),
+            value: Value::Struct(Struct {
+                fields: vec![Field {
+                    name: "type".to_string(),
+                    type_name: tag_type.to_string(),
+                    doc_string: String::new(),
+                    is_array: false,
+                    default: Some(enum_variant(tag_type, tag)),
+                    required: false,
+                }],
+            }),
+        }
+    }
+
+    fn with_id(mut definition: Definition, id_type: &str) -> Definition {
+        if let Value::Struct(s) = &mut definition.value {
+            s.fields.push(Field {
+                name: "id".to_string(),
+                type_name: id_type.to_string(),
+                doc_string: String::new(),
+                is_array: false,
+                default: None,
+                required: false,
+            });
+        }
+        definition
+    }
+
+    #[test]
+    fn test_tagged_union_variants() -> Result<()> {
+        let transaction = Definition {
+            name: "Transaction".to_string(),
+            doc_string: "The base Transaction specification.".to_string(),
+            value: Value::Struct(Struct::default()),
+        };
+        let transaction_type = Definition {
+            name: "TransactionType".to_string(),
+            doc_string: String::new(),
+            value: Value::Table(
+                [
+                    ("ORDER_FILL", "Order Fill Transaction"),
+                    ("CLOSE", "Account Close Transaction"),
+                ]
+                .into_iter()
+                .map(|(value, description)| Row::ValueDescription {
+                    value: value.to_string(),
+                    description: description.to_string(),
+                })
+                .collect(),
+            ),
+        };
+        let close = tagged_struct("CloseTransaction", "TransactionType", "CLOSE");
+        let fill = with_id(
+            tagged_struct("OrderFillTransaction", "TransactionType", "ORDER_FILL"),
+            "TransactionID",
+        );
+        let definitions = [&transaction, &close, &transaction_type, &fill];
+        let variants = super::tagged_union_variants(&transaction, &definitions)?;
+        assert_eq!(
+            Some(vec![
+                Variant {
+                    tag: "ORDER_FILL",
+                    doc_string: "Order Fill Transaction",
+                    struct_name: "OrderFillTransaction",
+                    id_type: Some("TransactionID"),
+                },
+                Variant {
+                    tag: "CLOSE",
+                    doc_string: "Account Close Transaction",
+                    struct_name: "CloseTransaction",
+                    id_type: None,
+                },
+            ]),
+            variants
+        );
+        // Only the listed definitions are tagged unions
+        assert_eq!(None, super::tagged_union_variants(&close, &definitions)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tagged_union_variants_by_name() -> Result<()> {
+        let order = Definition {
+            name: "Order".to_string(),
+            doc_string: String::new(),
+            value: Value::Struct(Struct::default()),
+        };
+        let order_request = Definition {
+            name: "OrderRequest".to_string(),
+            doc_string: String::new(),
+            value: Value::Empty,
+        };
+        let order_type = Definition {
+            name: "OrderType".to_string(),
+            doc_string: String::new(),
+            value: Value::Table(vec![Row::ValueDescription {
+                value: "MARKET".to_string(),
+                description: "A Market Order".to_string(),
+            }]),
+        };
+        let market = tagged_struct("MarketOrder", "OrderType", "MARKET");
+        let market_request = tagged_struct("MarketOrderRequest", "OrderType", "MARKET");
+        let definitions = [
+            &order,
+            &order_request,
+            &order_type,
+            &market_request,
+            &market,
+        ];
+        let struct_names = |definition| -> Result<Vec<&str>> {
+            Ok(super::tagged_union_variants(definition, &definitions)?
+                .unwrap_or_default()
+                .into_iter()
+                .map(|variant| variant.struct_name)
+                .collect())
+        };
+        // Both structs are tagged "MARKET", so they're told apart by name
+        assert_eq!(vec!["MarketOrder"], struct_names(&order)?);
+        assert_eq!(vec!["MarketOrderRequest"], struct_names(&order_request)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_tagged_union() -> Result<()> {
+        let variants = [
+            Variant {
+                tag: "ORDER_FILL",
+                doc_string: "Order Fill Transaction",
+                struct_name: "OrderFillTransaction",
+                id_type: Some("TransactionID"),
+            },
+            Variant {
+                tag: "CLOSE",
+                doc_string: "Account Close Transaction",
+                struct_name: "CloseTransaction",
+                id_type: Some("TransactionID"),
+            },
+        ];
+        let tokens = super::gen_tagged_union(
+            "Transaction",
+            "The base Transaction specification.",
+            &variants,
+        )?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                /// The base Transaction specification.
+                #[derive(Debug, Clone)]
+                pub enum Transaction {
+                    /// Order Fill Transaction
+                    OrderFill(Box<OrderFillTransaction>),
+                    /// Account Close Transaction
+                    Close(Box<CloseTransaction>),
+                    /// A type that isn't documented yet, as the json the server sent
+                    Unknown(serde_json::Value),
+                }
+                impl<'de> Deserialize<'de> for Transaction {
+                    fn deserialize<D: serde::Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> std::result::Result<Self, D::Error> {
+                        let value = serde_json::Value::deserialize(deserializer)?;
+                        match value.get("type").and_then(serde_json::Value::as_str) {
+                            Some("ORDER_FILL") => {
+                                crate::error::from_value(value).map(Transaction::OrderFill)
+                            }
+                            Some("CLOSE") => crate::error::from_value(value).map(Transaction::Close),
+                            _ => Ok(Transaction::Unknown(value)),
+                        }
+                    }
+                }
+                impl Serialize for Transaction {
+                    fn serialize<S: serde::Serializer>(
+                        &self,
+                        serializer: S,
+                    ) -> std::result::Result<S::Ok, S::Error> {
+                        match self {
+                            Transaction::OrderFill(inner) => inner.serialize(serializer),
+                            Transaction::Close(inner) => inner.serialize(serializer),
+                            Transaction::Unknown(value) => value.serialize(serializer),
+                        }
+                    }
+                }
+                impl Transaction {
+                    /// The `id` of the type it holds. For an `Unknown` type, it's read from the json, if
+                    /// it's there
+                    pub fn id(&self) -> Option<TransactionID> {
+                        match self {
+                            Transaction::OrderFill(inner) => inner.id.clone(),
+                            Transaction::Close(inner) => inner.id.clone(),
+                            Transaction::Unknown(value) => {
+                                value.get("id").and_then(|id| serde_json::from_value(id.clone()).ok())
+                            }
+                        }
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+}
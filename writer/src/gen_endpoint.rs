@@ -3,18 +3,21 @@ mod gen_responses;
 
 pub use self::gen_responses::gen_responses_for_call;
 use crate::{
-    util::{field_name, Location, ResponsesInfo, Writer},
+    bail,
+    gen_definition::gen_struct,
+    util::{field_name, ResponsesInfo, Writer},
     Error, Result,
 };
 use change_case::{lower_case, pascal_case, snake_case};
 use error_stack::ResultExt;
 use model::{
-    endpoint_docs::{HttpMethod, Response, RestCall},
+    definition_docs::Schema,
+    endpoint_docs::{HttpMethod, LocatedIn, Response, RestCall, RestCallParameter},
     Endpoint,
 };
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::collections::{HashMap, HashSet};
+use std::collections::BTreeMap;
 use tracing::instrument;
 use utils::pretty_doc_string;
 
@@ -33,6 +36,16 @@ pub trait CallNames {
     fn response_struct_prefix(&self) -> Result<String> {
         Ok(pascal_case(&self.method_name_as_string()?))
     }
+    /// Name of the struct that holds all the parameters for the call. eg. `CandlesRequest`
+    fn request_struct_name(&self) -> Result<Ident> {
+        let name = format!("{}Request", self.response_struct_prefix()?);
+        Ok(Ident::new(&name, Span::call_site()))
+    }
+    /// Name of the struct that holds the json request body. eg. `PostOrdersBody`
+    fn body_struct_name(&self) -> Result<Ident> {
+        let name = format!("{}Body", self.response_struct_prefix()?);
+        Ok(Ident::new(&name, Span::call_site()))
+    }
     /// Returns the parts (from base_path) of the filename where the code for the response structs are stored
     /// eg. a filename for the `account` enpdoint and the `instruments` could be:
     /// format!("{base_path}/endpoints/account/responses/instruments.rs");
@@ -40,7 +53,7 @@ pub trait CallNames {
     /// ```rust
     /// vec!["endpoints", "account", "responses", "instruments"]
     ///     .into_iter()
-    ///     .map(str::ToString);
+    ///     .map(str::to_string);
     /// ```
     fn responses_module_parts(&self) -> Result<Vec<String>> {
         Ok(vec![
@@ -57,13 +70,6 @@ pub trait CallNames {
     /// All the responses possible
     /// The good response and a vec of all the bad responses
     fn good_and_bad_responses(&self) -> Result<(&Response, Vec<&Response>)>;
-
-    /// The type name of the good response
-    /// (All other responses will be turned into Errors)
-    fn good_response_type_code(&self) -> Result<u16> {
-        let (good_response, _bad_responses) = self.good_and_bad_responses()?;
-        Ok(good_response.code)
-    }
 }
 
 impl CallNames for RestCall {
@@ -117,26 +123,6 @@ impl CallNames for RestCall {
     }
 }
 
-pub trait ResponseNames {
-    /// A centralized place to get the prefix for all the type names
-    /// of the possible responses to a REST cal
-    fn struct_prefix(call: &RestCall) -> Result<String> {
-        call.response_struct_prefix()
-    }
-    /// Needs to be implemented to get the code
-    fn code(&self) -> u16;
-    /// Generates the type name for a certain response
-    fn type_name(&self, prefix: &str) -> String {
-        format!("{prefix}{code}", code = self.code())
-    }
-}
-
-impl ResponseNames for Response {
-    fn code(&self) -> u16 {
-        self.code
-    }
-}
-
 /// Generate the code where we're inserting parameters into the url
 fn gen_path_params(call: &RestCall) -> TokenStream {
     call.parameters
@@ -146,111 +132,483 @@ fn gen_path_params(call: &RestCall) -> TokenStream {
         .collect()
 }
 
+/// Headers that every call takes. `Client` sets these on every request, so they aren't
+/// passed to each call
+const CLIENT_HEADERS: [&str; 2] = ["Authorization", "Accept-Datetime-Format"];
+
+/// True if `Client` fills in this parameter, rather than it being passed to the call
+fn is_client_header(param: &RestCallParameter) -> bool {
+    param.located_in.is_header() && CLIENT_HEADERS.contains(&param.name.as_str())
+}
+
+/// The header `Client` uses to know that a call other than a GET is safe to send again
+const CLIENT_REQUEST_ID: &str = "ClientRequestID";
+
+/// Adds an optional `ClientRequestID` header to calls that change something but don't
+/// document one, eg. POST /orders. Without it the client can never retry them
+fn with_client_request_id(call: &RestCall) -> RestCall {
+    let mut call = call.clone();
+    let documented = call
+        .parameters
+        .iter()
+        .any(|p| p.located_in.is_header() && p.name == CLIENT_REQUEST_ID);
+    if call.http_method != HttpMethod::Get && !documented {
+        call.parameters.push(RestCallParameter {
+            name: CLIENT_REQUEST_ID.to_string(),
+            located_in: LocatedIn::Header,
+            type_name: CLIENT_REQUEST_ID.to_string(),
+            description: "Client specified RequestID to be sent with request.".to_string(),
+            default: None,
+            required: false,
+            collection: None,
+        });
+    }
+    call
+}
+
 /// Generate the code where we're passing parameter to the rest API
 fn gen_header_params(call: &RestCall) -> Result<TokenStream> {
     call.parameters
         .iter()
-        .filter(|p| p.located_in.is_header())
-        .map(|p| gen_header_param(&p.name, &p.description))
+        .filter(|p| p.located_in.is_header() && !is_client_header(p))
+        .map(gen_header_param)
         .collect()
 }
 
-/// Generate the code where we're passing parameter to the rest API
-fn gen_query_params(call: &RestCall) -> Result<TokenStream> {
-    let params = call
+/// Generate the code that builds up the list of query parameters to send
+fn gen_query_params(call: &RestCall) -> TokenStream {
+    let params: Vec<TokenStream> = call
         .parameters
         .iter()
         .filter(|p| p.located_in.is_query())
-        .map(|p| gen_query_param(&p.name))
-        .collect::<Result<Vec<TokenStream>>>()?;
-    Ok(quote! { [#(#params),*] })
+        .map(gen_query_param)
+        .collect();
+    if params.is_empty() {
+        quote! { let query: Vec<(&str, String)> = Vec::new(); }
+    } else {
+        quote! {
+            let mut query: Vec<(&str, String)> = Vec::new();
+            #(#params)*
+        }
+    }
 }
 
-/// Generates code that passes a parameter via an http get param
-fn gen_query_param(name: &str) -> Result<TokenStream> {
+/// Generates code that adds a parameter to the http get params.
+/// Optional parameters are left out when they're not given, so the server uses its default.
+/// Lists are joined into a single value, eg. `instruments=EUR_USD,USD_JPY`
+/// `DateTime`s are written in the format the client asks the server for
+fn gen_query_param(param: &RestCallParameter) -> TokenStream {
+    let name = &param.name;
     let value = field_name(name);
-    Ok(quote! { (#name, #value) })
+    let is_date_time = param.type_name == "DateTime";
+    let to_string = |value: TokenStream| {
+        if is_date_time {
+            quote! { #value.format(client.datetime_format) }
+        } else {
+            quote! { #value.to_string() }
+        }
+    };
+    match (param.collection, param.required) {
+        (Some(format), required) => {
+            let separator = format.separator();
+            let item_to_string = if is_date_time {
+                quote! { |item| item.format(client.datetime_format) }
+            } else {
+                quote! { ToString::to_string }
+            };
+            let push = quote! {
+                query.push((
+                    #name,
+                    #value
+                        .iter()
+                        .map(#item_to_string)
+                        .collect::<Vec<String>>()
+                        .join(#separator),
+                ));
+            };
+            if required {
+                push
+            } else {
+                quote! {
+                    if !#value.is_empty() {
+                        #push
+                    }
+                }
+            }
+        }
+        (None, true) => {
+            let value = to_string(quote! { #value });
+            quote! { query.push((#name, #value)); }
+        }
+        (None, false) => {
+            let value_string = to_string(quote! { #value });
+            quote! {
+                if let Some(#value) = &#value {
+                    query.push((#name, #value_string));
+                }
+            }
+        }
+    }
+}
+
+/// True if the caller always has to give this parameter.
+/// Path parameters are always required, even if the docs don't say so
+fn is_required(param: &RestCallParameter) -> bool {
+    param.required || param.located_in.is_path()
+}
+
+/// The type a parameter is passed to the call method as. eg. `Vec<InstrumentName>` for lists
+/// or `Option<DateTime>` for parameters that have a server side default
+fn gen_param_type(param: &RestCallParameter) -> TokenStream {
+    let type_name = Ident::new(&param.type_name, Span::call_site());
+    if param.collection.is_some() {
+        // An empty list is the same as not sending it
+        quote! { Vec<#type_name> }
+    } else if is_required(param) {
+        quote! { #type_name }
+    } else {
+        quote! { Option<#type_name> }
+    }
 }
 
 /// Generates code that passes a parameter in the path through reqwest
 fn gen_path_param(name: &str) -> TokenStream {
-    let to_replace = quote! {"{" + #name + "}"};
-    let with = snake_case(name);
+    let to_replace = format!("{{{name}}}");
+    let with = field_name(name);
     quote! {
-        let url = url.replace(#to_replace, #with);
+        let url = url.replace(#to_replace, &#with.to_string());
     }
 }
 
-fn gen_header_param(header_name: &str, description: &str) -> Result<TokenStream> {
+fn gen_header_param(param: &RestCallParameter) -> Result<TokenStream> {
+    let RestCallParameter {
+        name: header_name,
+        description,
+        ..
+    } = param;
     let comment: TokenStream = format!("// {description}")
         .parse()
         .map_err(|err| Error::new(format!("Couldn't turn this comment into tokens: Error '{err:#?}' -- comment contents: {description}")))?;
     let value = Ident::new(&snake_case(header_name), Span::call_site());
-    Ok(quote! {
-        #comment
-        .header(
-        #header_name,
-        #value
-    )})
+    // Header values are strings, eg. `ClientRequestID` derefs to a `str`
+    Ok(if is_required(param) {
+        quote! {
+            #comment
+            let request = request.header(#header_name, &*#value);
+        }
+    } else {
+        quote! {
+            #comment
+            let request = match #value {
+                Some(#value) => request.header(#header_name, &*#value),
+                None => request,
+            };
+        }
+    })
 }
 
-/// Generates a single method that performs a Rest API call for a certain endpoint
+/// Generates a single method that performs a Rest API call for a certain endpoint.
+/// All the parameters are in the call's request struct, eg. `CandlesRequest`
 fn gen_call(call: &RestCall, endpoint_name: &str) -> Result<TokenStream> {
-    let RestCall { path, .. } = call;
     let method_name = call
         .method_name()
         .attach_printable_lazy(|| format!("for endpoint {endpoint_name}"))?;
+    let request_struct_name = call.request_struct_name()?;
+    let result_type = gen_result_type(call)?;
     let doc_string = pretty_doc_string(&call.doc_string).change_context_lazy(Error::default)?;
+    Ok(quote!(
+        #(#doc_string)*
+        pub async fn #method_name(&self, request: #request_struct_name) -> #result_type {
+            request.send(self.client).await
+        }
+    ))
+}
+
+/// True if the call replies with a stream of json objects instead of a single one
+fn is_stream(call: &RestCall) -> Result<bool> {
+    let (good_response, _) = call.good_and_bad_responses()?;
+    Ok(matches!(good_response.schema, Schema::Stream(_)))
+}
+
+/// The type a call returns. The good response struct, or the error enum for the call. eg.
+/// `error_stack::Result<responses::cancel::Cancel, responses::cancel::Error>`
+///
+/// Streams return a `futures::Stream` of the objects the server sends, once connected
+fn gen_result_type(call: &RestCall) -> Result<TokenStream> {
+    let module = Ident::new(&call.method_name_as_string()?, Span::call_site());
+    let good_response = Ident::new(&call.response_struct_prefix()?, Span::call_site());
+    let good_response = quote! { responses::#module::#good_response };
+    let headers = quote! { responses::#module::Headers };
+    let error = quote! { responses::#module::Error };
+    Ok(if is_stream(call)? {
+        quote! {
+            error_stack::Result<
+                impl futures::Stream<Item = error_stack::Result<#good_response, #error>>,
+                #error,
+            >
+        }
+    } else {
+        quote! { error_stack::Result<crate::response::Response<#good_response, #headers>, #error> }
+    })
+}
+
+/// Generates the struct that holds all the parameters for a call, eg:
+///
+/// ```ignore
+/// let candles = CandlesRequest::new(instrument)
+///     .granularity(CandlestickGranularity::H1)
+///     .count(10)
+///     .send(&client)
+///     .await?;
+/// ```
+///
+/// The required parameters are passed to `new`, the optional ones have a setter each
+fn gen_request_struct(call: &RestCall) -> Result<TokenStream> {
+    let name = call.request_struct_name()?;
+    let doc_string = pretty_doc_string(&format!(
+        "The request for: {}",
+        call.doc_string.trim_end_matches('.')
+    ))
+    .change_context_lazy(Error::default)?;
+    let params: Vec<&RestCallParameter> = call
+        .parameters
+        .iter()
+        .filter(|p| !is_client_header(p))
+        .collect();
+    let has_body = call.request_body.is_some();
+    let body_struct_name = if has_body {
+        Some(call.body_struct_name()?)
+    } else {
+        None
+    };
+    let body_field = body_struct_name
+        .as_ref()
+        .map(|body_struct_name| quote! { body: #body_struct_name, });
+    let body_name = has_body.then(|| quote! { body, });
+
+    // The struct itself
+    let field_names: Vec<TokenStream> = params.iter().map(|p| field_name(&p.name)).collect();
+    let field_types: Vec<TokenStream> = params.iter().map(|p| gen_param_type(p)).collect();
+
+    // The constructor
+    let new_params = gen_params(call)?;
+    let new_fields: Vec<TokenStream> = params
+        .iter()
+        .map(|p| {
+            let name = field_name(&p.name);
+            if is_new_param(p) {
+                quote! { #name }
+            } else {
+                quote! { #name: Default::default() }
+            }
+        })
+        .collect();
+    let new_doc = pretty_doc_string(&gen_new_doc(&params)).change_context_lazy(Error::default)?;
+
+    let setters = params
+        .iter()
+        .filter(|p| !is_new_param(p))
+        .map(|p| gen_setter(p))
+        .collect::<Result<Vec<TokenStream>>>()?;
+
+    let send = gen_send(call)?;
+    // When all its parameters are optional, `new` makes the default request
+    let derive_default = new_params.is_empty().then(|| quote! { #[derive(Default)] });
+
+    Ok(quote! {
+        #(#doc_string)*
+        #derive_default
+        pub struct #name {
+            #(#field_names: #field_types,)*
+            #body_field
+        }
+
+        impl #name {
+            #(#new_doc)*
+            pub fn new(#new_params) -> #name {
+                #name {
+                    #(#new_fields,)*
+                    #body_name
+                }
+            }
+
+            #(#setters)*
+
+            #send
+        }
+    })
+}
+
+/// The doc string for a request struct's `new` method. It lists the required parameters
+fn gen_new_doc(params: &[&RestCallParameter]) -> String {
+    let required: Vec<String> = params
+        .iter()
+        .filter(|p| is_new_param(p))
+        .map(|p| {
+            let name = field_name(&p.name);
+            format!("* `{name}` - {}", strip_description_tags(&p.description))
+        })
+        .collect();
+    if required.is_empty() {
+        "Creates the request. All of its parameters are optional".to_string()
+    } else {
+        format!(
+            "Creates the request with the parameters it can't do without:\n\n{}",
+            required.join("\n")
+        )
+    }
+}
+
+/// Generates the setter for an optional parameter. Lists take anything that can be iterated over
+fn gen_setter(param: &RestCallParameter) -> Result<TokenStream> {
+    let name = field_name(&param.name);
+    let type_name = Ident::new(&param.type_name, Span::call_site());
+    let mut doc = strip_description_tags(&param.description).to_string();
+    if let Some(default) = &param.default {
+        doc.push_str(&format!(
+            "\n\nThe server uses `{default}` if this isn't set"
+        ));
+    }
+    let doc_string = pretty_doc_string(&doc).change_context_lazy(Error::default)?;
+    Ok(if param.collection.is_some() {
+        quote! {
+            #(#doc_string)*
+            pub fn #name(mut self, #name: impl IntoIterator<Item = #type_name>) -> Self {
+                self.#name = #name.into_iter().collect();
+                self
+            }
+        }
+    } else {
+        quote! {
+            #(#doc_string)*
+            pub fn #name(mut self, #name: #type_name) -> Self {
+                self.#name = Some(#name);
+                self
+            }
+        }
+    })
+}
+
+/// Generates the `send` method of a request struct, that performs the actual http request
+fn gen_send(call: &RestCall) -> Result<TokenStream> {
+    let RestCall { path, .. } = call;
     let http_method = match call.http_method {
-        HttpMethod::Get => quote! { self.client.get(self.client.start_get(url)).await? },
-        HttpMethod::Post => quote! { self.client.post(url) },
-        HttpMethod::Put => quote! { self.client.put(url) },
-        HttpMethod::Patch => quote! { self.client.patch(url) },
+        HttpMethod::Get => quote! { client.get(&url) },
+        HttpMethod::Post => quote! { client.post(&url) },
+        HttpMethod::Put => quote! { client.put(&url) },
+        HttpMethod::Patch => quote! { client.patch(&url) },
     };
-    let param_inputs = gen_params(call)?;
+    let field_names: Vec<TokenStream> = call
+        .parameters
+        .iter()
+        .filter(|p| !is_client_header(p))
+        .map(|p| field_name(&p.name))
+        .collect();
+    let body_name = call.request_body.is_some().then(|| quote! { body, });
     let path_params = gen_path_params(call);
-    let query_params = gen_query_params(call)?;
+    let query_params = gen_query_params(call);
     let header_params = gen_header_params(call)?;
-    Ok(quote!(
-        #(#doc_string)*
-        pub async fn #method_name(&self, #param_inputs) -> Result<()> {
+    let json_body = if call.request_body.is_some() {
+        quote! { .json(&crate::datetime_format::json_body(&body, client.datetime_format)) }
+    } else {
+        quote! {}
+    };
+    let result_type = gen_result_type(call)?;
+    // Streams are served from their own host
+    let (url, send) = if is_stream(call)? {
+        (
+            quote! { client.stream_url(url) },
+            quote! { client.stream(request).await },
+        )
+    } else {
+        (
+            quote! { client.url(url) },
+            quote! { client.send(request).await },
+        )
+    };
+    Ok(quote! {
+        /// Sends the request
+        pub async fn send(self, client: &Client) -> #result_type {
+            let Self { #(#field_names,)* #body_name } = self;
             let url = #path;
             #path_params
-            let url = self.client.url(url);
-            let query = #query_params;
-            let response = #http_method
+            let url = #url;
+            #query_params
+            let request = #http_method
+                .query(&query)
+                #json_body;
             #header_params
-            .query(&query)
-            .send()
-            .await?;
-            let status_code = response.status_code();
+            #send
+        }
+    })
+}
 
+/// True if the parameter is passed to the request struct's `new` method, rather than having a setter
+fn is_new_param(param: &RestCallParameter) -> bool {
+    is_required(param) && !is_client_header(param)
+}
 
-        }
-    ))
+/// Takes the "[default=50, maximum=500]" tags off the end of a parameter description.
+/// We already have them parsed out into [`RestCallParameter`]
+fn strip_description_tags(description: &str) -> &str {
+    let trimmed = description.trim_end();
+    match trimmed
+        .strip_suffix(']')
+        .and_then(|start| start.rsplit_once('['))
+    {
+        Some((description, _tags)) => description.trim_end(),
+        None => trimmed,
+    }
 }
 
-/// Just a comma delimited list of params
+/// The parameters for the request struct's `new` method, as a comma delimited list
 fn gen_params(call: &RestCall) -> Result<TokenStream> {
-    let params: Vec<TokenStream> = call
+    let mut params: Vec<TokenStream> = call
         .parameters
         .iter()
+        .filter(|p| is_new_param(p))
         .map(|p| {
             let name = field_name(&p.name);
-            let type_name = Ident::new(&pascal_case(&p.type_name), Span::call_site());
+            let type_name = gen_param_type(p);
             quote! { #name: #type_name }
         })
         .collect();
+    if call.request_body.is_some() {
+        let body_struct_name = call.body_struct_name()?;
+        params.push(quote! { body: #body_struct_name });
+    }
     Ok(quote! {#(#params),*})
 }
 
+/// Generates the struct that a call sends as its json body, if it has one
+fn gen_request_body(call: &RestCall) -> Result<TokenStream> {
+    let Some(schema) = &call.request_body else {
+        return Ok(quote! {});
+    };
+    let Schema::Struct(body) = schema else {
+        bail!("Expected the request body to be a struct, but got: {schema:#?}");
+    };
+    let name = call.body_struct_name()?;
+    let doc_string = pretty_doc_string(&format!(
+        "The json body for: {}",
+        call.doc_string.trim_end_matches('.')
+    ))
+    .change_context_lazy(Error::default)?;
+    let body = gen_struct(body, &name.to_string())
+        .attach_printable_lazy(|| format!("Generating the request body for {}", call.path))?;
+    Ok(quote! {
+        #(#doc_string)*
+        #body
+    })
+}
+
 impl<'a> Writer<'a> {
     #[instrument(skip(response_map))]
     pub fn gen_endpoint_responses(
         &self,
         base_path: &str,
         endpoint_name: &str,
-        response_map: &HashMap<String, ResponsesInfo>,
+        response_map: &BTreeMap<String, ResponsesInfo>,
     ) -> Result<TokenStream> {
         // A map of call names to all the info about their responses
         // Used to generate the response structs, and then again to call them
@@ -284,20 +642,41 @@ impl<'a> Writer<'a> {
         let Endpoint { name, calls } = endpoint;
         let struct_name = pascal_case(name);
         let struct_ident = Ident::new(&struct_name, Span::call_site());
+        let calls: Vec<RestCall> = calls.iter().map(with_client_request_id).collect();
+        let bodies = calls
+            .iter()
+            .map(gen_request_body)
+            .collect::<Result<Vec<TokenStream>>>()?;
+        let requests = calls
+            .iter()
+            .map(gen_request_struct)
+            .collect::<Result<Vec<TokenStream>>>()?;
+        // Only the json bodies need serde
+        let serde_use = calls
+            .iter()
+            .any(|call| call.request_body.is_some())
+            .then(|| {
+                quote!(
+                    use serde::{Serialize, Deserialize};
+                )
+            });
         let calls = calls
             .iter()
             .map(|call| gen_call(call, name))
             .collect::<Result<Vec<TokenStream>>>()?;
 
-        let uses = self.get_uses(endpoint);
-
         Ok(quote!(
-            use serde::{Serialize, Deserialize};
+            #serde_use
+            use crate::client::Client;
 
             pub mod responses;
 
-            struct #struct_ident<'a> {
-                client: &'a Client,
+            #(#bodies)*
+
+            #(#requests)*
+
+            pub struct #struct_ident<'a> {
+                pub(crate) client: &'a Client,
             }
 
             impl<'a> #struct_ident<'a> {
@@ -305,48 +684,19 @@ impl<'a> Writer<'a> {
             }
         ))
     }
-
-    /// Generates the token_stream for all the uses clauses for all the types used in this endpoint
-    fn get_uses(&self, endpoint: &Endpoint) -> TokenStream {
-        let type_names = get_parameter_type_names(endpoint);
-        let uses: HashSet<&Location<'_>> = type_names
-            .iter()
-            .flat_map(|tn| self.type_name_to_location(tn))
-            .collect();
-        let uses: Vec<TokenStream> = uses.iter().map(|l| l.as_uses()).collect();
-        quote! {
-            #(#uses)*
-        }
-    }
-}
-
-/// Given an `Endpoint` finds all the type names in the parameters that need to be imported
-fn get_parameter_type_names(endpoint: &Endpoint) -> Vec<String> {
-    let hash: HashSet<String> = endpoint
-        .calls
-        .iter()
-        .flat_map(|call| call.parameters.iter())
-        .map(|p| p.type_name.as_str())
-        // Remove type_names that don't need to be imported
-        .filter(|type_name| !["string", "List of"].contains(type_name))
-        .map(pascal_case)
-        .collect();
-    hash.into_iter().collect()
 }
 
 #[cfg(test)]
 mod unit_test {
-    use crate::{
-        gen_endpoint::{gen_responses::gen_response, ResponseNames},
-        util::Writer,
-        Error, Result,
-    };
+    use crate::{gen_endpoint::gen_responses::gen_response, Error, Result};
     use error_stack::ResultExt;
-    use itertools::Itertools;
+    use indoc::indoc;
     use model::{
         definition_docs::{Field, Schema, Struct},
-        endpoint_docs::{Response, ResponseHeader},
-        Content, Endpoint,
+        endpoint_docs::{
+            CollectionFormat, Endpoints, HttpMethod, LocatedIn, Response, ResponseHeader, RestCall,
+            RestCallParameter,
+        },
     };
     use pretty_assertions::assert_eq;
     use utils::stream_to_string;
@@ -372,68 +722,362 @@ mod unit_test {
             }),
         };
         let prefix = "MyCall";
-        let type_name = response.type_name(prefix);
-        let ts = gen_response(&type_name, &response)?;
+        let type_name = format!("{prefix}{}", response.code);
+        let ts = gen_response(&type_name, &response, &[])?;
         let s = stream_to_string(&ts).change_context_lazy(Error::default)?;
         assert_eq!(
             s,
             r#"/// Pricing information has been successfully provided.
-#[derive(Serialize, Deserialize)]
-#[serde_inline_default]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MyCall200 {
     /// The latest candle sticks.
-    latest_candles: Vec<CandlestickResponse>,
+    #[serde(rename = "latestCandles")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub latest_candles: Vec<CandlestickResponse>,
 }
-impl Default for MyCall200 {
-    fn default() -> Self {
-        Self {
-            latest_candles: Default::default(),
+"#
+        );
+        Ok(())
+    }
+
+    /// A good response with no fields, for calls where we don't care what comes back
+    fn empty_response(code: u16) -> Response {
+        Response {
+            code,
+            description: String::new(),
+            headers: Vec::new(),
+            schema: Schema::Struct(Struct { fields: Vec::new() }),
         }
     }
-}
-"#
+
+    fn post_orders_call() -> RestCall {
+        RestCall {
+            endpoint: Endpoints::Order,
+            http_method: HttpMethod::Post,
+            path: "/v3/accounts/{accountID}/orders".to_string(),
+            doc_string: "Create an Order for an Account".to_string(),
+            parameters: vec![RestCallParameter {
+                name: "accountID".to_string(),
+                located_in: LocatedIn::Path,
+                type_name: "AccountID".to_string(),
+                description: "Account Identifier [required]".to_string(),
+                default: None,
+                required: true,
+                collection: None,
+            }],
+            request_body: Some(Schema::Struct(Struct {
+                fields: vec![Field {
+                    name: "order".to_string(),
+                    type_name: "OrderRequest".to_string(),
+                    doc_string: "Specification of the Order to create".to_string(),
+                    is_array: false,
+                    default: None,
+                    required: false,
+                }],
+            })),
+            responses: vec![empty_response(201)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_gen_request_body() -> Result<()> {
+        let call = post_orders_call();
+        let tokens = super::gen_request_body(&call)?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                /// The json body for: Create an Order for an Account
+                #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+                pub struct PostOrdersBody {
+                    /// Specification of the Order to create
+                    #[serde(default, skip_serializing_if = "Option::is_none")]
+                    pub order: Option<OrderRequest>,
+                }
+            "#}
         );
+        // Calls without a body don't generate anything
+        let call = RestCall {
+            request_body: None,
+            ..post_orders_call()
+        };
+        assert!(super::gen_request_body(&call)?.is_empty());
         Ok(())
     }
 
-    fn load_contents() -> Vec<Content> {
-        let yaml = std::fs::read_to_string("../content.yaml").expect("Opening content.yaml");
-        serde_yaml::from_str(&yaml).expect("Reading in content.yaml")
+    #[test]
+    fn test_gen_request_struct_with_body() -> Result<()> {
+        let call = post_orders_call();
+        let tokens = super::gen_request_struct(&call)?;
+        let code = tokens.to_string();
+        assert!(
+            code.contains("pub fn new (account_id : AccountID , body : PostOrdersBody)"),
+            "{code}"
+        );
+        // The body's DateTimes are sent in the format the client asks the server for
+        assert!(
+            code.contains(
+                ". json (& crate :: datetime_format :: json_body (& body , client . datetime_format))"
+            ),
+            "{code}"
+        );
+        Ok(())
     }
 
-    fn get_endpoint<'a>(contents: &'a [Content], endpoint_name: &str) -> &'a Endpoint {
-        contents
-            .iter()
-            .flat_map(Content::as_endpoint)
-            .find(|ep| ep.name == endpoint_name)
-            .expect("Couldn't find account endpoint in parsed content")
+    #[test]
+    fn test_gen_request_struct() -> Result<()> {
+        let call = RestCall {
+            parameters: list_orders_call()
+                .parameters
+                .into_iter()
+                .filter(|p| {
+                    ["Authorization", "accountID", "ids", "count"].contains(&p.name.as_str())
+                })
+                .collect(),
+            ..list_orders_call()
+        };
+        let tokens = super::gen_request_struct(&call)?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                /// The request for: Get a list of Orders for an Account
+                pub struct OrdersRequest {
+                    account_id: AccountID,
+                    ids: Vec<OrderID>,
+                    count: Option<i64>,
+                }
+                impl OrdersRequest {
+                    /// Creates the request with the parameters it can't do without:
+                    ///
+                    /// * `account_id` - Account Identifier
+                    pub fn new(account_id: AccountID) -> OrdersRequest {
+                        OrdersRequest {
+                            account_id,
+                            ids: Default::default(),
+                            count: Default::default(),
+                        }
+                    }
+                    /// List of Order IDs to retrieve
+                    pub fn ids(mut self, ids: impl IntoIterator<Item = OrderID>) -> Self {
+                        self.ids = ids.into_iter().collect();
+                        self
+                    }
+                    /// The maximum number of Orders to return
+                    ///
+                    /// The server uses `50` if this isn't set
+                    pub fn count(mut self, count: i64) -> Self {
+                        self.count = Some(count);
+                        self
+                    }
+                    /// Sends the request
+                    pub async fn send(
+                        self,
+                        client: &Client,
+                    ) -> error_stack::Result<
+                        crate::response::Response<responses::orders::Orders, responses::orders::Headers>,
+                        responses::orders::Error,
+                    > {
+                        let Self { account_id, ids, count } = self;
+                        let url = "/v3/accounts/{accountID}/orders";
+                        let url = url.replace("{accountID}", &account_id.to_string());
+                        let url = client.url(url);
+                        let mut query: Vec<(&str, String)> = Vec::new();
+                        if !ids.is_empty() {
+                            query
+                                .push((
+                                    "ids",
+                                    ids
+                                        .iter()
+                                        .map(ToString::to_string)
+                                        .collect::<Vec<String>>()
+                                        .join(","),
+                                ));
+                        }
+                        if let Some(count) = &count {
+                            query.push(("count", count.to_string()));
+                        }
+                        let request = client.get(&url).query(&query);
+                        client.send(request).await
+                    }
+                }
+            "#}
+        );
+        Ok(())
     }
 
     #[test]
-    fn test_get_parameter_type_names() {
-        let contents = load_contents();
-        let account_ep = get_endpoint(&contents, "account");
-        let mut types = super::get_parameter_type_names(account_ep);
-        let mut expected = vec!["TransactionID", "AcceptDatetimeFormat", "AccountID"];
-        types.sort();
-        expected.sort();
-        assert_eq!(expected, types);
+    fn test_gen_call() -> Result<()> {
+        let call = list_orders_call();
+        let tokens = super::gen_call(&call, "order")?;
+        let code = stream_to_string(&quote::quote! { impl Order { #tokens } })
+            .change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                impl Order {
+                    /// Get a list of Orders for an Account
+                    pub async fn orders(
+                        &self,
+                        request: OrdersRequest,
+                    ) -> error_stack::Result<
+                        crate::response::Response<responses::orders::Orders, responses::orders::Headers>,
+                        responses::orders::Error,
+                    > {
+                        request.send(self.client).await
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    fn list_orders_call() -> RestCall {
+        let param = |name: &str, located_in, type_name: &str| RestCallParameter {
+            name: name.to_string(),
+            located_in,
+            type_name: type_name.to_string(),
+            description: String::new(),
+            default: None,
+            required: false,
+            collection: None,
+        };
+        RestCall {
+            endpoint: Endpoints::Order,
+            http_method: HttpMethod::Get,
+            path: "/v3/accounts/{accountID}/orders".to_string(),
+            doc_string: "Get a list of Orders for an Account".to_string(),
+            parameters: vec![
+                RestCallParameter {
+                    required: true,
+                    ..param("Authorization", LocatedIn::Header, "String")
+                },
+                param("ClientRequestID", LocatedIn::Header, "ClientRequestID"),
+                RestCallParameter {
+                    required: true,
+                    ..param("Request-Only-Header", LocatedIn::Header, "String")
+                },
+                RestCallParameter {
+                    description: "Account Identifier [required]".to_string(),
+                    ..param("accountID", LocatedIn::Path, "AccountID")
+                },
+                RestCallParameter {
+                    description: "List of Order IDs to retrieve".to_string(),
+                    collection: Some(CollectionFormat::Csv),
+                    ..param("ids", LocatedIn::Query, "OrderID")
+                },
+                RestCallParameter {
+                    description: "The maximum number of Orders to return [default=50, maximum=500]"
+                        .to_string(),
+                    default: Some("50".to_string()),
+                    ..param("count", LocatedIn::Query, "i64")
+                },
+                RestCallParameter {
+                    required: true,
+                    ..param("instrument", LocatedIn::Query, "InstrumentName")
+                },
+            ],
+            responses: vec![empty_response(200)],
+            ..Default::default()
+        }
     }
 
     #[test]
-    fn test_get_uses() {
-        tracing_subscriber::fmt().try_init().ok();
-        let contents = load_contents();
-        let account_ep = get_endpoint(&contents, "account");
-
-        let writer = Writer::new(&contents);
-        let tokens = writer.get_uses(&account_ep);
-        let as_text = stream_to_string(&tokens).unwrap();
-        let expected = "use crate::definitions::account::AccountId;
-use crate::definitions::transaction::TransactionId;
-use crate::definitions::primitives::AcceptDatetimeFormat;";
-        let got_lines: Vec<&str> = as_text.lines().sorted().collect();
-        let expected_lines: Vec<&str> = expected.lines().sorted().collect();
-        assert_eq!(expected_lines, got_lines);
+    fn test_gen_params() -> Result<()> {
+        let call = list_orders_call();
+        let code = super::gen_params(&call)?.to_string();
+        // Authorization is set by the Client, and the optional parameters have setters
+        assert_eq!(
+            "request_only_header : String , account_id : AccountID , instrument : InstrumentName",
+            code
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_query_params() -> Result<()> {
+        let call = list_orders_call();
+        let tokens = super::gen_query_params(&call);
+        let code = stream_to_string(&quote::quote! { fn f() { #tokens } })
+            .change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                fn f() {
+                    let mut query: Vec<(&str, String)> = Vec::new();
+                    if !ids.is_empty() {
+                        query
+                            .push((
+                                "ids",
+                                ids.iter().map(ToString::to_string).collect::<Vec<String>>().join(","),
+                            ));
+                    }
+                    if let Some(count) = &count {
+                        query.push(("count", count.to_string()));
+                    }
+                    query.push(("instrument", instrument.to_string()));
+                }
+            "#}
+        );
+        // DateTimes are written in the format the client asks the server for
+        let mut since = list_orders_call()
+            .parameters
+            .into_iter()
+            .find(|p| p.name == "count")
+            .unwrap();
+        since.name = "since".to_string();
+        since.type_name = "DateTime".to_string();
+        let tokens = super::gen_query_param(&since);
+        assert_eq!(
+            "if let Some (since) = & since { query . push ((\"since\" , since . format (client . datetime_format))) ; }",
+            tokens.to_string()
+        );
+        // Calls without query params still send an empty list
+        let call = post_orders_call();
+        assert_eq!(
+            "let query : Vec < (& str , String) > = Vec :: new () ;",
+            super::gen_query_params(&call).to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_client_request_id() {
+        let call = super::with_client_request_id(&post_orders_call());
+        let added: Vec<&RestCallParameter> = call
+            .parameters
+            .iter()
+            .filter(|p| p.name == "ClientRequestID")
+            .collect();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].located_in, LocatedIn::Header);
+        assert!(!added[0].required);
+        // It's not added twice, nor to GETs, which are always safe to send again
+        let again = super::with_client_request_id(&call);
+        assert_eq!(again.parameters.len(), call.parameters.len());
+        let get = RestCall {
+            parameters: vec![],
+            ..list_orders_call()
+        };
+        assert!(super::with_client_request_id(&get).parameters.is_empty());
+    }
+
+    #[test]
+    fn test_gen_header_params() -> Result<()> {
+        let call = list_orders_call();
+        let code = super::gen_header_params(&call)?.to_string();
+        assert!(!code.contains("Authorization"), "{code}");
+        assert!(
+            code.contains(
+                r#"let request = request . header ("Request-Only-Header" , & * request_only_header) ;"#
+            ),
+            "{code}"
+        );
+        assert!(
+            code.contains(r#"let request = match client_request_id { Some (client_request_id) => request . header ("ClientRequestID" , & * client_request_id) , None => request , } ;"#),
+            "{code}"
+        );
+        Ok(())
     }
 }
@@ -1,93 +1,243 @@
-use crate::{gen_definition::gen_struct, Error, Result};
+use crate::{bail, gen_definition::gen_struct, util::snake_case_name, Error, Result};
 use error_stack::ResultExt;
+use itertools::Itertools;
 use model::{
-    definition_docs::{Schema, Stream},
-    endpoint_docs::Response,
+    definition_docs::{Schema, Stream, Value},
+    endpoint_docs::{Response, ResponseHeader},
+    Definition,
 };
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 use tracing::instrument;
 use utils::pretty_doc_string;
 
-/// Generates all the possible responses for a particular API call endpoint
-#[instrument(skip(good_response, bad_responses))]
+/// Generates all the possible responses for a particular API call endpoint. `definitions` are
+/// all the definitions, used to find what each object in a stream is tagged with
+#[instrument(skip(good_response, bad_responses, definitions))]
 pub fn gen_responses_for_call(
     struct_prefix: &str,
     good_response: &Response,
     bad_responses: &[&Response],
+    other_responses: &[u16],
+    definitions: &[&Definition],
 ) -> Result<TokenStream> {
     let span = tracing::Span::current();
+    let good_response_headers = good_response.headers.clone();
+    let good_response_schema = good_response.schema.clone();
     // Get the good response (always 200 or 201)
-    let good_response = gen_response(struct_prefix, good_response)?;
-    let bad_responses = if !bad_responses.is_empty() {
-        // Bad response names
-        let bad_response_names: Vec<String> = bad_responses
-            .iter()
-            .map(|r| format!("{struct_prefix}{}", r.code))
-            .collect();
-        // Generate the structs
-        let bad_response_structs = bad_response_names
-            .iter()
-            .zip(bad_responses.iter().cloned())
-            .map(|(name, r)| gen_response(name, r))
-            .collect::<Result<Vec<TokenStream>>>()
-            .attach_printable_lazy(|| format!("Generating bad responses: {span:#?}"))?;
-        let structs = quote! { #(#bad_response_structs)* };
-
-        // Generate the Error enum
-        let variant_names = bad_response_names
-            .iter()
-            .zip(bad_responses.iter())
-            .map(|(n, r)| {
-                let code = format!("E{}", &r.code);
-                let code = Ident::new(&code, Span::call_site());
-                let struct_name = Ident::new(n, Span::call_site());
-                quote! {#code(#struct_name)}
-            });
-
-        let error = quote! {
-            #[derive(Debug)]
-            pub enum Error {
-                #(#variant_names),*
+    let good_response = gen_response(struct_prefix, good_response, definitions)?;
+    // Bad response names
+    let bad_response_names: Vec<String> = bad_responses
+        .iter()
+        .map(|r| format!("{struct_prefix}{}", r.code))
+        .collect();
+    // Generate the structs
+    let bad_response_structs = bad_response_names
+        .iter()
+        .zip(bad_responses.iter().cloned())
+        .map(|(name, r)| gen_response(name, r, definitions))
+        .collect::<Result<Vec<TokenStream>>>()
+        .attach_printable_lazy(|| format!("Generating bad responses: {span:#?}"))?;
+    let error = gen_call_error(&bad_response_names, bad_responses, other_responses)?;
+    let headers = gen_headers(&good_response_headers)?;
+    let body = gen_response_body(struct_prefix, &good_response_schema);
+    // A stream is parsed a line at a time rather than derived, see `gen_response_stream`
+    let serde_use = if matches!(good_response_schema, Schema::Stream(_)) && bad_responses.is_empty()
+    {
+        quote! { use serde::Serialize; }
+    } else {
+        quote! { use serde::{Serialize, Deserialize}; }
+    };
+
+    Ok(quote! {
+        #serde_use
+
+        #good_response
+
+        #body
+
+        #headers
+
+        #(#bad_response_structs)*
+
+        #error
+    })
+}
+
+/// Generates the `Error` enum for a call. It has a variant for every status code the call is
+/// documented to return, holding the parsed body of the reply, eg. `E404(Cancel404)`.
+///
+/// The codes in `other_responses` don't have their own documented body, so they hold the
+/// standard error body that oanda sends
+fn gen_call_error(
+    bad_response_names: &[String],
+    bad_responses: &[&Response],
+    other_responses: &[u16],
+) -> Result<TokenStream> {
+    let mut variants = Vec::new();
+    let mut parsers = Vec::new();
+    for (name, response) in bad_response_names.iter().zip(bad_responses) {
+        let code = response.code;
+        let variant = Ident::new(&format!("E{code}"), Span::call_site());
+        let struct_name = Ident::new(name, Span::call_site());
+        let doc_string =
+            pretty_doc_string(&response.description).change_context_lazy(Error::default)?;
+        let message = format!("{code}: {}", response.description)
+            .replace('{', "{{")
+            .replace('}', "}}");
+        variants.push(quote! {
+            #(#doc_string)*
+            #[error(#message)]
+            #variant(Box<#struct_name>),
+        });
+        let code = Literal::u16_unsuffixed(code);
+        parsers.push(quote! {
+            #code => Error::#variant(crate::error::from_json(body.as_bytes())?),
+        });
+    }
+    for code in other_responses
+        .iter()
+        .filter(|code| !bad_responses.iter().any(|r| r.code == **code))
+    {
+        let variant = Ident::new(&format!("E{code}"), Span::call_site());
+        let message = format!("HTTP status {code}: {{}}");
+        let doc_string = pretty_doc_string(&format!("The server replied with HTTP status {code}"))
+            .change_context_lazy(Error::default)?;
+        variants.push(quote! {
+            #(#doc_string)*
+            #[error(#message, .0.error_message)]
+            #variant(crate::error::ErrorBody),
+        });
+        let code = Literal::u16_unsuffixed(*code);
+        parsers.push(quote! {
+            #code => Error::#variant(crate::error::from_json(body.as_bytes())?),
+        });
+    }
+    Ok(quote! {
+        /// All the ways this call can fail
+        #[derive(Debug, thiserror::Error)]
+        pub enum Error {
+            #(#variants)*
+            /// The server replied with a status code that isn't documented for this call
+            #[error("Unexpected HTTP status {0}")]
+            Unexpected(u16),
+            /// The request couldn't be sent, or the reply couldn't be read
+            #[error("Unable to make the request")]
+            Request,
+            /// The reply isn't what the docs say this call sends
+            #[error("Unable to parse the reply")]
+            Decode,
+        }
+
+        impl crate::client::CallError for Error {
+            fn from_response(status: u16, body: &str) -> std::result::Result<Self, crate::error::JsonError> {
+                Ok(match status {
+                    #(#parsers)*
+                    _ => Error::Unexpected(status),
+                })
             }
-        };
 
-        quote! {
-            use serde::{Serialize, Deserialize};
+            fn request() -> Self {
+                Error::Request
+            }
 
-            #structs
+            fn decode() -> Self {
+                Error::Decode
+            }
+        }
+    })
+}
 
-            #error
+/// Generates the `crate::response::ResponseBody` impl for a call's successful reply, that hands
+/// its `lastTransactionID` to the `crate::response::Response`. Streams aren't read into one
+fn gen_response_body(struct_name: &str, schema: &Schema) -> Option<TokenStream> {
+    let Schema::Struct(r#struct) = schema else {
+        return None;
+    };
+    let struct_name = Ident::new(struct_name, Span::call_site());
+    let field = r#struct
+        .fields
+        .iter()
+        .find(|field| field.name == "lastTransactionID" && !field.is_array);
+    let last_transaction_id = match field {
+        // See `gen_struct` for which fields are optional
+        Some(field) if !field.required && field.default.is_none() => {
+            quote! { self.last_transaction_id.clone() }
         }
-    } else {
-        quote!()
+        Some(_) => quote! { Some(self.last_transaction_id.clone()) },
+        None => quote! { None },
     };
+    Some(quote! {
+        impl crate::response::ResponseBody for #struct_name {
+            fn last_transaction_id(&self) -> Option<crate::definitions::transaction_id::TransactionID> {
+                #last_transaction_id
+            }
+        }
+    })
+}
 
-    // let enum_contents: Vec<TokenStream> = variant_names
-    //     .zip(bad_responses.into_iter())
-    //     .map(|(enum_name, contents)| {
-    //         quote!(
-    //             #enum_name(#contents)
-    //         )
-    //     })
-    //     .collect();
+/// The type of a header's field. The ones that hold a definition get its type, eg. "RequestID",
+/// and the rest are text, eg. the "Location" url
+fn header_type(name: &str) -> TokenStream {
+    match name {
+        "RequestID" => quote! { crate::definitions::request_id::RequestID },
+        _ => quote! { String },
+    }
+}
 
+/// Generates the `Headers` struct for a call: the headers documented for its successful reply,
+/// eg. `request_id` for "RequestID". They're read into a `crate::response::Response`
+fn gen_headers(headers: &[ResponseHeader]) -> Result<TokenStream> {
+    let mut fields = Vec::new();
+    let mut readers = Vec::new();
+    for ResponseHeader { name, description } in headers {
+        let field = Ident::new(&snake_case_name(name), Span::call_site());
+        let doc_string = pretty_doc_string(description).change_context_lazy(Error::default)?;
+        let type_name = header_type(name);
+        fields.push(quote! {
+            #(#doc_string)*
+            pub #field: Option<#type_name>,
+        });
+        readers.push(quote! {
+            #field: crate::response::header(headers, #name),
+        });
+    }
+    // Only name the map when there's something to read from it
+    let headers_arg = if readers.is_empty() {
+        quote! { _headers }
+    } else {
+        quote! { headers }
+    };
     Ok(quote! {
-        #good_response
+        /// The headers documented for a successful reply
+        #[derive(Debug, Clone, Default)]
+        pub struct Headers {
+            #(#fields)*
+        }
 
-        #bad_responses
+        impl crate::response::ResponseHeaders for Headers {
+            fn from_headers(#headers_arg: &reqwest::header::HeaderMap) -> Self {
+                Headers {
+                    #(#readers)*
+                }
+            }
+        }
     })
 }
 
 /// Generates the type / struct that this response will return, including the docstring
-#[instrument(skip(response))]
-pub fn gen_response(struct_prefix: &str, response: &Response) -> Result<TokenStream> {
+#[instrument(skip(response, definitions))]
+pub fn gen_response(
+    struct_prefix: &str,
+    response: &Response,
+    definitions: &[&Definition],
+) -> Result<TokenStream> {
     // let ident = Ident::new(&name, Span::call_site());
     let doc_string =
         pretty_doc_string(&response.description).change_context_lazy(Error::default)?;
     let schema = match &response.schema {
         Schema::Struct(r#struct) => gen_struct(r#struct, struct_prefix)?,
-        Schema::Stream(stream) => gen_response_stream(stream, struct_prefix)?,
+        Schema::Stream(stream) => gen_response_stream(stream, struct_prefix, definitions)?,
     };
     // Make
     Ok(quote! {
@@ -96,9 +246,319 @@ pub fn gen_response(struct_prefix: &str, response: &Response) -> Result<TokenStr
     })
 }
 
-fn gen_response_stream(_stream: &Stream, _name: &str) -> Result<TokenStream> {
-    // TODO: Make this work
-    Ok(quote!(
-        struct Stream();
-    ))
+/// Generates the enum of objects that a streaming response can send, one per line, eg:
+///
+/// ```ignore
+/// pub enum Stream {
+///     ClientPrice(ClientPrice),
+///     PricingHeartbeat(PricingHeartbeat),
+/// }
+/// ```
+///
+/// Each object says what it is in its `type` field, which its definition gives a default, eg.
+/// "PRICE" for a `ClientPrice` and "HEARTBEAT" for a `PricingHeartbeat`. A line is parsed
+/// straight into the object with its tag, see `crate::stream::StreamItem`. One object may not
+/// have a tag of its own, eg. a `Transaction`, which is one of many types. It gets any other line
+fn gen_response_stream(
+    stream: &Stream,
+    name: &str,
+    definitions: &[&Definition],
+) -> Result<TokenStream> {
+    let name = Ident::new(name, Span::call_site());
+    let objects: Vec<&str> = stream.objects.iter().map(String::as_str).sorted().collect();
+    let tagged: Vec<(&str, String)> = objects
+        .iter()
+        .filter_map(|object| Some((*object, stream_tag(object, definitions)?)))
+        .collect();
+    let untagged: Vec<&str> = objects
+        .iter()
+        .copied()
+        .filter(|object| !tagged.iter().any(|(tagged, _)| tagged == object))
+        .collect();
+    let tags: Vec<&str> = tagged.iter().map(|(_, tag)| tag.as_str()).collect();
+    if untagged.len() > 1 || !tags.iter().all_unique() {
+        bail!("Can't tell apart the objects in the stream by their type: {stream:?}")
+    }
+    let variants: Vec<Ident> = objects
+        .iter()
+        .map(|object| Ident::new(object, Span::call_site()))
+        .collect();
+    let parsers = tagged.iter().map(|(object, tag)| {
+        let variant = Ident::new(object, Span::call_site());
+        quote! {
+            Some(#tag) => crate::error::from_json(line).map(#name::#variant),
+        }
+    });
+    let otherwise = match untagged.first() {
+        Some(object) => {
+            let variant = Ident::new(object, Span::call_site());
+            quote! { _ => crate::error::from_json(line).map(#name::#variant), }
+        }
+        None => quote! { _ => Err(crate::stream::unknown_type(tag, &[#(#tags),*])), },
+    };
+    Ok(quote! {
+        #[derive(Debug, Clone, Serialize)]
+        #[serde(untagged)]
+        pub enum #name {
+            #(#variants(#variants),)*
+        }
+
+        impl crate::stream::StreamItem for #name {
+            fn from_line(tag: Option<&str>, line: &[u8]) -> std::result::Result<Self, crate::error::JsonError> {
+                match tag {
+                    #(#parsers)*
+                    #otherwise
+                }
+            }
+        }
+    })
+}
+
+/// The `type` that a stream's object always has, eg. "HEARTBEAT" for a `PricingHeartbeat`. It's
+/// the default of the object's `type` field, which `resolve_type_names` has made
+/// `String::from("HEARTBEAT")`
+fn stream_tag(object: &str, definitions: &[&Definition]) -> Option<String> {
+    let Value::Struct(r#struct) = &definitions.iter().find(|d| d.name == object)?.value else {
+        return None;
+    };
+    let default = r#struct
+        .fields
+        .iter()
+        .find(|field| field.name == "type" && field.type_name == "String")?
+        .default
+        .as_deref()?;
+    let literal = default.strip_prefix("String::from(")?.strip_suffix(')')?;
+    syn::parse_str::<syn::LitStr>(literal)
+        .ok()
+        .map(|literal| literal.value())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::{Error, Result};
+    use error_stack::ResultExt;
+    use indoc::indoc;
+    use model::{
+        definition_docs::{Field, Schema, Stream, Struct, Value},
+        endpoint_docs::{Response, ResponseHeader},
+        Definition,
+    };
+    use pretty_assertions::assert_eq;
+    use utils::stream_to_string;
+
+    #[test]
+    fn test_gen_call_error() -> Result<()> {
+        let not_found = Response {
+            code: 404,
+            description: "The Account or Order specified does not exist.".to_string(),
+            headers: Vec::new(),
+            schema: Schema::Struct(Struct { fields: Vec::new() }),
+        };
+        // 404 is documented with its own body, so it's not treated as one of the "other" responses
+        let tokens = super::gen_call_error(&["Cancel404".to_string()], &[&not_found], &[401, 404])?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                /// All the ways this call can fail
+                #[derive(Debug, thiserror::Error)]
+                pub enum Error {
+                    /// The Account or Order specified does not exist.
+                    #[error("404: The Account or Order specified does not exist.")]
+                    E404(Box<Cancel404>),
+                    /// The server replied with HTTP status 401
+                    #[error("HTTP status 401: {}", .0.error_message)]
+                    E401(crate::error::ErrorBody),
+                    /// The server replied with a status code that isn't documented for this call
+                    #[error("Unexpected HTTP status {0}")]
+                    Unexpected(u16),
+                    /// The request couldn't be sent, or the reply couldn't be read
+                    #[error("Unable to make the request")]
+                    Request,
+                    /// The reply isn't what the docs say this call sends
+                    #[error("Unable to parse the reply")]
+                    Decode,
+                }
+                impl crate::client::CallError for Error {
+                    fn from_response(
+                        status: u16,
+                        body: &str,
+                    ) -> std::result::Result<Self, crate::error::JsonError> {
+                        Ok(
+                            match status {
+                                404 => Error::E404(crate::error::from_json(body.as_bytes())?),
+                                401 => Error::E401(crate::error::from_json(body.as_bytes())?),
+                                _ => Error::Unexpected(status),
+                            },
+                        )
+                    }
+                    fn request() -> Self {
+                        Error::Request
+                    }
+                    fn decode() -> Self {
+                        Error::Decode
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_headers() -> Result<()> {
+        let headers = [
+            ResponseHeader {
+                name: "Location".to_string(),
+                description: "A link to the Order that was just created".to_string(),
+            },
+            ResponseHeader {
+                name: "RequestID".to_string(),
+                description: "The unique identifier generated for the request".to_string(),
+            },
+        ];
+        let tokens = super::gen_headers(&headers)?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                /// The headers documented for a successful reply
+                #[derive(Debug, Clone, Default)]
+                pub struct Headers {
+                    /// A link to the Order that was just created
+                    pub location: Option<String>,
+                    /// The unique identifier generated for the request
+                    pub request_id: Option<crate::definitions::request_id::RequestID>,
+                }
+                impl crate::response::ResponseHeaders for Headers {
+                    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+                        Headers {
+                            location: crate::response::header(headers, "Location"),
+                            request_id: crate::response::header(headers, "RequestID"),
+                        }
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_response_body() -> Result<()> {
+        let field = |name: &str, required| Field {
+            name: name.to_string(),
+            type_name: "TransactionID".to_string(),
+            doc_string: String::new(),
+            is_array: false,
+            default: None,
+            required,
+        };
+        let schema = |fields| Schema::Struct(Struct { fields });
+        let code = |schema: &Schema| {
+            super::gen_response_body("Get", schema)
+                .map(|tokens| tokens.to_string())
+                .unwrap_or_default()
+        };
+        assert!(code(&schema(vec![field("lastTransactionID", false)]))
+            .contains("{ self . last_transaction_id . clone () }"));
+        assert!(code(&schema(vec![field("lastTransactionID", true)]))
+            .contains("{ Some (self . last_transaction_id . clone ()) }"));
+        assert!(code(&schema(vec![field("orderCreateTransaction", false)])).contains("{ None }"));
+        let stream = Schema::Stream(Stream {
+            objects: ["ClientPrice".to_string()].into(),
+        });
+        assert!(super::gen_response_body("Stream", &stream).is_none());
+        Ok(())
+    }
+
+    /// A definition of a stream's object, with a `type` field as `resolve_type_names` leaves it
+    fn stream_object(name: &str, tag: Option<&str>) -> Definition {
+        Definition {
+            name: name.to_string(),
+            doc_string: String::new(),
+            value: Value::Struct(Struct {
+                fields: vec![Field {
+                    name: "type".to_string(),
+                    type_name: "String".to_string(),
+                    doc_string: String::new(),
+                    is_array: false,
+                    default: tag.map(|tag| format!("String::from({tag:?})")),
+                    required: false,
+                }],
+            }),
+        }
+    }
+
+    fn stream(objects: [&str; 2]) -> Stream {
+        Stream {
+            objects: objects.into_iter().map(str::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn test_gen_response_stream() -> Result<()> {
+        let definitions = [
+            stream_object("ClientPrice", Some("PRICE")),
+            stream_object("PricingHeartbeat", Some("HEARTBEAT")),
+        ];
+        let definitions: Vec<&Definition> = definitions.iter().collect();
+        let stream = stream(["PricingHeartbeat", "ClientPrice"]);
+        let tokens = super::gen_response_stream(&stream, "Stream", &definitions)?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                #[derive(Debug, Clone, Serialize)]
+                #[serde(untagged)]
+                pub enum Stream {
+                    ClientPrice(ClientPrice),
+                    PricingHeartbeat(PricingHeartbeat),
+                }
+                impl crate::stream::StreamItem for Stream {
+                    fn from_line(
+                        tag: Option<&str>,
+                        line: &[u8],
+                    ) -> std::result::Result<Self, crate::error::JsonError> {
+                        match tag {
+                            Some("PRICE") => crate::error::from_json(line).map(Stream::ClientPrice),
+                            Some("HEARTBEAT") => {
+                                crate::error::from_json(line).map(Stream::PricingHeartbeat)
+                            }
+                            _ => Err(crate::stream::unknown_type(tag, &["PRICE", "HEARTBEAT"])),
+                        }
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_response_stream_untagged() -> Result<()> {
+        // A Transaction is one of many types, so its definition has no tag of its own
+        let definitions = [
+            stream_object("Transaction", None),
+            stream_object("TransactionHeartbeat", Some("HEARTBEAT")),
+        ];
+        let definitions: Vec<&Definition> = definitions.iter().collect();
+        let stream = stream(["TransactionHeartbeat", "Transaction"]);
+        let code = super::gen_response_stream(&stream, "Stream", &definitions)?.to_string();
+        assert!(
+            code.contains(r#"Some ("HEARTBEAT") => crate :: error :: from_json (line) . map (Stream :: TransactionHeartbeat) ,"#),
+            "{code}"
+        );
+        assert!(
+            code.contains(
+                "_ => crate :: error :: from_json (line) . map (Stream :: Transaction) ,"
+            ),
+            "{code}"
+        );
+        // Two objects without a tag can't be told apart
+        let both_untagged = [
+            stream_object("Transaction", None),
+            stream_object("TransactionHeartbeat", None),
+        ];
+        let definitions: Vec<&Definition> = both_untagged.iter().collect();
+        assert!(super::gen_response_stream(&stream, "Stream", &definitions).is_err());
+        Ok(())
+    }
 }
@@ -1,28 +1,424 @@
 //! Generates error.rs for oanda_v2
-use proc_macro2::TokenStream;
-use quote::quote;
-
-pub fn gen_error() -> TokenStream {
-    quote!(
-        use parse_display::Display;
-        use thiserror::Error as ThisError;
-        use reqwest::StatusCode;
+use crate::{Error, Result};
+use error_stack::ResultExt;
+use model::ErrorDefinition;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::Ident;
+use utils::pretty_doc_string;
+
+/// Generates the crate's `Error`. It's the cause under each call's own error type, so callers
+/// can match on what went wrong in the same way for every call. eg:
+///
+/// ```ignore
+/// match report.downcast_ref::<oanda_v2::Error>() {
+///     Some(oanda_v2::Error::RateLimit) => ...,
+///     Some(oanda_v2::Error::Rejected { reason, .. }) => ...,
+/// }
+/// ```
+///
+/// It also generates an `HttpErrorKind`, with a variant for each status code on OANDA's
+/// troubleshooting page, see [`gen_http_error_kind`]
+pub fn gen_error(errors: &[ErrorDefinition]) -> Result<TokenStream> {
+    let http_error_kind = gen_http_error_kind(errors)?;
+    Ok(quote!(
         use serde::Deserialize;
 
-        #[derive(Display, Debug, ThisError)]
-        #[display(style = "snake_case")]
+        #[derive(Debug, Default, thiserror::Error)]
         pub enum Error {
-            #[display("reqwest error: {0}")]
-            Reqwest(#[from] reqwest::Error),
-            #[display("Unexpected http error: {code} {body}")]
-            UnexpectedHttp { code: u16, body: String },
-            #[dispaly("Unable to parse json: {json} {err}")]
-            JsonParse {
-                err: serde_json::Error,
-                input: String,
+            /// Most errors come from this category
+            #[default]
+            #[error("general")]
+            General,
+            #[error("Message: {0}")]
+            Message(String),
+            /// The request couldn't be sent, or its reply couldn't be read
+            #[error("Unable to reach the server")]
+            Transport,
+            /// The server replied with a status code other than 2xx
+            #[error("HTTP status {status}: {}", body.error_message)]
+            Http { status: u16, body: ErrorBody },
+            /// The server replied that the transaction was rejected, eg. with a
+            /// `MarketOrderRejectTransaction`
+            #[error("Transaction rejected: {reason:?}")]
+            Rejected {
+                transaction: Box<Transaction>,
+                reason: Option<TransactionRejectReason>,
+            },
+            /// The reply wasn't the json we expected
+            #[error("Unable to parse the json from {url} at {path}, line {line} column {column}: {message}")]
+            Json {
+                /// The url of the call that sent the json
+                url: String,
+                /// Where in the json it went wrong, eg. `orders[2].price`
+                path: String,
+                line: usize,
+                column: usize,
+                message: String,
             },
+            /// The server replied with HTTP status 429 Too Many Requests
+            #[error("Too many requests")]
+            RateLimit,
+        }
+
+        impl Error {
+            pub fn new(msg: impl ToString) -> Error {
+                Error::Message(msg.to_string())
+            }
+
+            /// For when the json from `url` can't be parsed, see [`from_json`]
+            pub fn json(url: impl ToString, err: &JsonError) -> Error {
+                Error::Json {
+                    url: url.to_string(),
+                    path: err.path().to_string(),
+                    line: err.inner().line(),
+                    column: err.inner().column(),
+                    message: err.inner().to_string(),
+                }
+            }
+
+            /// Works out what went wrong from the reply to a request, with a status code other
+            /// than 2xx
+            pub fn from_response(status: u16, body: &str) -> Error {
+                if status == 429 {
+                    return Error::RateLimit;
+                }
+                let unparsed = || ErrorBody {
+                    error_code: None,
+                    error_message: body.to_string(),
+                };
+                let json = match serde_json::from_str::<serde_json::Value>(body) {
+                    Ok(json) => json,
+                    Err(_) => return Error::Http { status, body: unparsed() },
+                };
+                // eg. `orderRejectTransaction` when creating an Order fails
+                let rejected = json
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .find(|(key, _)| key.ends_with("RejectTransaction"))
+                    .and_then(|(_, transaction)| {
+                        let reason = transaction
+                            .get("rejectReason")
+                            .and_then(|reason| serde_json::from_value(reason.clone()).ok());
+                        serde_json::from_value(transaction.clone())
+                            .ok()
+                            .map(|transaction| (transaction, reason))
+                    });
+                if let Some((transaction, reason)) = rejected {
+                    return Error::Rejected {
+                        transaction: Box::new(transaction),
+                        reason,
+                    };
+                }
+                let body = serde_json::from_value(json).unwrap_or_else(|_| unparsed());
+                Error::Http { status, body }
+            }
         }
 
         pub type Result<T> = error_stack::Result<T, Error>;
-    )
+
+        /// Why some json couldn't be parsed, and the path to the value it failed at
+        pub type JsonError = serde_path_to_error::Error<serde_json::Error>;
+
+        /// Parses `json`, keeping the path to the value it fails at for an [`Error::Json`]
+        pub fn from_json<'a, T: Deserialize<'a>>(json: &'a [u8]) -> std::result::Result<T, JsonError> {
+            let mut deserializer = serde_json::Deserializer::from_slice(json);
+            let value = serde_path_to_error::deserialize(&mut deserializer)?;
+            // Nothing but whitespace may follow the value
+            deserializer
+                .end()
+                .map_err(|err| JsonError::new(serde_path_to_error::Track::new().path(), err))?;
+            Ok(value)
+        }
+
+        /// Parses `value` from inside another type's `Deserialize`, eg. one variant of a tagged
+        /// union. The outer path stops at `value`, so the path within it goes in the message
+        pub fn from_value<T: serde::de::DeserializeOwned, E: serde::de::Error>(
+            value: serde_json::Value,
+        ) -> std::result::Result<T, E> {
+            serde_path_to_error::deserialize(value).map_err(E::custom)
+        }
+
+        /// The json body the server sends with most errors
+        #[derive(Debug, Clone, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct ErrorBody {
+            pub error_code: Option<String>,
+            pub error_message: String,
+        }
+
+        #http_error_kind
+    ))
+}
+
+/// Generates `HttpErrorKind`, eg:
+///
+/// ```ignore
+/// pub enum HttpErrorKind {
+///     /// A “400 Bad Request” reponse may be returned from the v20 REST API when ...
+///     BadRequest,
+///     ...
+/// }
+/// ```
+///
+/// Each variant is documented with what the troubleshooting page says about it, and its
+/// `remedy()` gives the likely causes, so they can be shown to the user with a failed call
+fn gen_http_error_kind(errors: &[ErrorDefinition]) -> Result<TokenStream> {
+    let variants = errors
+        .iter()
+        .map(|error| {
+            let doc_string = pretty_doc_string(&doc_text(&error.documentation))
+                .change_context_lazy(|| Error::new(format!("Documenting HTTP {}", error.code)))?;
+            let variant = variant_name(error);
+            Ok(quote! {
+                #(#doc_string)*
+                #variant,
+            })
+        })
+        .collect::<Result<Vec<TokenStream>>>()?;
+    let from_status = errors.iter().map(|error| {
+        let code = Literal::u16_unsuffixed(error.code);
+        let variant = variant_name(error);
+        quote! { #code => Some(HttpErrorKind::#variant), }
+    });
+    let status = errors.iter().map(|error| {
+        let code = Literal::u16_unsuffixed(error.code);
+        let variant = variant_name(error);
+        quote! { HttpErrorKind::#variant => #code, }
+    });
+    let remedies = errors.iter().map(|error| {
+        let variant = variant_name(error);
+        let remedy = remedy(&error.documentation);
+        quote! { HttpErrorKind::#variant => #remedy, }
+    });
+    let names = errors.iter().map(|error| {
+        let variant = variant_name(error);
+        let name = status_name(error);
+        quote! { HttpErrorKind::#variant => #name, }
+    });
+    Ok(quote! {
+        /// The HTTP error statuses OANDA documents, and what usually causes them. The client
+        /// attaches one to the report of a call that fails with one of these statuses
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum HttpErrorKind {
+            #(#variants)*
+        }
+
+        impl HttpErrorKind {
+            /// The kind for an HTTP status code, if OANDA documents it
+            pub fn from_status(status: u16) -> Option<HttpErrorKind> {
+                match status {
+                    #(#from_status)*
+                    _ => None,
+                }
+            }
+
+            /// The HTTP status code, eg. 404
+            pub fn status(&self) -> u16 {
+                match self {
+                    #(#status)*
+                }
+            }
+
+            /// What usually causes this error, and how to fix it
+            pub fn remedy(&self) -> &'static str {
+                match self {
+                    #(#remedies)*
+                }
+            }
+        }
+
+        /// eg. "404 Not Found"
+        impl std::fmt::Display for HttpErrorKind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    #(#names)*
+                })
+            }
+        }
+    })
+}
+
+/// The status as the troubleshooting page names it, eg. "400 Bad Request" from
+/// `A “400 Bad Request” reponse may be returned ...`
+fn status_name(error: &ErrorDefinition) -> String {
+    error
+        .documentation
+        .split_once('“')
+        .and_then(|(_, rest)| rest.split_once('”'))
+        .map(|(name, _)| name.trim().to_string())
+        .unwrap_or_else(|| error.code.to_string())
+}
+
+/// The variant for an error, eg. `BadRequest` for "400 Bad Request". If the documentation
+/// doesn't name the status, it's `Status` and the code, eg. `Status416`
+fn variant_name(error: &ErrorDefinition) -> Ident {
+    let name = status_name(error);
+    let name = name.trim_start_matches(|c: char| c.is_ascii_digit() || c.is_whitespace());
+    if name.is_empty() {
+        format_ident!("Status{}", error.code)
+    } else {
+        format_ident!("{}", change_case::pascal_case(name))
+    }
+}
+
+/// The documentation as rustdoc. The example replies are marked as text, so they aren't run as
+/// doc tests
+fn doc_text(documentation: &str) -> String {
+    let mut in_code = false;
+    documentation
+        .trim()
+        .lines()
+        .map(|line| {
+            if line.trim() != "```" {
+                return line;
+            }
+            in_code = !in_code;
+            if in_code {
+                "```text"
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The reasons the documentation gives for the error, one per line, eg.
+/// "Malformed Request: One or more required parameters for the request is missing or is
+/// invalid.". Where it doesn't give any, it's the first paragraph
+fn remedy(documentation: &str) -> String {
+    let mut heading = None;
+    let mut reasons = Vec::new();
+    let mut lines = documentation.lines();
+    while let Some(line) = lines.next() {
+        if let Some(title) = line.strip_prefix("## ") {
+            heading = Some(title.trim());
+        } else if let Some(reason) = line.strip_prefix("**Reason:**") {
+            // The reason carries on until the next blank line
+            let reason = std::iter::once(reason)
+                .chain(lines.by_ref().take_while(|line| !line.trim().is_empty()))
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join(" ");
+            reasons.push(match heading {
+                Some(heading) => format!("{heading}: {reason}"),
+                None => reason,
+            });
+        }
+    }
+    if reasons.is_empty() {
+        let paragraph = documentation
+            .trim()
+            .split("\n\n")
+            .next()
+            .unwrap_or_default();
+        paragraph.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        reasons.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::ErrorDefinition;
+    use pretty_assertions::assert_eq;
+    use utils::stream_to_string;
+
+    fn errors() -> Vec<ErrorDefinition> {
+        vec![
+            ErrorDefinition {
+                code: 404,
+                documentation: indoc::indoc! {r#"
+                    A “404 Not Found” response may be returned from the v20 REST API when the client is attempting to refer to an entity that does not exist.
+
+                    ## NO_SUCH_TRADE
+
+                    If an incorrect trade ID is submitted the following error will be returned to the user.
+                    ```
+                    "errorMessage":"The trade ID specified does not exist"
+                    ```
+
+                    **Reason:** The trade id specified is not valid.
+
+
+                    ## NO_SUCH_ORDER
+
+                    **Reason:** The order id specified is not valid.
+                    It may no longer be in the recent order list.
+                "#}
+                .to_string(),
+            },
+            ErrorDefinition {
+                code: 405,
+                documentation: "The “405 Method Not Allowed” response may be returned when the client is accessing an endpoint using an incorrect\nHTTP method.".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_gen_error() {
+        let code = stream_to_string(&super::gen_error(&errors()).unwrap()).unwrap();
+        assert!(code.contains("pub enum Error {"), "{code}");
+        assert!(
+            code.contains(
+                "Rejected { transaction: Box<Transaction>, reason: Option<TransactionRejectReason> },"
+            ),
+            "{code}"
+        );
+        assert!(
+            code.contains("pub fn from_response(status: u16, body: &str) -> Error {"),
+            "{code}"
+        );
+        assert!(code.contains("pub enum HttpErrorKind {"), "{code}");
+        assert!(
+            code.contains("404 => Some(HttpErrorKind::NotFound),"),
+            "{code}"
+        );
+        assert!(
+            code.contains(r#"HttpErrorKind::MethodNotAllowed => "405 Method Not Allowed","#),
+            "{code}"
+        );
+        assert!(code.contains("/// ```text"), "{code}");
+    }
+
+    #[test]
+    fn test_remedy() {
+        let errors = errors();
+        assert_eq!(
+            "NO_SUCH_TRADE: The trade id specified is not valid.\n\
+            NO_SUCH_ORDER: The order id specified is not valid. It may no longer be in the recent order list.",
+            super::remedy(&errors[0].documentation)
+        );
+        assert_eq!(
+            "The “405 Method Not Allowed” response may be returned when the client is accessing an endpoint using an incorrect HTTP method.",
+            super::remedy(&errors[1].documentation)
+        );
+    }
+
+    #[test]
+    fn test_remedy_with_list() {
+        let documentation = indoc::indoc! {r#"
+            A “401 Unauthorized” reponse may be returned from the v20 REST API when the endpoint being accessed requires the client to be authenticated.
+
+            ## Insufficient Authorization
+
+            If an incorrect request is submitted the following error will be returned to the user.
+
+            ```
+            "errorMessage":"Insufficient authorization to perform request."
+            ```
+
+            **Reason:** The following information needs to be verified:
+            1. The URL provided to the curl command is correct. Click here to learn how to configure curl examples.
+            2. The authentication token is valid and has been added as a Bearer token in the HTTP Authorization header."#};
+        // The list is part of the reason
+        assert_eq!(
+            "Insufficient Authorization: The following information needs to be verified: \
+            1. The URL provided to the curl command is correct. Click here to learn how to configure curl examples. \
+            2. The authentication token is valid and has been added as a Bearer token in the HTTP Authorization header.",
+            super::remedy(documentation)
+        );
+    }
 }
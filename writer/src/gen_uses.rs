@@ -0,0 +1,347 @@
+//! Works out the `use` clauses a generated file needs, from the types and attribute macros it
+//! refers to
+use crate::{util::Location, Error, Result};
+use error_stack::Report;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+};
+use syn::{visit_mut::VisitMut, Attribute, File, Item, TypePath, UseTree};
+
+/// The attribute macros the generated code uses, and the crate each comes from
+const ATTRIBUTE_MACROS: [(&str, &str); 1] = [("serde_inline_default", "serde_inline_default")];
+
+/// Adds a `use` clause for each of our types that `file` refers to by its bare name. eg. a
+/// field of type `AccountID` gets us `use crate::definitions::account_id::AccountID;`
+///
+/// The attribute macros in [`ATTRIBUTE_MACROS`] are imported the same way. eg.
+/// `#[serde_inline_default]` gets us `use serde_inline_default::serde_inline_default;`
+///
+/// Where a `use` wouldn't work, the reference is replaced with the type's full path instead:
+///
+/// * The file declares something with the same name. eg. the `OrderBook` response holds an
+///   `OrderBook` definition
+/// * The name is declared in several places, so we take the one in `crate::definitions`
+///
+/// ## Parameters
+///
+/// * `file` - The generated code
+/// * `file_name` - Where it will be written, so it doesn't import its own types
+/// * `type_name_to_uses` - Everywhere each type is declared, see [`crate::util::gen_map`]
+pub fn gen_uses<'a>(
+    mut file: File,
+    file_name: &str,
+    type_name_to_uses: &HashMap<Cow<'a, str>, HashSet<Location<'a>>>,
+) -> Result<TokenStream> {
+    let mut visitor = TypeUses {
+        type_name_to_uses,
+        file_name,
+        declared: HashSet::new(),
+        imported: HashSet::new(),
+        uses: BTreeMap::new(),
+        ambiguous: Vec::new(),
+    };
+    for item in &file.items {
+        match item {
+            Item::Struct(item) => visitor.declared.insert(item.ident.to_string()),
+            Item::Enum(item) => visitor.declared.insert(item.ident.to_string()),
+            Item::Type(item) => visitor.declared.insert(item.ident.to_string()),
+            Item::Trait(item) => visitor.declared.insert(item.ident.to_string()),
+            Item::Use(item) => {
+                imported_names(&item.tree, &mut visitor.imported);
+                continue;
+            }
+            _ => continue,
+        };
+    }
+    visitor.visit_file_mut(&mut file);
+    if !visitor.ambiguous.is_empty() {
+        let report = visitor.ambiguous.into_iter().fold(
+            Report::new(Error::new(format!(
+                "Unable to work out which type to use in {file_name}"
+            ))),
+            |report, ambiguous| report.attach_printable(ambiguous),
+        );
+        return Err(report);
+    }
+    let uses = visitor.uses.values().map(Location::as_uses);
+    Ok(quote! {
+        #(#uses)*
+        #file
+    })
+}
+
+/// Collects the names a `use` clause brings into scope
+fn imported_names(tree: &UseTree, names: &mut HashSet<String>) {
+    match tree {
+        UseTree::Path(path) => imported_names(&path.tree, names),
+        UseTree::Name(name) => {
+            names.insert(name.ident.to_string());
+        }
+        UseTree::Rename(rename) => {
+            names.insert(rename.rename.to_string());
+        }
+        UseTree::Group(group) => group
+            .items
+            .iter()
+            .for_each(|tree| imported_names(tree, names)),
+        UseTree::Glob(_) => {}
+    }
+}
+
+struct TypeUses<'a, 'b> {
+    type_name_to_uses: &'b HashMap<Cow<'a, str>, HashSet<Location<'a>>>,
+    /// The file we're generating the uses for
+    file_name: &'b str,
+    /// The types the file declares itself
+    declared: HashSet<String>,
+    /// The names the file already has `use` clauses for
+    imported: HashSet<String>,
+    /// The `use` clauses to add, by type or macro name
+    uses: BTreeMap<String, Location<'a>>,
+    /// Descriptions of the type names we couldn't choose a location for
+    ambiguous: Vec<String>,
+}
+
+impl<'a, 'b> TypeUses<'a, 'b> {
+    /// The locations a type name could refer to, other than the file we're generating
+    fn locations(&self, type_name: &str) -> Vec<&'b Location<'a>> {
+        self.type_name_to_uses
+            .get(type_name)
+            .into_iter()
+            .flatten()
+            .filter(|location| !self.file_name.ends_with(&location.file_name()))
+            .collect()
+    }
+}
+
+impl<'a, 'b> VisitMut for TypeUses<'a, 'b> {
+    /// We only generate impls for the types declared in the same file, so we leave the type
+    /// and trait being implemented alone, and just look inside the impl
+    fn visit_item_impl_mut(&mut self, item_impl: &mut syn::ItemImpl) {
+        for item in &mut item_impl.items {
+            self.visit_impl_item_mut(item);
+        }
+    }
+
+    fn visit_attribute_mut(&mut self, attribute: &mut Attribute) {
+        syn::visit_mut::visit_attribute_mut(self, attribute);
+        let Some(name) = attribute.path().get_ident().map(ToString::to_string) else {
+            return;
+        };
+        if self.imported.contains(&name) {
+            return;
+        }
+        if let Some((_, krate)) = ATTRIBUTE_MACROS
+            .iter()
+            .find(|(macro_name, _)| *macro_name == name)
+        {
+            let location = Location::new(vec![Cow::from(*krate), Cow::from(name.clone())]);
+            self.uses.insert(name, location);
+        }
+    }
+
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        // Generic arguments first, eg. the `TradeID` in `Vec<TradeID>`
+        syn::visit_mut::visit_type_path_mut(self, type_path);
+        if type_path.qself.is_some()
+            || type_path.path.leading_colon.is_some()
+            || type_path.path.segments.len() != 1
+        {
+            return;
+        }
+        let segment = &type_path.path.segments[0];
+        let type_name = segment.ident.to_string();
+        if self.imported.contains(&type_name) {
+            return;
+        }
+        let location = match self.locations(&type_name).as_slice() {
+            [] => return,
+            [location] if !self.declared.contains(&type_name) => {
+                self.uses.insert(type_name, (*location).clone());
+                return;
+            }
+            [location] => *location,
+            locations => {
+                let definitions: Vec<&&Location> = locations
+                    .iter()
+                    .filter(|location| location.path.get(1) == Some(&Cow::from("definitions")))
+                    .collect();
+                let [location] = definitions.as_slice() else {
+                    let locations = locations.iter().map(|l| l.as_string()).collect::<Vec<_>>();
+                    self.ambiguous
+                        .push(format!("{type_name} could be any of: {locations:?}"));
+                    return;
+                };
+                **location
+            }
+        };
+        let arguments = segment.arguments.clone();
+        type_path.path = location.as_path();
+        if let Some(last) = type_path.path.segments.last_mut() {
+            last.arguments = arguments;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{util::Location, Error, Result};
+    use error_stack::ResultExt;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use std::{
+        borrow::Cow,
+        collections::{HashMap, HashSet},
+    };
+    use syn::parse_quote;
+    use utils::stream_to_string;
+
+    fn definition(type_name: &'static str, module: &'static str) -> Location<'static> {
+        Location::new(vec![
+            "crate".into(),
+            "definitions".into(),
+            module.into(),
+            type_name.into(),
+        ])
+    }
+
+    fn type_name_to_uses(
+        locations: Vec<Location<'static>>,
+    ) -> HashMap<Cow<'static, str>, HashSet<Location<'static>>> {
+        let mut out: HashMap<Cow<str>, HashSet<Location>> = HashMap::new();
+        for location in locations {
+            let type_name = location.type_name().unwrap();
+            out.entry(type_name).or_default().insert(location);
+        }
+        out
+    }
+
+    #[test]
+    fn test_gen_uses() -> Result<()> {
+        let map = type_name_to_uses(vec![
+            definition("AccountID", "account_id"),
+            definition("TradeID", "trade_id"),
+            definition("Account", "account"),
+            definition("AcceptDatetimeFormat", "accept_datetime_format"),
+        ]);
+        let file = parse_quote! {
+            use crate::definitions::accept_datetime_format::AcceptDatetimeFormat;
+            pub struct Account {
+                pub id: Option<AccountID>,
+                pub trades: Option<Vec<TradeID>>,
+                pub format: AcceptDatetimeFormat,
+                pub alias: Option<String>,
+            }
+            impl Default for Account {
+                fn default() -> Self {
+                    Self { id: Default::default() }
+                }
+            }
+        };
+        let tokens = super::gen_uses(file, "src/definitions/account.rs", &map)?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                use crate::definitions::account_id::AccountID;
+                use crate::definitions::trade_id::TradeID;
+                use crate::definitions::accept_datetime_format::AcceptDatetimeFormat;
+                pub struct Account {
+                    pub id: Option<AccountID>,
+                    pub trades: Option<Vec<TradeID>>,
+                    pub format: AcceptDatetimeFormat,
+                    pub alias: Option<String>,
+                }
+                impl Default for Account {
+                    fn default() -> Self {
+                        Self { id: Default::default() }
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_uses_qualified() -> Result<()> {
+        let response = Location::new(vec![
+            "crate".into(),
+            "endpoints".into(),
+            "instrument".into(),
+            "responses".into(),
+            "order_book".into(),
+            "OrderBook".into(),
+        ]);
+        let map = type_name_to_uses(vec![
+            definition("OrderBook", "order_book"),
+            definition("Instrument", "instrument"),
+            response,
+        ]);
+        // The response declares its own `OrderBook`, so can't import the definition
+        let file = parse_quote! {
+            pub struct OrderBook {
+                pub order_book: Option<OrderBook>,
+                pub instrument: Instrument,
+            }
+            impl Default for OrderBook {
+                fn default() -> Self {
+                    Self { order_book: None }
+                }
+            }
+        };
+        let tokens = super::gen_uses(
+            file,
+            "src/endpoints/instrument/responses/order_book.rs",
+            &map,
+        )?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                use crate::definitions::instrument::Instrument;
+                pub struct OrderBook {
+                    pub order_book: Option<crate::definitions::order_book::OrderBook>,
+                    pub instrument: Instrument,
+                }
+                impl Default for OrderBook {
+                    fn default() -> Self {
+                        Self { order_book: None }
+                    }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gen_uses_attribute_macro() -> Result<()> {
+        let map = type_name_to_uses(vec![definition("TimeInForce", "time_in_force")]);
+        let file = parse_quote! {
+            #[serde_inline_default]
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub struct MarketOrder {
+                #[serde_inline_default(TimeInForce::Fok)]
+                pub time_in_force: TimeInForce,
+            }
+        };
+        let tokens = super::gen_uses(file, "src/definitions/market_order.rs", &map)?;
+        let code = stream_to_string(&tokens).change_context_lazy(Error::default)?;
+        assert_eq!(
+            code,
+            indoc! {r#"
+                use crate::definitions::time_in_force::TimeInForce;
+                use serde_inline_default::serde_inline_default;
+                #[serde_inline_default]
+                #[derive(Debug, Clone, Serialize, Deserialize)]
+                pub struct MarketOrder {
+                    #[serde_inline_default(TimeInForce::Fok)]
+                    pub time_in_force: TimeInForce,
+                }
+            "#}
+        );
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@ pub mod gen_definition;
 mod gen_endpoint;
 mod gen_error;
 mod gen_mods;
+mod gen_uses;
+mod resolve;
 pub mod util;
 
 pub use error::{EasyError, Error, Result};
@@ -0,0 +1,411 @@
+//! Maps the type names in the docs to the rust types we generate for them.
+//!
+//! The docs use `string`, `integer` and `boolean` for primitives, and the names of definitions
+//! for everything else. We resolve them all before generating any code, so the generators can
+//! use a type name as is, and so we fail with the full list of names we don't understand
+//! rather than generating code that doesn't compile.
+//!
+//! Field defaults are resolved the same way, from the value in the docs to a rust expression of
+//! the field's type. eg. `GTC` for a `TimeInForce` becomes `TimeInForce::Gtc`.
+use crate::{
+    util::{gen_map, Location},
+    Error, Result,
+};
+use change_case::pascal_case;
+use error_stack::Report;
+use model::{
+    definition_docs::{Field, Row, Schema, Value},
+    endpoint_docs::RestCallParameter,
+    Content, Documentation,
+};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+/// The primitive types in the docs, and the rust type we use for each
+const PRIMITIVES: [(&str, &str); 3] = [
+    ("string", "String"),
+    ("integer", "i64"),
+    ("boolean", "bool"),
+];
+
+/// Names the docs use for a definition, other than its own name
+const ALIASES: [(&str, &str); 1] = [("Decimal", "DecimalNumber")];
+
+/// Returns a copy of `contents` with every field and parameter type name replaced by the rust
+/// type we generate for it. eg. `integer` becomes `i64`, `Array[TradeID]` becomes an array of
+/// `TradeID`. Every field default is replaced by a rust expression for it, see
+/// [`Resolver::resolve_default`].
+///
+/// Fails with a report listing every type name and default we couldn't resolve, and every
+/// parameter that can't be written into a url or header, and where it was used
+pub fn resolve_type_names(contents: &[Content]) -> Result<Vec<Content>> {
+    let type_name_to_uses = gen_map(contents);
+    let values = contents
+        .iter()
+        .flat_map(Content::definitions)
+        .flatten()
+        .map(|definition| (definition.name.as_str(), &definition.value))
+        .collect();
+    let resolver = Resolver {
+        type_name_to_uses: &type_name_to_uses,
+        values: &values,
+    };
+    let mut unresolved = Vec::new();
+    let mut contents = contents.to_vec();
+    for content in &mut contents {
+        match &mut content.documentation {
+            Documentation::Definitions { definitions, .. } => {
+                for definition in definitions {
+                    if let Value::Struct(s) = &mut definition.value {
+                        for field in &mut s.fields {
+                            resolver.resolve_field(field, &definition.name, &mut unresolved);
+                        }
+                    }
+                }
+            }
+            Documentation::Endpoint(endpoint) => {
+                for call in &mut endpoint.calls {
+                    let context = format!("{} {}", call.http_method, call.path);
+                    for param in &mut call.parameters {
+                        resolver.resolve_parameter(param, &context, &mut unresolved);
+                    }
+                    let schemas = call
+                        .request_body
+                        .iter_mut()
+                        .chain(call.responses.iter_mut().map(|r| &mut r.schema));
+                    for schema in schemas {
+                        resolver.resolve_schema(schema, &context, &mut unresolved);
+                    }
+                }
+            }
+        }
+    }
+    if unresolved.is_empty() {
+        return Ok(contents);
+    }
+    let report = unresolved.into_iter().fold(
+        Report::new(Error::new("Unable to resolve type names")),
+        |report, unresolved| report.attach_printable(unresolved),
+    );
+    Err(report)
+}
+
+/// The rust expression for `value`, one of the variants of the enum `type_name`, as
+/// `gen_rows` names them. eg. `TimeInForce::Gtc` for `GTC`
+pub fn enum_variant(type_name: &str, value: &str) -> String {
+    format!("{type_name}::{}", pascal_case(value))
+}
+
+struct Resolver<'a> {
+    /// All the types we define, see [`gen_map`]
+    type_name_to_uses: &'a HashMap<Cow<'a, str>, HashSet<Location<'a>>>,
+    /// The value of each definition, by name
+    values: &'a HashMap<&'a str, &'a Value>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Returns the rust type for a single type name from the docs
+    fn resolve(&self, type_name: &str) -> Option<String> {
+        if let Some((_, rust)) = PRIMITIVES.iter().find(|(docs, _)| *docs == type_name) {
+            return Some(rust.to_string());
+        }
+        let type_name = ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == type_name)
+            .map_or(type_name, |(_, name)| name);
+        self.type_name_to_uses
+            .contains_key(type_name)
+            .then(|| type_name.to_string())
+    }
+
+    fn resolve_field(&self, field: &mut Field, context: &str, unresolved: &mut Vec<String>) {
+        let type_name = match field
+            .type_name
+            .strip_prefix("Array[")
+            .and_then(|s| s.strip_suffix(']'))
+        {
+            Some(item) => {
+                field.is_array = true;
+                item.to_string()
+            }
+            None => field.type_name.clone(),
+        };
+        match self.resolve(&type_name) {
+            Some(resolved) => field.type_name = resolved,
+            None => {
+                unresolved.push(format!("{type_name} in field {} of {context}", field.name));
+                return;
+            }
+        }
+        if let Some(default) = &field.default {
+            match self.resolve_default(&field.type_name, default) {
+                Some(expression) => field.default = Some(expression),
+                None => unresolved.push(format!(
+                    "default {default:?} for {} in field {} of {context}",
+                    field.type_name, field.name
+                )),
+            }
+        }
+    }
+
+    /// The rust expression for `default`, a value of `type_name` written as it is in the docs.
+    /// eg. `String::from("ALL")`, `true` or `TimeInForce::Gtc`.
+    ///
+    /// `None` if it's not a value of the type, or not a type we can write a value of
+    fn resolve_default(&self, type_name: &str, default: &str) -> Option<String> {
+        match type_name {
+            "String" => Some(format!("String::from({default:?})")),
+            "i64" => default.parse::<i64>().ok().map(|n| n.to_string()),
+            "bool" => match default.to_lowercase().as_str() {
+                "true" => Some("true".to_string()),
+                "false" => Some("false".to_string()),
+                _ => None,
+            },
+            _ => match self.values.get(type_name)? {
+                // A single row table is a newtype, not an enum
+                Value::Table(rows) if rows.len() > 1 => rows
+                    .iter()
+                    .any(|row| matches!(row, Row::ValueDescription { value, .. } if value == default))
+                    .then(|| enum_variant(type_name, default)),
+                _ => None,
+            },
+        }
+    }
+
+    /// True if `type_name` is written out with `to_string`, so it can go in a url or header.
+    /// That's the primitives and the types generated from a table, which all impl `Display`
+    fn is_display(&self, type_name: &str) -> bool {
+        PRIMITIVES.iter().any(|(_, rust)| *rust == type_name)
+            || matches!(self.values.get(type_name), Some(Value::Table(_)))
+    }
+
+    fn resolve_parameter(
+        &self,
+        param: &mut RestCallParameter,
+        context: &str,
+        unresolved: &mut Vec<String>,
+    ) {
+        match self.resolve(&param.type_name) {
+            Some(resolved) => param.type_name = resolved,
+            None => {
+                return unresolved.push(format!(
+                    "{} in parameter {} of {context}",
+                    param.type_name, param.name
+                ))
+            }
+        }
+        // Parameter defaults are only documentation, the server fills them in. But every
+        // parameter is written out as a string, in the url or a header
+        if !self.is_display(&param.type_name) {
+            unresolved.push(format!(
+                "{} in parameter {} of {context} can't be written as a string",
+                param.type_name, param.name
+            ))
+        }
+    }
+
+    fn resolve_schema(&self, schema: &mut Schema, context: &str, unresolved: &mut Vec<String>) {
+        match schema {
+            Schema::Struct(s) => {
+                for field in &mut s.fields {
+                    self.resolve_field(field, context, unresolved);
+                }
+            }
+            // The objects in a stream are always definitions
+            Schema::Stream(stream) => unresolved.extend(
+                stream
+                    .objects
+                    .iter()
+                    .filter(|object| !self.type_name_to_uses.contains_key(object.as_str()))
+                    .map(|object| format!("{object} in the stream of {context}")),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Result;
+    use model::{
+        definition_docs::{Field, Row, Struct, Value},
+        Content, Definition, Documentation,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn field(name: &str, type_name: &str) -> Field {
+        Field {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            doc_string: String::new(),
+            is_array: false,
+            default: None,
+            required: false,
+        }
+    }
+
+    fn definitions(definitions: Vec<Definition>) -> Vec<Content> {
+        vec![Content {
+            urls: Vec::new(),
+            documentation: Documentation::Definitions {
+                name: "Account".to_string(),
+                definitions,
+            },
+        }]
+    }
+
+    fn account(fields: Vec<Field>) -> Vec<Content> {
+        definitions(vec![
+            Definition {
+                name: "AccountID".to_string(),
+                doc_string: String::new(),
+                value: Value::Empty,
+            },
+            Definition {
+                name: "Account".to_string(),
+                doc_string: String::new(),
+                value: Value::Struct(Struct { fields }),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_resolve_type_names() -> Result<()> {
+        let contents = account(vec![
+            field("id", "AccountID"),
+            field("alias", "string"),
+            field("userID", "integer"),
+            field("hedgingEnabled", "boolean"),
+            field("childIDs", "Array[AccountID]"),
+        ]);
+        let resolved = super::resolve_type_names(&contents)?;
+        let Some(Definition {
+            value: Value::Struct(Struct { fields }),
+            ..
+        }) = resolved[0].definitions().and_then(|d| d.last())
+        else {
+            panic!("No Account in {resolved:#?}")
+        };
+        let types: Vec<(&str, bool)> = fields
+            .iter()
+            .map(|f| (f.type_name.as_str(), f.is_array))
+            .collect();
+        assert_eq!(
+            vec![
+                ("AccountID", false),
+                ("String", false),
+                ("i64", false),
+                ("bool", false),
+                ("AccountID", true),
+            ],
+            types
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_type_names_unresolved() {
+        let contents = account(vec![field("id", "AccountId"), field("units", "ListOf")]);
+        let report = super::resolve_type_names(&contents).unwrap_err();
+        let printed = format!("{report:?}");
+        assert!(
+            printed.contains("AccountId in field id of Account"),
+            "{printed}"
+        );
+        assert!(
+            printed.contains("ListOf in field units of Account"),
+            "{printed}"
+        );
+    }
+
+    /// An enum of how long an order lasts, like `TimeInForce`
+    fn time_in_force() -> Definition {
+        Definition {
+            name: "TimeInForce".to_string(),
+            doc_string: String::new(),
+            value: Value::Table(
+                ["GTC", "FOK"]
+                    .into_iter()
+                    .map(|value| Row::ValueDescription {
+                        value: value.to_string(),
+                        description: String::new(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn with_default(name: &str, type_name: &str, default: &str) -> Field {
+        Field {
+            default: Some(default.to_string()),
+            ..field(name, type_name)
+        }
+    }
+
+    #[test]
+    fn test_resolve_defaults() -> Result<()> {
+        let contents = definitions(vec![
+            time_in_force(),
+            Definition {
+                name: "MarketOrder".to_string(),
+                doc_string: String::new(),
+                value: Value::Struct(Struct {
+                    fields: vec![
+                        with_default("timeInForce", "TimeInForce", "GTC"),
+                        with_default("units", "string", "ALL"),
+                        with_default("count", "integer", "500"),
+                        with_default("smooth", "boolean", "False"),
+                    ],
+                }),
+            },
+        ]);
+        let resolved = super::resolve_type_names(&contents)?;
+        let Some(Definition {
+            value: Value::Struct(Struct { fields }),
+            ..
+        }) = resolved[0].definitions().and_then(|d| d.last())
+        else {
+            panic!("No MarketOrder in {resolved:#?}")
+        };
+        let defaults: Vec<Option<&str>> = fields.iter().map(|f| f.default.as_deref()).collect();
+        assert_eq!(
+            vec![
+                Some("TimeInForce::Gtc"),
+                Some(r#"String::from("ALL")"#),
+                Some("500"),
+                Some("false"),
+            ],
+            defaults
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_defaults_unresolved() {
+        let contents = definitions(vec![
+            time_in_force(),
+            Definition {
+                name: "MarketOrder".to_string(),
+                doc_string: String::new(),
+                value: Value::Struct(Struct {
+                    fields: vec![
+                        with_default("timeInForce", "TimeInForce", "GFD"),
+                        with_default("count", "integer", "lots"),
+                    ],
+                }),
+            },
+        ]);
+        let report = super::resolve_type_names(&contents).unwrap_err();
+        let printed = format!("{report:?}");
+        assert!(
+            printed
+                .contains(r#"default "GFD" for TimeInForce in field timeInForce of MarketOrder"#),
+            "{printed}"
+        );
+        assert!(
+            printed.contains(r#"default "lots" for i64 in field count of MarketOrder"#),
+            "{printed}"
+        );
+    }
+}
@@ -1,18 +1,22 @@
 use crate::{
+    gen_client::gen_client,
     gen_definition::gen_definition,
     gen_endpoint::{gen_responses_for_call, CallNames},
+    gen_error::gen_error,
     gen_mods::gen_mods,
+    gen_uses::gen_uses,
+    resolve::resolve_type_names,
     EasyError, Error, Result,
 };
-use change_case::{lower_case, pascal_case, snake_case};
+use change_case::{lower_case, snake_case};
 use error_stack::ResultExt;
 use model::endpoint_docs::{HttpMethod, Response, RestCall};
-use model::{Content, Documentation, Endpoint};
+use model::{Content, Definition, Documentation, Endpoint, ErrorDefinition, Everything};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use rust_format::{Formatter, PrettyPlease};
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::Path;
 use tracing::instrument;
 
@@ -27,7 +31,7 @@ pub struct Writer<'a> {
 }
 
 impl<'a> Writer<'a> {
-    pub fn new(contents: &'a [Content]) -> Writer {
+    pub fn new(contents: &'a [Content]) -> Writer<'a> {
         let type_name_to_uses = gen_map(contents);
         let mod_lines_by_file = gen_all_mods(&type_name_to_uses);
         Writer {
@@ -38,23 +42,18 @@ impl<'a> Writer<'a> {
     }
 
     /// Given a type_name returns the file it should be written to
+    #[cfg(test)]
     fn type_name_to_file_name(&self, type_name: &str) -> Option<String> {
         self.type_name_to_uses
             .get(&Cow::from(type_name))
             .and_then(|o| o.iter().next())
-            .map(|uses| {
-                if uses.path.first() == Some(&Cow::from("crate")) {
-                    uses.path[1..].join("/") + ".rs"
-                } else {
-                    uses.path.join("/") + ".rs"
-                }
-            })
+            .map(Location::file_name)
     }
 
     /// Given a type_name returns the location, (so you can generate uses clauses for it)
     /// If there are multiple uses it just returns the first one
     #[instrument(skip(self))]
-    pub fn type_name_to_location(&'a self, type_name: &'a str) -> Option<&'a Location> {
+    pub fn type_name_to_location(&'a self, type_name: &'a str) -> Option<&'a Location<'a>> {
         let result = self
             .type_name_to_uses
             .get(&Cow::from(type_name))
@@ -67,13 +66,14 @@ impl<'a> Writer<'a> {
     pub fn write_definitions(&self, base_path: &str) -> Result<()> {
         // Generate all the definitions we need
         let mut definition_mods = Vec::new();
-        for definition in self
+        let definitions: Vec<&Definition> = self
             .contents
             .iter()
             .flat_map(Content::definitions)
             .flatten()
-        {
-            let content = gen_definition(definition).attach_printable_lazy(|| {
+            .collect();
+        for definition in definitions.iter().copied() {
+            let content = gen_definition(definition, &definitions).attach_printable_lazy(|| {
                 format!("Generating definition for {}", definition.name)
             })?;
             let mod_name = change_case::snake_case(&definition.name);
@@ -108,6 +108,14 @@ impl<'a> Writer<'a> {
         self.stream_to_file(tokens, &filename)
             .change_context_lazy(|| Error::new(format!("Saving endpoint to {filename}")))?;
 
+        // The streams' responses need to know what their objects are tagged with
+        let definitions: Vec<&Definition> = self
+            .contents
+            .iter()
+            .flat_map(Content::definitions)
+            .flatten()
+            .collect();
+
         // Generate each of the endpoints
         for endpoint in self.contents.iter().flat_map(Content::as_endpoint) {
             let tokens = self
@@ -117,7 +125,7 @@ impl<'a> Writer<'a> {
             self.stream_to_file(tokens, &filename)
                 .change_context_lazy(|| Error::new(format!("Saving endpoint to {filename}")))?;
             // Generate the responses in a sub module
-            let responses_info = get_responses_info(&endpoint.calls)?;
+            let responses_info = get_responses_info(&endpoint.calls, &definitions)?;
             let tokens = self
                 .gen_endpoint_responses(base_path, &endpoint.name, &responses_info)
                 .attach_printable_lazy(|| format!("Generating endpoint for {}", endpoint.name))?;
@@ -129,6 +137,26 @@ impl<'a> Writer<'a> {
         Ok(endpoints)
     }
 
+    /// Writes the crate's `Error`, and the `HttpErrorKind` for each of the documented `errors`
+    pub fn write_error(&self, base_path: &str, errors: &[ErrorDefinition]) -> Result<()> {
+        let filename = format!("{base_path}/error.rs");
+        let tokens = gen_error(errors).attach_printable("Generating the errors")?;
+        self.stream_to_file(tokens, &filename)
+            .change_context_lazy(|| Error::new(format!("Saving error to {filename}")))
+    }
+
+    /// Writes the `Client` that hands out all of the endpoints
+    pub fn write_client(&self, base_path: &str, endpoints: &[&str]) -> Result<()> {
+        // The client sends the Accept-Datetime-Format header on every request
+        let Some(datetime_format) = self.type_name_to_location("AcceptDatetimeFormat") else {
+            crate::bail!("Couldn't find the AcceptDatetimeFormat definition that the client needs")
+        };
+        let tokens = gen_client(endpoints, datetime_format.as_uses())?;
+        let filename = format!("{base_path}/client.rs");
+        self.stream_to_file(tokens, &filename)
+            .change_context_lazy(|| Error::new(format!("Saving client to {filename}")))
+    }
+
     /// Writes a token_stream out to a file
     pub fn stream_to_file(&self, stream: TokenStream, path: &str) -> Result<()> {
         // Prepend the token_stream with the "mod" statements
@@ -139,6 +167,16 @@ impl<'a> Writer<'a> {
         };
 
         // Work out the all the types it uses, and add their uses clauses
+        let stream = match syn::parse2::<syn::File>(stream.clone()) {
+            Ok(file) => gen_uses(file, path, &self.type_name_to_uses)
+                .attach_printable_lazy(|| format!("Adding the uses clauses to {path}"))?,
+            Err(err) => {
+                tracing::error!(
+                    "Unable to parse the code for {path:#?}, so it has no uses clauses: {err:#?}"
+                );
+                stream
+            }
+        };
 
         // Create the dir if it doesn't already exist
         let path = Path::new(path);
@@ -172,15 +210,25 @@ pub struct ResponsesInfo<'a> {
     pub token_stream: TokenStream,
 }
 
-fn get_responses_info(calls: &[RestCall]) -> Result<HashMap<String, ResponsesInfo>> {
+/// The responses of each call, by the call's method name. Sorted, so the files are generated the
+/// same way every time
+fn get_responses_info<'a>(
+    calls: &'a [RestCall],
+    definitions: &[&Definition],
+) -> Result<BTreeMap<String, ResponsesInfo<'a>>> {
     calls
         .iter()
         .map(|call| {
             let struct_prefix = call.response_struct_prefix()?;
             let responses_module_parts = call.responses_module_parts()?;
             let (good_response, bad_responses) = call.good_and_bad_responses()?;
-            let token_stream =
-                gen_responses_for_call(&struct_prefix, good_response, bad_responses.as_slice())?;
+            let token_stream = gen_responses_for_call(
+                &struct_prefix,
+                good_response,
+                bad_responses.as_slice(),
+                &call.other_responses,
+                definitions,
+            )?;
             Ok((
                 call.method_name_as_string()?,
                 ResponsesInfo {
@@ -192,7 +240,7 @@ fn get_responses_info(calls: &[RestCall]) -> Result<HashMap<String, ResponsesInf
                 },
             ))
         })
-        .collect::<Result<HashMap<String, ResponsesInfo>>>()
+        .collect::<Result<BTreeMap<String, ResponsesInfo>>>()
 }
 
 /// The location of a definition
@@ -219,30 +267,36 @@ impl<'a> Location<'a> {
         self.path.last().cloned()
     }
 
+    /// The file the type is written to, relative to `src`. eg. `definitions/account_id.rs`
+    pub fn file_name(&self) -> String {
+        let start = usize::from(self.path.first() == Some(&Cow::from("crate")));
+        let end = self.path.len().saturating_sub(1).max(start);
+        self.path[start..end].join("/") + ".rs"
+    }
+
+    /// The full path to the type. eg. `crate::definitions::account_id::AccountID`
+    pub fn as_path(&self) -> syn::Path {
+        let last = self.path.len() - 1;
+        let segments = self.path.iter().enumerate().map(|(num, part)| {
+            let part = if num == last {
+                // The last part of the path, is the type name exactly as we define it
+                part.to_string()
+            } else {
+                // All the parts leading up to the type name are module names so they are snake case
+                snake_case(part)
+            };
+            syn::PathSegment::from(Ident::new(&part, Span::call_site()))
+        });
+        syn::Path {
+            leading_colon: None,
+            segments: segments.collect(),
+        }
+    }
+
     /// Returns the token stream to add this location as a uses statement
     pub fn as_uses(&self) -> TokenStream {
-        let separators = std::iter::repeat(quote! {::})
-            .take(self.path.len() - 1)
-            .chain(std::iter::once(quote! {}));
-        let tokens: Vec<TokenStream> = self
-            .path
-            .iter()
-            .enumerate()
-            .map(|(num, part)| {
-                if num == self.path.len() - 1 {
-                    // The last part of the path, is the type name so it should be PascalCase
-                    pascal_case(part)
-                } else {
-                    // All the parts leading up to the type name are module names so they are snake case
-                    snake_case(part)
-                }
-            })
-            .map(|part| Ident::new(&part, Span::call_site()))
-            .map(|ident| quote! {#ident})
-            .zip(separators)
-            .map(|(ident, separator)| quote!(#ident #separator))
-            .collect();
-        quote!(use #(#tokens)*;)
+        let path = self.as_path();
+        quote!(use #path;)
     }
 }
 
@@ -258,7 +312,7 @@ impl<'a> std::fmt::Display for Location<'a> {
 /// # Returns
 ///
 ///  map of `type_name` -> every place that type is declared. The Vec contains each module
-fn gen_map<'a>(contents: &'a [Content]) -> HashMap<Cow<'a, str>, HashSet<Location<'a>>> {
+pub(crate) fn gen_map<'a>(contents: &'a [Content]) -> HashMap<Cow<'a, str>, HashSet<Location<'a>>> {
     let input = contents
         .iter()
         .map(|c| &c.documentation)
@@ -274,14 +328,16 @@ fn gen_map<'a>(contents: &'a [Content]) -> HashMap<Cow<'a, str>, HashSet<Locatio
                     ])
                 })
                 .collect::<Vec<Location<'a>>>(),
-            Documentation::Definitions { name, definitions } => definitions
+            // Each definition gets its own file. eg. `definitions/account_id.rs`
+            Documentation::Definitions { definitions, .. } => definitions
                 .iter()
                 .map(|d| {
                     Location::new(vec![
                         "crate".into(),
                         "definitions".into(),
-                        snake_case(name).into(),
-                        pascal_case(&d.name).into(),
+                        snake_case(&d.name).into(),
+                        // The type name exactly as we define it. eg. `AccountID`
+                        d.name.as_str().into(),
                     ])
                 })
                 .collect(),
@@ -304,10 +360,11 @@ pub fn gen_all_mods<'a>(
     map: &'a HashMap<Cow<'a, str>, HashSet<Location<'a>>>,
 ) -> HashMap<String, TokenStream> {
     // Create a map of all the imports for each file
-    let mut to_create: HashMap<String, HashSet<Cow<'a, str>>> = HashMap::new();
+    // Sorted, so the files are generated the same way every time
+    let mut to_create: HashMap<String, BTreeSet<Cow<'a, str>>> = HashMap::new();
     for (file_name, module) in map
-        .iter()
-        .flat_map(|(_type_name, locations)| locations.iter())
+        .values()
+        .flat_map(|locations| locations.iter())
         .flat_map(|location| {
             match location.path.as_slice() {
                 [start, a] if start == "crate" => Some(("lib.rs".to_string(), a)),
@@ -337,17 +394,21 @@ pub fn gen_all_mods<'a>(
 /// # Args
 ///
 /// * `base_path` - The `src` directory where we'll start dumping the generated files
-/// * `contents` - The data used to generate the source code
+/// * `everything` - The data used to generate the source code
 ///
 /// # Returns
 ///
 /// A map of type_name -> All the places that type is defined
-pub fn generate_source(base_path: &str, contents: &[Content]) -> Result<()> {
+pub fn generate_source(base_path: &str, everything: &Everything) -> Result<()> {
+    let contents =
+        resolve_type_names(&everything.content).attach_printable("Resolving type names")?;
     // map of type_name to uses_clause
-    let writer = Writer::new(contents);
+    let writer = Writer::new(&contents);
 
     writer.write_definitions(base_path)?;
-    writer.write_endpoints(base_path)?;
+    let endpoints = writer.write_endpoints(base_path)?;
+    writer.write_client(base_path, &endpoints)?;
+    writer.write_error(base_path, &everything.errors)?;
 
     // // We use the mods here
     // let mods = gen_mods(&mods);
@@ -358,7 +419,7 @@ pub fn generate_source(base_path: &str, contents: &[Content]) -> Result<()> {
     // };
     // stream_to_file(lib, &format!("{base_path}/lib.rs"))
     //     .change_context_lazy(|| Error::new("Generating lib.rs"))?;
-    Ok(Default::default())
+    Ok(())
 }
 
 /// Just generates the src/endpoints.rs
@@ -381,13 +442,45 @@ pub fn field_name(name: &str) -> TokenStream {
         let name = format_ident! { "r#type" };
         quote! { #name }
     } else {
-        let name = name.replace('-', "_");
-        let name = change_case::snake_case(&name);
-        let name = Ident::new(&name, proc_macro2::Span::call_site());
+        let name = Ident::new(&snake_case_name(name), proc_macro2::Span::call_site());
         quote! { #name }
     }
 }
 
+/// Snake cases a json key, keeping acronyms together. eg. `relatedTransactionIDs` becomes
+/// `related_transaction_ids` rather than `related_transaction_i_ds`
+pub fn snake_case_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' || c == '_' {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            continue;
+        }
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1).copied();
+        let after_next = chars.get(i + 2).copied();
+        let starts_word = c.is_uppercase()
+            && match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                // The last capital of an acronym starts the next word, eg. HTTPServer.
+                // Unless it's a plural acronym, eg. IDs
+                Some(prev) if prev.is_uppercase() => {
+                    let plural = next == Some('s') && !after_next.is_some_and(char::is_lowercase);
+                    next.is_some_and(char::is_lowercase) && !plural
+                }
+                _ => false,
+            };
+        if starts_word && !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
 /// Returns the method_name without converting it to an Ident
 /// Generates a rust name for the method to trigger the Rest api call.
 ///
@@ -415,24 +508,42 @@ fn endpoint_method_name(ep: &RestCall) -> Result<String> {
 
 #[cfg(test)]
 mod test {
-    use std::borrow::Cow;
+    use std::{borrow::Cow, path::Path};
 
-    use model::Content;
+    use model::Everything;
     use utils::stream_to_string;
 
     use crate::util::{gen_all_mods, Location};
 
     use super::Writer;
 
+    #[test]
+    fn test_snake_case_name() {
+        for (input, expected) in [
+            ("relatedTransactionIDs", "related_transaction_ids"),
+            ("accountID", "account_id"),
+            ("lastTransactionID", "last_transaction_id"),
+            ("timeInForce", "time_in_force"),
+            ("unrealizedPL", "unrealized_pl"),
+            ("HTTPServer", "http_server"),
+            ("tradeIDsToClose", "trade_ids_to_close"),
+            ("Accept-Datetime-Format", "accept_datetime_format"),
+            ("units", "units"),
+        ] {
+            assert_eq!(expected, super::snake_case_name(input), "{input}");
+        }
+    }
+
     #[test]
     fn test_gen_map() {
-        let content = std::fs::read_to_string("../content.yaml").unwrap();
-        let contents: Vec<Content> = serde_yaml::from_str(&content).unwrap();
+        let content = std::fs::read_to_string("../serialize_all/content.yaml").unwrap();
+        let everything: Everything = serde_yaml::from_str(&content).unwrap();
+        let contents = everything.content;
         let locations = super::gen_map(&contents);
         println!("{locations:#?}");
         let account_id = locations
-            .get(&Cow::from("AccountId"))
-            .expect("No AccountId!")
+            .get(&Cow::from("AccountID"))
+            .expect("No AccountID!")
             .iter()
             .next()
             .unwrap();
@@ -440,8 +551,8 @@ mod test {
             path: vec![
                 Cow::from("crate"),
                 Cow::from("definitions"),
-                Cow::from("account"),
-                Cow::from("AccountId"),
+                Cow::from("account_id"),
+                Cow::from("AccountID"),
             ],
         };
         assert_eq!(expected, *account_id);
@@ -461,20 +572,21 @@ mod test {
 
     #[test]
     fn test_type_name_to_file_name() {
-        let content = std::fs::read_to_string("../content.yaml").unwrap();
-        let contents: Vec<Content> = serde_yaml::from_str(&content).unwrap();
+        let content = std::fs::read_to_string("../serialize_all/content.yaml").unwrap();
+        let everything: Everything = serde_yaml::from_str(&content).unwrap();
+        let contents = everything.content;
         let writer = Writer::new(contents.as_slice());
         let type_name = "CandlestickResponse";
         let file_name = writer.type_name_to_file_name(type_name);
         println!("File name for {type_name} = {file_name:?}");
         assert_eq!(
-            Some("definitions/instrument/CandlestickResponse.rs"),
+            Some("definitions/candlestick_response.rs"),
             file_name.as_deref()
         );
         let location = writer.type_name_to_location(type_name);
         println!("Uses for {type_name} = {location:?}");
         assert_eq!(
-            Some("crate::definitions::instrument::CandlestickResponse"),
+            Some("crate::definitions::candlestick_response::CandlestickResponse"),
             location.map(|l| l.to_string()).as_deref()
         );
     }
@@ -495,4 +607,62 @@ mod test {
         let as_text = stream_to_string(&tokens).unwrap();
         println!("{as_text}");
     }
+
+    /// Copies the files in `from` into `to`, recursively
+    fn copy_dir(from: &std::path::Path, to: &std::path::Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)? {
+            let entry = entry?;
+            let to = to.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                copy_dir(&entry.path(), &to)?;
+            } else {
+                std::fs::copy(entry.path(), to)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates the oanda_v2 crate from content.yaml into a scratch directory, next to its hand
+    /// written modules, and checks that it compiles without warnings
+    #[test]
+    fn test_generated_crate_compiles() {
+        let content = std::fs::read_to_string("../serialize_all/content.yaml").unwrap();
+        let everything: Everything = serde_yaml::from_str(&content).unwrap();
+        let dir = std::env::temp_dir().join(format!("oanda_v2_{}", std::process::id()));
+        let src = dir.join("src");
+        copy_dir(Path::new("../oanda_v2/src"), &src).unwrap();
+        // Only keep the hand written modules, so everything else must be generated again
+        for generated in ["definitions", "endpoints"] {
+            std::fs::remove_dir_all(src.join(generated)).unwrap();
+        }
+        super::generate_source(src.to_str().unwrap(), &everything).unwrap();
+        // The crate's own manifest, minus the build script that would generate it again
+        let manifest = std::fs::read_to_string("../oanda_v2/Cargo.toml").unwrap();
+        let (head, build_dependencies) = manifest.split_once("[build-dependencies]").unwrap();
+        let tail = build_dependencies
+            .find("\n[")
+            .map_or("", |end| &build_dependencies[end..]);
+        let manifest =
+            head.replace("build = \"build.rs\"", "build = false") + tail + "\n[workspace]\n";
+        std::fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+        std::fs::copy("../Cargo.lock", dir.join("Cargo.lock")).unwrap();
+        // Kept between runs, so the dependencies are only checked once
+        let target_dir = Path::new("../target/generated_crate");
+        std::fs::create_dir_all(target_dir).unwrap();
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let output = std::process::Command::new(cargo)
+            .args(["check", "--offline", "--tests", "--quiet"])
+            .current_dir(&dir)
+            .env("CARGO_TARGET_DIR", target_dir.canonicalize().unwrap())
+            .env("RUSTFLAGS", "-D warnings")
+            .output()
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            output.status.success(),
+            "The generated crate doesn't compile:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 }